@@ -1,6 +1,7 @@
 //! Types and constants for handling acceleration.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 use super::length;
 
 /// The `Acceleration` struct can be used to deal with Accelerations in a common way.
@@ -79,6 +80,43 @@ impl Measurement for Acceleration {
 
 implement_measurement! { Acceleration }
 
+implement_units! {
+    Acceleration, AccelerationUnit {
+        MeterPerSecondSquared => ("m/s\u{00B2}", "meter per second squared", "meters per second squared", 1.0) in Si,
+        FootPerSecondSquared => ("ft/s\u{00B2}", "foot per second squared", "feet per second squared", 1.0 / length::METER_FEET_FACTOR) in UsCustomary | Imperial,
+    }
+}
+
+/// The unit names understood when parsing an `Acceleration`.
+const UNIT_NAMES: &[UnitConstructor<'static, Acceleration>] = &[
+    ("m/s\u{00B2}", Acceleration::from_meters_per_second_per_second),
+    ("m/s^2", Acceleration::from_meters_per_second_per_second),
+    ("m/s2", Acceleration::from_meters_per_second_per_second),
+    ("ft/s\u{00B2}", Acceleration::from_feet_per_second_per_second),
+    ("ft/s^2", Acceleration::from_feet_per_second_per_second),
+    ("ft/s2", Acceleration::from_feet_per_second_per_second),
+    ("meters per second per second", Acceleration::from_meters_per_second_per_second),
+    ("metres per second per second", Acceleration::from_meters_per_second_per_second),
+    ("meters per second squared", Acceleration::from_meters_per_second_per_second),
+    ("metres per second squared", Acceleration::from_meters_per_second_per_second),
+    ("feet per second per second", Acceleration::from_feet_per_second_per_second),
+    ("feet per second squared", Acceleration::from_feet_per_second_per_second),
+];
+
+impl UnitNames for Acceleration {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Acceleration {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -157,4 +195,19 @@ mod test {
         assert_eq!(a > b, false);
         assert_eq!(a >= b, false);
     }
+
+    #[test]
+    fn from_str() {
+        let a: Acceleration = "9.81 m/s^2".parse().unwrap();
+        let b: Acceleration = "32 ft/s\u{00B2}".parse().unwrap();
+        assert_almost_eq(a.as_meters_per_second_per_second(), 9.81);
+        assert_almost_eq(b.as_feet_per_second_per_second(), 32.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Acceleration::from_unit(1.0, AccelerationUnit::FootPerSecondSquared);
+        assert_almost_eq(a.as_feet_per_second_per_second(), 1.0);
+        assert_almost_eq(a.as_unit(AccelerationUnit::MeterPerSecondSquared), 0.3048);
+    }
 }
@@ -1,6 +1,7 @@
 //! Types and constants for handling angles
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 
 /// The 'Angle' struct can be used to deal with angles in a common way.
 ///
@@ -99,6 +100,38 @@ impl Measurement for Angle {
 
 implement_measurement! { Angle }
 
+implement_units! {
+    Angle, AngleUnit {
+        Radian => ("rad", "radian", "radians", 1.0) in Si,
+        Degree => ("\u{00B0}", "degree", "degrees", ::PI / 180.0),
+    }
+}
+
+/// The unit names understood when parsing an `Angle`.
+const UNIT_NAMES: &[UnitConstructor<'static, Angle>] = &[
+    ("rad", Angle::from_radians),
+    ("\u{00B0}", Angle::from_degrees),
+    ("deg", Angle::from_degrees),
+    ("radian", Angle::from_radians),
+    ("radians", Angle::from_radians),
+    ("degree", Angle::from_degrees),
+    ("degrees", Angle::from_degrees),
+];
+
+impl UnitNames for Angle {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Angle {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use angle::*;
@@ -114,4 +147,19 @@ mod test {
         assert_almost_eq(r1, 2.0 * PI);
         assert_almost_eq(r2, 180.0);
     }
+
+    #[test]
+    fn from_str() {
+        let a: Angle = "90\u{00B0}".parse().unwrap();
+        let b: Angle = "1.5 rad".parse().unwrap();
+        assert_almost_eq(a.as_degrees(), 90.0);
+        assert_almost_eq(b.as_radians(), 1.5);
+    }
+
+    #[test]
+    fn units() {
+        let a = Angle::from_unit(180.0, AngleUnit::Degree);
+        assert_almost_eq(a.as_radians(), ::PI);
+        assert_almost_eq(a.as_unit(AngleUnit::Radian), ::PI);
+    }
 }
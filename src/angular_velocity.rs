@@ -1,6 +1,8 @@
 //! Types and constants for handling speed of rotation (angular velocity)
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::Frequency;
 use ::PI;
 
 /// The 'AngularVelocity' struct can be used to deal with angular velocities in a common way.
@@ -70,6 +72,52 @@ impl Measurement for AngularVelocity {
 
 implement_measurement! { AngularVelocity }
 
+implement_units! {
+    AngularVelocity, AngularVelocityUnit {
+        RadianPerSecond => ("rad/s", "radian per second", "radians per second", 1.0) in Si,
+        RevolutionPerMinute => ("rpm", "revolution per minute", "revolutions per minute", 2.0 * PI / 60.0),
+        RevolutionPerSecond => ("Hz", "revolution per second", "revolutions per second", 2.0 * PI),
+    }
+}
+
+/// The unit names understood when parsing an `AngularVelocity`.
+const UNIT_NAMES: &[UnitConstructor<'static, AngularVelocity>] = &[
+    ("rad/s", AngularVelocity::from_radians_per_second),
+    ("radians per second", AngularVelocity::from_radians_per_second),
+    ("rpm", AngularVelocity::from_rpm),
+    ("revolutions per minute", AngularVelocity::from_rpm),
+    ("Hz", AngularVelocity::from_hertz),
+    ("hertz", AngularVelocity::from_hertz),
+    ("revolutions per second", AngularVelocity::from_hertz),
+];
+
+impl UnitNames for AngularVelocity {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for AngularVelocity {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+/// One revolution per second is 2π radians per second.
+impl From<Frequency> for AngularVelocity {
+    fn from(frequency: Frequency) -> Self {
+        AngularVelocity::from_hertz(frequency.as_hertz())
+    }
+}
+
+impl From<AngularVelocity> for Frequency {
+    fn from(angular_velocity: AngularVelocity) -> Self {
+        Frequency::from_hertz(angular_velocity.as_hertz())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -84,4 +132,27 @@ mod test {
         assert_almost_eq(r1, 628.31853);
         assert_almost_eq(r2, 954.929659642538);
     }
+
+    #[test]
+    fn from_str() {
+        let a: AngularVelocity = "6000 rpm".parse().unwrap();
+        let b: AngularVelocity = "2 rad/s".parse().unwrap();
+        assert_almost_eq(a.as_rpm(), 6000.0);
+        assert_almost_eq(b.as_radians_per_second(), 2.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = AngularVelocity::from_unit(60.0, AngularVelocityUnit::RevolutionPerMinute);
+        assert_almost_eq(a.as_hertz(), 1.0);
+        assert_almost_eq(a.as_unit(AngularVelocityUnit::RadianPerSecond), 2.0 * ::PI);
+    }
+
+    #[test]
+    fn frequency() {
+        let a = AngularVelocity::from(Frequency::from_hertz(1.0));
+        assert_almost_eq(a.as_radians_per_second(), 2.0 * ::PI);
+        let f = Frequency::from(AngularVelocity::from_radians_per_second(2.0 * ::PI));
+        assert_almost_eq(f.as_hertz(), 1.0);
+    }
 }
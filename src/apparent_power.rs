@@ -0,0 +1,270 @@
+//! Types and constants for handling apparent power in AC circuits.
+
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::{Current, Power, Voltage};
+#[cfg(not(feature = "no_std"))]
+use super::{Angle, ReactivePower};
+
+/// The `ApparentPower` struct can be used to deal with the apparent power of
+/// an AC circuit, the product of its RMS voltage and current, in a common
+/// way. The real `Power` it delivers is the apparent power scaled by the
+/// power factor, the cosine of the phase angle between voltage and current.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{ApparentPower, Current, Power, Voltage};
+///
+/// let s = ApparentPower::from_voltage_current(
+///     Voltage::from_volts(230.0),
+///     Current::from_amperes(10.0),
+/// );
+/// let pf = s.power_factor(Power::from_kilowatts(1.955));
+/// println!("{} at a power factor of {:.2}", s, pf);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ApparentPower {
+    volt_amperes: f64,
+}
+
+impl ApparentPower {
+    /// Create a new ApparentPower from a floating point value in volt-amperes
+    /// (VA)
+    pub fn from_volt_amperes(volt_amperes: f64) -> Self {
+        ApparentPower { volt_amperes }
+    }
+
+    /// Create a new ApparentPower from a floating point value in
+    /// kilovolt-amperes (kVA)
+    pub fn from_kilovolt_amperes(kilovolt_amperes: f64) -> Self {
+        Self::from_volt_amperes(kilovolt_amperes * 1e3)
+    }
+
+    /// Create a new ApparentPower from a floating point value in
+    /// megavolt-amperes (MVA)
+    pub fn from_megavolt_amperes(megavolt_amperes: f64) -> Self {
+        Self::from_volt_amperes(megavolt_amperes * 1e6)
+    }
+
+    /// Create a new ApparentPower from the RMS voltage across, and RMS
+    /// current through, a single-phase load
+    pub fn from_voltage_current(voltage: Voltage, current: Current) -> Self {
+        Self::from_volt_amperes(voltage.as_volts() * current.as_amperes())
+    }
+
+    /// Create a new ApparentPower from the line-to-line voltage and line
+    /// current of a balanced three-phase load, however it is connected
+    pub fn three_phase(line_voltage: Voltage, line_current: Current) -> Self {
+        Self::from_volt_amperes(::SQRT_3 * line_voltage.as_volts() * line_current.as_amperes())
+    }
+
+    /// Create a new ApparentPower from the real Power delivered at a power
+    /// factor
+    pub fn from_power_factor(real: Power, power_factor: f64) -> Self {
+        Self::from_volt_amperes(real.as_watts() / power_factor)
+    }
+
+    /// Create a new ApparentPower from its real and reactive components
+    #[cfg(not(feature = "no_std"))]
+    pub fn from_components(real: Power, reactive: ReactivePower) -> Self {
+        Self::from_volt_amperes(real.as_watts().hypot(reactive.as_vars()))
+    }
+
+    /// Convert this ApparentPower into a floating point value in
+    /// volt-amperes (VA)
+    pub fn as_volt_amperes(&self) -> f64 {
+        self.volt_amperes
+    }
+
+    /// Convert this ApparentPower into a floating point value in
+    /// kilovolt-amperes (kVA)
+    pub fn as_kilovolt_amperes(&self) -> f64 {
+        self.volt_amperes / 1e3
+    }
+
+    /// Convert this ApparentPower into a floating point value in
+    /// megavolt-amperes (MVA)
+    pub fn as_megavolt_amperes(&self) -> f64 {
+        self.volt_amperes / 1e6
+    }
+
+    /// The power factor at which this ApparentPower delivers the given real
+    /// Power
+    pub fn power_factor(&self, real: Power) -> f64 {
+        real.as_watts() / self.volt_amperes
+    }
+
+    /// The phase angle between voltage and current at which this
+    /// ApparentPower delivers the given real Power. The sign of the angle,
+    /// leading or lagging, cannot be recovered and is always positive.
+    #[cfg(not(feature = "no_std"))]
+    pub fn phase_angle(&self, real: Power) -> Angle {
+        Angle::acos(self.power_factor(real))
+    }
+
+    /// The real Power delivered when the current lags the voltage by the
+    /// given phase angle
+    #[cfg(not(feature = "no_std"))]
+    pub fn real_power(&self, phase: Angle) -> Power {
+        Power::from_watts(self.volt_amperes * phase.cos())
+    }
+
+    /// The ReactivePower drawn when the current lags the voltage by the given
+    /// phase angle. It is negative when the current leads.
+    #[cfg(not(feature = "no_std"))]
+    pub fn reactive_power(&self, phase: Angle) -> ReactivePower {
+        ReactivePower::from_vars(self.volt_amperes * phase.sin())
+    }
+}
+
+impl Measurement for ApparentPower {
+    fn as_base_units(&self) -> f64 {
+        self.volt_amperes
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_volt_amperes(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "VA"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("mVA", 1e-3),
+            ("VA", 1e0),
+            ("kVA", 1e3),
+            ("MVA", 1e6),
+            ("GVA", 1e9),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { ApparentPower }
+
+implement_units! {
+    ApparentPower, ApparentPowerUnit {
+        VoltAmpere => ("VA", "volt-ampere", "volt-amperes", 1.0) in Si,
+        KilovoltAmpere => ("kVA", "kilovolt-ampere", "kilovolt-amperes", 1e3) in Si,
+        MegavoltAmpere => ("MVA", "megavolt-ampere", "megavolt-amperes", 1e6) in Si,
+    }
+}
+
+/// The unit names understood when parsing an `ApparentPower`.
+const UNIT_NAMES: &[UnitConstructor<'static, ApparentPower>] = &[
+    ("mVA", |v| ApparentPower::from_volt_amperes(v * 1e-3)),
+    ("VA", ApparentPower::from_volt_amperes),
+    ("kVA", ApparentPower::from_kilovolt_amperes),
+    ("MVA", ApparentPower::from_megavolt_amperes),
+    ("GVA", |v| ApparentPower::from_volt_amperes(v * 1e9)),
+    ("volt-ampere", ApparentPower::from_volt_amperes),
+    ("volt-amperes", ApparentPower::from_volt_amperes),
+    ("kilovolt-ampere", ApparentPower::from_kilovolt_amperes),
+    ("kilovolt-amperes", ApparentPower::from_kilovolt_amperes),
+    ("megavolt-ampere", ApparentPower::from_megavolt_amperes),
+    ("megavolt-amperes", ApparentPower::from_megavolt_amperes),
+];
+
+impl UnitNames for ApparentPower {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for ApparentPower {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use apparent_power::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn as_volt_amperes() {
+        let s = ApparentPower::from_kilovolt_amperes(2.5);
+        assert_almost_eq(s.as_volt_amperes(), 2500.0);
+        assert_almost_eq(s.as_megavolt_amperes(), 0.0025);
+    }
+
+    #[test]
+    fn voltage_current() {
+        let s = ApparentPower::from_voltage_current(
+            Voltage::from_volts(230.0),
+            Current::from_amperes(10.0),
+        );
+        assert_almost_eq(s.as_kilovolt_amperes(), 2.3);
+    }
+
+    #[test]
+    fn three_phase() {
+        let s = ApparentPower::three_phase(Voltage::from_volts(400.0), Current::from_amperes(10.0));
+        assert_almost_eq(s.as_kilovolt_amperes(), 6.928_203_230_275_51);
+        let phase = Voltage::from_volts(400.0).star_line_to_phase();
+        let per_phase = ApparentPower::from_voltage_current(phase, Current::from_amperes(10.0));
+        assert_almost_eq(s.as_volt_amperes(), 3.0 * per_phase.as_volt_amperes());
+    }
+
+    #[test]
+    fn power_factor() {
+        let s = ApparentPower::from_kilovolt_amperes(2.0);
+        assert_almost_eq(s.power_factor(Power::from_kilowatts(1.6)), 0.8);
+        assert_almost_eq(
+            ApparentPower::from_power_factor(Power::from_kilowatts(1.6), 0.8).as_kilovolt_amperes(),
+            2.0,
+        );
+    }
+
+    #[test]
+    fn phase_angle() {
+        let s = ApparentPower::from_kilovolt_amperes(2.0);
+        let phase = s.phase_angle(Power::from_kilowatts(1.0));
+        assert_almost_eq(phase.as_degrees(), 60.0);
+        assert_almost_eq(s.real_power(phase).as_kilowatts(), 1.0);
+        assert_almost_eq(
+            s.reactive_power(phase).as_kilovars(),
+            1.732_050_807_568_877_2,
+        );
+        assert_almost_eq(
+            s.reactive_power(Angle::from_degrees(-30.0)).as_kilovars(),
+            -1.0,
+        );
+    }
+
+    #[test]
+    fn components() {
+        let s = ApparentPower::from_components(
+            Power::from_watts(300.0),
+            ReactivePower::from_vars(400.0),
+        );
+        assert_almost_eq(s.as_volt_amperes(), 500.0);
+    }
+
+    #[test]
+    fn display() {
+        let s = ApparentPower::from_volt_amperes(1500.0);
+        assert_eq!(format!("{}", s), "1.5\u{00A0}kVA");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: ApparentPower = "15 kVA".parse().unwrap();
+        let b: ApparentPower = "500 volt-amperes".parse().unwrap();
+        assert_almost_eq(a.as_volt_amperes(), 15_000.0);
+        assert_almost_eq(b.as_volt_amperes(), 500.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = ApparentPower::from_unit(1.0, ApparentPowerUnit::MegavoltAmpere);
+        assert_almost_eq(a.as_unit(ApparentPowerUnit::KilovoltAmpere), 1000.0);
+    }
+}
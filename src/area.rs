@@ -1,6 +1,7 @@
 //! Types and constants for handling areas.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 use super::length;
 
 /// Number of acres in a square meter
@@ -304,6 +305,111 @@ impl Measurement for Area {
 
 implement_measurement! { Area }
 
+implement_units! {
+    Area, AreaUnit {
+        SquareNanometer => ("nm\u{00B2}", "square nanometer", "square nanometers", 1.0 / (length::METER_NANOMETER_FACTOR * length::METER_NANOMETER_FACTOR)) in Si,
+        SquareMicrometer => ("\u{00B5}m\u{00B2}", "square micrometer", "square micrometers", 1.0 / (length::METER_MICROMETER_FACTOR * length::METER_MICROMETER_FACTOR)) in Si,
+        SquareMillimeter => ("mm\u{00B2}", "square millimeter", "square millimeters", 1.0 / (length::METER_MILLIMETER_FACTOR * length::METER_MILLIMETER_FACTOR)) in Si,
+        SquareCentimeter => ("cm\u{00B2}", "square centimeter", "square centimeters", 1.0 / (length::METER_CENTIMETER_FACTOR * length::METER_CENTIMETER_FACTOR)) in Si,
+        SquareDecimeter => ("dm\u{00B2}", "square decimeter", "square decimeters", 1.0 / (length::METER_DECIMETER_FACTOR * length::METER_DECIMETER_FACTOR)),
+        SquareMeter => ("m\u{00B2}", "square meter", "square meters", 1.0) in Si,
+        SquareHectometer => ("hm\u{00B2}", "square hectometer", "square hectometers", 1.0 / (length::METER_HECTOMETER_FACTOR * length::METER_HECTOMETER_FACTOR)),
+        Hectare => ("ha", "hectare", "hectares", 1.0 / (length::METER_HECTOMETER_FACTOR * length::METER_HECTOMETER_FACTOR)) in Si,
+        SquareKilometer => ("km\u{00B2}", "square kilometer", "square kilometers", 1.0 / (length::METER_KILOMETER_FACTOR * length::METER_KILOMETER_FACTOR)) in Si,
+        SquareInch => ("in\u{00B2}", "square inch", "square inches", 1.0 / (length::METER_INCH_FACTOR * length::METER_INCH_FACTOR)) in UsCustomary | Imperial,
+        SquareFoot => ("ft\u{00B2}", "square foot", "square feet", 1.0 / (length::METER_FEET_FACTOR * length::METER_FEET_FACTOR)) in UsCustomary | Imperial,
+        SquareYard => ("yd\u{00B2}", "square yard", "square yards", 1.0 / (length::METER_YARD_FACTOR * length::METER_YARD_FACTOR)) in UsCustomary | Imperial,
+        Acre => ("ac", "acre", "acres", 1.0 / SQUARE_METER_ACRE_FACTOR) in UsCustomary | Imperial,
+        SquareMile => ("mi\u{00B2}", "square mile", "square miles", 1.0 / (length::METER_MILE_FACTOR * length::METER_MILE_FACTOR)) in UsCustomary | Imperial,
+    }
+}
+
+/// The unit names understood when parsing an `Area`.
+const UNIT_NAMES: &[UnitConstructor<'static, Area>] = &[
+    ("nm\u{00B2}", Area::from_square_nanometers),
+    ("\u{00B5}m\u{00B2}", Area::from_square_micrometers),
+    ("mm\u{00B2}", Area::from_square_millimeters),
+    ("cm\u{00B2}", Area::from_square_centimeters),
+    ("m\u{00B2}", Area::from_square_meters),
+    ("km\u{00B2}", Area::from_square_kilometers),
+    ("thousand km\u{00B2}", |v| Area::from_square_kilometers(v * 1e3)),
+    ("million km\u{00B2}", |v| Area::from_square_kilometers(v * 1e6)),
+    ("mm2", Area::from_square_millimeters),
+    ("cm2", Area::from_square_centimeters),
+    ("m2", Area::from_square_meters),
+    ("km2", Area::from_square_kilometers),
+    ("dm\u{00B2}", Area::from_square_decimeters),
+    ("hm\u{00B2}", Area::from_square_hectometers),
+    ("ha", Area::from_hectares),
+    ("in\u{00B2}", Area::from_square_inches),
+    ("sq in", Area::from_square_inches),
+    ("ft\u{00B2}", Area::from_square_feet),
+    ("sq ft", Area::from_square_feet),
+    ("yd\u{00B2}", Area::from_square_yards),
+    ("sq yd", Area::from_square_yards),
+    ("ac", Area::from_acres),
+    ("mi\u{00B2}", Area::from_square_miles),
+    ("sq mi", Area::from_square_miles),
+    ("square nanometer", Area::from_square_nanometers),
+    ("square nanometers", Area::from_square_nanometers),
+    ("square nanometre", Area::from_square_nanometers),
+    ("square nanometres", Area::from_square_nanometers),
+    ("square micrometer", Area::from_square_micrometers),
+    ("square micrometers", Area::from_square_micrometers),
+    ("square micrometre", Area::from_square_micrometers),
+    ("square micrometres", Area::from_square_micrometers),
+    ("square millimeter", Area::from_square_millimeters),
+    ("square millimeters", Area::from_square_millimeters),
+    ("square millimetre", Area::from_square_millimeters),
+    ("square millimetres", Area::from_square_millimeters),
+    ("square centimeter", Area::from_square_centimeters),
+    ("square centimeters", Area::from_square_centimeters),
+    ("square centimetre", Area::from_square_centimeters),
+    ("square centimetres", Area::from_square_centimeters),
+    ("square decimeter", Area::from_square_decimeters),
+    ("square decimeters", Area::from_square_decimeters),
+    ("square decimetre", Area::from_square_decimeters),
+    ("square decimetres", Area::from_square_decimeters),
+    ("square meter", Area::from_square_meters),
+    ("square meters", Area::from_square_meters),
+    ("square metre", Area::from_square_meters),
+    ("square metres", Area::from_square_meters),
+    ("square hectometer", Area::from_square_hectometers),
+    ("square hectometers", Area::from_square_hectometers),
+    ("square hectometre", Area::from_square_hectometers),
+    ("square hectometres", Area::from_square_hectometers),
+    ("square kilometer", Area::from_square_kilometers),
+    ("square kilometers", Area::from_square_kilometers),
+    ("square kilometre", Area::from_square_kilometers),
+    ("square kilometres", Area::from_square_kilometers),
+    ("hectare", Area::from_hectares),
+    ("hectares", Area::from_hectares),
+    ("square inch", Area::from_square_inches),
+    ("square inches", Area::from_square_inches),
+    ("square foot", Area::from_square_feet),
+    ("square feet", Area::from_square_feet),
+    ("square yard", Area::from_square_yards),
+    ("square yards", Area::from_square_yards),
+    ("acre", Area::from_acres),
+    ("acres", Area::from_acres),
+    ("square mile", Area::from_square_miles),
+    ("square miles", Area::from_square_miles),
+];
+
+impl UnitNames for Area {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Area {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use area::*;
@@ -529,4 +635,18 @@ mod test {
         assert_almost_eq(r2, 258998704.7);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Area = "2 ha".parse().unwrap();
+        let b: Area = "12 sq ft".parse().unwrap();
+        assert_almost_eq(a.as_square_meters(), 20000.0);
+        assert_almost_eq(b.as_square_feet(), 12.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Area::from_unit(1.0, AreaUnit::Hectare);
+        assert_almost_eq(a.as_square_meters(), 10_000.0);
+        assert_almost_eq(a.as_unit(AreaUnit::SquareKilometer), 0.01);
+    }
 }
@@ -0,0 +1,221 @@
+//! A command-line unit converter and calculator.
+//!
+//! ```text
+//! measurements convert 5 ft m
+//! measurements convert 250 "lbf ft" Nm
+//! measurements units pressure
+//! measurements
+//! ```
+//!
+//! With no arguments, expressions are read from standard input one line at a
+//! time and evaluated. End an expression with "-> unit" to convert the
+//! result into that unit.
+
+extern crate measurements;
+
+use measurements::expression::{evaluate, ExpressionError, Value};
+use measurements::prelude::*;
+use measurements::*;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process;
+
+const USAGE: &str = "\
+Usage:
+    measurements convert <value> <unit> <target unit>
+    measurements convert <expression> <target unit>
+    measurements units [quantity]
+    measurements [repl]
+
+In the REPL, enter an expression such as \"3 kg * 9.81 m/s^2\", optionally
+followed by \"-> unit\" to convert the result. Enter \"quit\" to leave.";
+
+/// Every quantity which can be listed with `units`, and the function which
+/// lists its units.
+const QUANTITIES: &[(&str, fn())] = &[
+    ("Length", list_units::<LengthUnit>),
+    ("Area", list_units::<AreaUnit>),
+    ("Volume", list_units::<VolumeUnit>),
+    ("Mass", list_units::<MassUnit>),
+    ("Temperature", list_units::<TemperatureUnit>),
+    ("Pressure", list_units::<PressureUnit>),
+    ("Speed", list_units::<SpeedUnit>),
+    ("Acceleration", list_units::<AccelerationUnit>),
+    ("Energy", list_units::<EnergyUnit>),
+    ("Power", list_units::<PowerUnit>),
+    ("Voltage", list_units::<VoltageUnit>),
+    ("Current", list_units::<CurrentUnit>),
+    ("Resistance", list_units::<ResistanceUnit>),
+    ("Force", list_units::<ForceUnit>),
+    ("Angle", list_units::<AngleUnit>),
+    ("Frequency", list_units::<FrequencyUnit>),
+    ("Angular velocity", list_units::<AngularVelocityUnit>),
+    ("Torque", list_units::<TorqueUnit>),
+    ("Data", list_units::<DataUnit>),
+    ("Data rate", list_units::<DataRateUnit>),
+    ("Density", list_units::<DensityUnit>),
+    ("Volumetric flow rate", list_units::<VolumetricFlowRateUnit>),
+    ("Mass flow rate", list_units::<MassFlowRateUnit>),
+    ("Electric charge", list_units::<ElectricChargeUnit>),
+    ("Capacitance", list_units::<CapacitanceUnit>),
+    ("Inductance", list_units::<InductanceUnit>),
+    ("Conductance", list_units::<ConductanceUnit>),
+    ("Apparent power", list_units::<ApparentPowerUnit>),
+    ("Reactive power", list_units::<ReactivePowerUnit>),
+];
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        None | Some("repl") => repl(),
+        Some("convert") => convert(&args[1..]),
+        Some("units") => units(&args[1..]),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(other) => Err(format!("unknown command '{}'\n\n{}", other, USAGE)),
+    };
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+fn convert(args: &[String]) -> Result<(), String> {
+    let (target, source) = match args.split_last() {
+        Some((target, source)) if !source.is_empty() => (target, source.join(" ")),
+        _ => {
+            return Err(format!(
+                "convert needs a value and a target unit\n\n{}",
+                USAGE
+            ))
+        }
+    };
+    let value = evaluate(&source).map_err(|e| describe(&source, &e))?;
+    let converted = value.as_unit(target).map_err(|e| describe(target, &e))?;
+    println!(
+        "{} = {} {}",
+        source,
+        format_number(converted),
+        target.trim()
+    );
+    Ok(())
+}
+
+fn units(args: &[String]) -> Result<(), String> {
+    let name = match args {
+        [] => {
+            for &(quantity, _) in QUANTITIES {
+                println!("{}", quantity);
+            }
+            return Ok(());
+        }
+        _ => args.join(" "),
+    };
+    let key = normalise(&name);
+    match QUANTITIES
+        .iter()
+        .find(|&&(quantity, _)| normalise(quantity) == key)
+    {
+        Some(&(_, list)) => {
+            list();
+            Ok(())
+        }
+        None => Err(format!(
+            "unknown quantity '{}'; run `measurements units` for a list",
+            name
+        )),
+    }
+}
+
+/// Lower-case a quantity name and drop spaces and underscores, so
+/// "angular velocity", "AngularVelocity" and "angular_velocity" all match.
+fn normalise(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn list_units<U: Unit>() {
+    for unit in U::iter() {
+        println!(
+            "{:<8} {} ({})",
+            unit.symbol(),
+            unit.singular(),
+            unit.plural()
+        );
+    }
+}
+
+fn repl() -> Result<(), String> {
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let mut lines = stdin.lock().lines();
+    loop {
+        if interactive {
+            print!("> ");
+            io::stdout().flush().map_err(|e| e.to_string())?;
+        }
+        let line = match lines.next() {
+            Some(line) => line.map_err(|e| e.to_string())?,
+            None => return Ok(()),
+        };
+        let line = line.trim();
+        match line {
+            "" => {}
+            "quit" | "exit" => return Ok(()),
+            "help" => println!("{}", USAGE),
+            _ => match evaluate_line(line) {
+                Ok(result) => println!("{}", result),
+                Err(message) => eprintln!("error: {}", message),
+            },
+        }
+    }
+}
+
+/// Evaluate one line of REPL input, which is an expression optionally
+/// followed by "-> unit".
+fn evaluate_line(line: &str) -> Result<String, String> {
+    let (expression, target) = match line.rfind("->") {
+        Some(index) => (&line[..index], Some(&line[index + 2..])),
+        None => (line, None),
+    };
+    let value: Value = evaluate(expression).map_err(|e| describe(line, &e))?;
+    match target {
+        Some(target) => {
+            let converted = value.as_unit(target).map_err(|e| {
+                let offset = expression.len() + 2;
+                describe(line, &ExpressionError::new(e.kind(), offset + e.position()))
+            })?;
+            Ok(format!("{} {}", format_number(converted), target.trim()))
+        }
+        None => Ok(value.to_string()),
+    }
+}
+
+/// Describe an error, with the input and a caret pointing at the problem.
+fn describe(input: &str, error: &ExpressionError) -> String {
+    let column = input
+        .get(..error.position())
+        .map_or(0, |prefix| prefix.chars().count());
+    format!("{}\n    {}\n    {}^", error, input, " ".repeat(column))
+}
+
+/// Format a number to ten significant figures, without trailing zeros.
+fn format_number(number: f64) -> String {
+    if number == 0.0 || !number.is_finite() {
+        return number.to_string();
+    }
+    let magnitude = number.abs().log10().floor() as i32;
+    let decimals = (9 - magnitude).clamp(0, 20) as usize;
+    let formatted = format!("{:.*}", decimals, number);
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    }
+}
@@ -0,0 +1,246 @@
+//! Types and constants for handling electrical capacitance.
+
+use super::e_series::ESeries;
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+
+/// The `Capacitance` struct can be used to deal with electrical capacitance
+/// in a common way.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Capacitance, Resistance};
+/// use std::time::Duration;
+///
+/// let c = Capacitance::from_microfarads(10.0);
+/// let tau: Duration = Resistance::from_kiloohms(4.7) * c;
+/// println!("The RC time constant is {:?}", tau);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Capacitance {
+    farads: f64,
+}
+
+impl Capacitance {
+    /// Create a new Capacitance from a floating point value in farads
+    pub fn from_farads(farads: f64) -> Self {
+        Capacitance { farads }
+    }
+
+    /// Create a new Capacitance from a floating point value in millifarads
+    pub fn from_millifarads(millifarads: f64) -> Self {
+        Self::from_farads(millifarads * 1e-3)
+    }
+
+    /// Create a new Capacitance from a floating point value in microfarads
+    pub fn from_microfarads(microfarads: f64) -> Self {
+        Self::from_farads(microfarads * 1e-6)
+    }
+
+    /// Create a new Capacitance from a floating point value in nanofarads
+    pub fn from_nanofarads(nanofarads: f64) -> Self {
+        Self::from_farads(nanofarads * 1e-9)
+    }
+
+    /// Create a new Capacitance from a floating point value in picofarads
+    pub fn from_picofarads(picofarads: f64) -> Self {
+        Self::from_farads(picofarads * 1e-12)
+    }
+
+    /// Convert this Capacitance into a floating point value in farads
+    pub fn as_farads(&self) -> f64 {
+        self.farads
+    }
+
+    /// Convert this Capacitance into a floating point value in millifarads
+    pub fn as_millifarads(&self) -> f64 {
+        self.farads / 1e-3
+    }
+
+    /// Convert this Capacitance into a floating point value in microfarads
+    pub fn as_microfarads(&self) -> f64 {
+        self.farads / 1e-6
+    }
+
+    /// Convert this Capacitance into a floating point value in nanofarads
+    pub fn as_nanofarads(&self) -> f64 {
+        self.farads / 1e-9
+    }
+
+    /// Convert this Capacitance into a floating point value in picofarads
+    pub fn as_picofarads(&self) -> f64 {
+        self.farads / 1e-12
+    }
+
+    /// Create a new Capacitance from capacitors connected in series. No
+    /// capacitors at all are a short circuit, with infinite capacitance.
+    pub fn series<I: IntoIterator<Item = Capacitance>>(capacitances: I) -> Self {
+        Self::from_farads(
+            1.0 / capacitances
+                .into_iter()
+                .fold(0.0, |sum, c| sum + 1.0 / c.farads),
+        )
+    }
+
+    /// Create a new Capacitance from capacitors connected in parallel
+    pub fn parallel<I: IntoIterator<Item = Capacitance>>(capacitances: I) -> Self {
+        Self::from_farads(capacitances.into_iter().fold(0.0, |sum, c| sum + c.farads))
+    }
+
+    /// Round this Capacitance to the nearest preferred value in an E-series,
+    /// also returning the relative error of that value.
+    pub fn to_e_series(&self, series: ESeries) -> (Capacitance, f64) {
+        let preferred = series.nearest(self.farads);
+        (
+            Capacitance::from_farads(preferred),
+            (preferred - self.farads) / self.farads,
+        )
+    }
+}
+
+impl Measurement for Capacitance {
+    fn as_base_units(&self) -> f64 {
+        self.farads
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_farads(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "F"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("fF", 1e-15),
+            ("pF", 1e-12),
+            ("nF", 1e-9),
+            ("\u{00B5}F", 1e-6),
+            ("mF", 1e-3),
+            ("F", 1e0),
+            ("kF", 1e3),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { Capacitance }
+
+implement_units! {
+    Capacitance, CapacitanceUnit {
+        Picofarad => ("pF", "picofarad", "picofarads", 1e-12) in Si,
+        Nanofarad => ("nF", "nanofarad", "nanofarads", 1e-9) in Si,
+        Microfarad => ("\u{00B5}F", "microfarad", "microfarads", 1e-6) in Si,
+        Millifarad => ("mF", "millifarad", "millifarads", 1e-3) in Si,
+        Farad => ("F", "farad", "farads", 1.0) in Si,
+    }
+}
+
+/// The unit names understood when parsing a `Capacitance`.
+const UNIT_NAMES: &[UnitConstructor<'static, Capacitance>] = &[
+    ("fF", |v| Capacitance::from_farads(v * 1e-15)),
+    ("pF", Capacitance::from_picofarads),
+    ("nF", Capacitance::from_nanofarads),
+    ("\u{00B5}F", Capacitance::from_microfarads),
+    ("mF", Capacitance::from_millifarads),
+    ("F", Capacitance::from_farads),
+    ("kF", |v| Capacitance::from_farads(v * 1e3)),
+    ("farad", Capacitance::from_farads),
+    ("farads", Capacitance::from_farads),
+    ("millifarad", Capacitance::from_millifarads),
+    ("millifarads", Capacitance::from_millifarads),
+    ("microfarad", Capacitance::from_microfarads),
+    ("microfarads", Capacitance::from_microfarads),
+    ("nanofarad", Capacitance::from_nanofarads),
+    ("nanofarads", Capacitance::from_nanofarads),
+    ("picofarad", Capacitance::from_picofarads),
+    ("picofarads", Capacitance::from_picofarads),
+];
+
+impl UnitNames for Capacitance {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Capacitance {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use capacitance::*;
+    use electric_charge::ElectricCharge;
+    use resistance::Resistance;
+    use test_utils::assert_almost_eq;
+    use time::Duration;
+    use voltage::Voltage;
+
+    #[test]
+    fn as_farads() {
+        let c = Capacitance::from_microfarads(4.7);
+        assert_almost_eq(c.as_farads(), 4.7e-6);
+        assert_almost_eq(c.as_nanofarads(), 4700.0);
+        assert_almost_eq(c.as_picofarads(), 4.7e6);
+        assert_almost_eq(c.as_millifarads(), 4.7e-3);
+    }
+
+    #[test]
+    fn rc_time_constant() {
+        let c = Capacitance::from_microfarads(100.0);
+        let tau: Duration = Resistance::from_kiloohms(10.0) * c;
+        assert_almost_eq(tau.as_secs_f64(), 1.0);
+        let r: Resistance = Duration::new(1, 0) / c;
+        assert_almost_eq(r.as_kiloohms(), 10.0);
+    }
+
+    #[test]
+    fn charge() {
+        let q: ElectricCharge = Capacitance::from_microfarads(10.0) * Voltage::from_volts(5.0);
+        assert_almost_eq(q.as_microcoulombs(), 50.0);
+        let c: Capacitance = q / Voltage::from_volts(5.0);
+        assert_almost_eq(c.as_microfarads(), 10.0);
+    }
+
+    #[test]
+    fn series_parallel() {
+        let c = [
+            Capacitance::from_microfarads(10.0),
+            Capacitance::from_microfarads(40.0),
+        ];
+        assert_almost_eq(Capacitance::series(c.iter().cloned()).as_microfarads(), 8.0);
+        assert_almost_eq(
+            Capacitance::parallel(c.iter().cloned()).as_microfarads(),
+            50.0,
+        );
+        let (c, _) = Capacitance::from_nanofarads(90.0).to_e_series(ESeries::E6);
+        assert_almost_eq(c.as_nanofarads(), 100.0);
+    }
+
+    #[test]
+    fn display() {
+        let c = Capacitance::from_nanofarads(220.0);
+        assert_eq!(format!("{}", c), "220\u{00A0}nF");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: Capacitance = "4.7 uF".parse().unwrap();
+        let b: Capacitance = "100 picofarads".parse().unwrap();
+        assert_almost_eq(a.as_microfarads(), 4.7);
+        assert_almost_eq(b.as_picofarads(), 100.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Capacitance::from_unit(22.0, CapacitanceUnit::Nanofarad);
+        assert_almost_eq(a.as_unit(CapacitanceUnit::Picofarad), 22_000.0);
+    }
+}
@@ -0,0 +1,217 @@
+//! Types and constants for handling electrical conductance.
+
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::resistance::Resistance;
+
+/// The `Conductance` struct can be used to deal with electrical conductance,
+/// the reciprocal of resistance, in a common way.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Conductance, Resistance};
+///
+/// let g = Conductance::from_resistance(Resistance::from_kiloohms(2.0));
+/// println!("A 2 kΩ resistor conducts {}", g);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Conductance {
+    siemens: f64,
+}
+
+impl Conductance {
+    /// Create a new Conductance from a floating point value in siemens
+    pub fn from_siemens(siemens: f64) -> Self {
+        Conductance { siemens }
+    }
+
+    /// Create a new Conductance from a floating point value in millisiemens
+    pub fn from_millisiemens(millisiemens: f64) -> Self {
+        Self::from_siemens(millisiemens * 1e-3)
+    }
+
+    /// Create a new Conductance from a floating point value in microsiemens
+    pub fn from_microsiemens(microsiemens: f64) -> Self {
+        Self::from_siemens(microsiemens * 1e-6)
+    }
+
+    /// Create a new Conductance from the reciprocal of a Resistance
+    pub fn from_resistance(resistance: Resistance) -> Self {
+        Self::from_siemens(1.0 / resistance.as_ohms())
+    }
+
+    /// Convert this Conductance into a floating point value in siemens
+    pub fn as_siemens(&self) -> f64 {
+        self.siemens
+    }
+
+    /// Convert this Conductance into a floating point value in millisiemens
+    pub fn as_millisiemens(&self) -> f64 {
+        self.siemens / 1e-3
+    }
+
+    /// Convert this Conductance into a floating point value in microsiemens
+    pub fn as_microsiemens(&self) -> f64 {
+        self.siemens / 1e-6
+    }
+
+    /// Convert this Conductance into its reciprocal, a Resistance
+    pub fn as_resistance(&self) -> Resistance {
+        Resistance::from_ohms(1.0 / self.siemens)
+    }
+
+    /// Create a new Conductance from conductors connected in series. No
+    /// conductors at all are a short circuit, with infinite conductance.
+    pub fn series<I: IntoIterator<Item = Conductance>>(conductances: I) -> Self {
+        Self::from_siemens(
+            1.0 / conductances
+                .into_iter()
+                .fold(0.0, |sum, g| sum + 1.0 / g.siemens),
+        )
+    }
+
+    /// Create a new Conductance from conductors connected in parallel
+    pub fn parallel<I: IntoIterator<Item = Conductance>>(conductances: I) -> Self {
+        Self::from_siemens(conductances.into_iter().fold(0.0, |sum, g| sum + g.siemens))
+    }
+}
+
+impl Measurement for Conductance {
+    fn as_base_units(&self) -> f64 {
+        self.siemens
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_siemens(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "S"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("pS", 1e-12),
+            ("nS", 1e-9),
+            ("\u{00B5}S", 1e-6),
+            ("mS", 1e-3),
+            ("S", 1e0),
+            ("kS", 1e3),
+            ("MS", 1e6),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { Conductance }
+
+implement_units! {
+    Conductance, ConductanceUnit {
+        Microsiemens => ("\u{00B5}S", "microsiemens", "microsiemens", 1e-6) in Si,
+        Millisiemens => ("mS", "millisiemens", "millisiemens", 1e-3) in Si,
+        Siemens => ("S", "siemens", "siemens", 1.0) in Si,
+    }
+}
+
+/// The unit names understood when parsing a `Conductance`.
+const UNIT_NAMES: &[UnitConstructor<'static, Conductance>] = &[
+    ("pS", |v| Conductance::from_siemens(v * 1e-12)),
+    ("nS", |v| Conductance::from_siemens(v * 1e-9)),
+    ("\u{00B5}S", Conductance::from_microsiemens),
+    ("mS", Conductance::from_millisiemens),
+    ("S", Conductance::from_siemens),
+    ("kS", |v| Conductance::from_siemens(v * 1e3)),
+    ("MS", |v| Conductance::from_siemens(v * 1e6)),
+    ("siemens", Conductance::from_siemens),
+    ("millisiemens", Conductance::from_millisiemens),
+    ("microsiemens", Conductance::from_microsiemens),
+    ("mho", Conductance::from_siemens),
+    ("mhos", Conductance::from_siemens),
+];
+
+impl UnitNames for Conductance {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Conductance {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use conductance::*;
+    use current::Current;
+    use test_utils::assert_almost_eq;
+    use voltage::Voltage;
+
+    #[test]
+    fn as_siemens() {
+        let g = Conductance::from_millisiemens(2.5);
+        assert_almost_eq(g.as_siemens(), 0.0025);
+        assert_almost_eq(g.as_microsiemens(), 2500.0);
+    }
+
+    #[test]
+    fn reciprocal() {
+        let g = Conductance::from_resistance(Resistance::from_kiloohms(2.0));
+        assert_almost_eq(g.as_millisiemens(), 0.5);
+        assert_almost_eq(g.as_resistance().as_ohms(), 2000.0);
+        assert_almost_eq(Resistance::from_conductance(g).as_kiloohms(), 2.0);
+        assert_almost_eq(
+            Resistance::from_ohms(50.0).as_conductance().as_siemens(),
+            0.02,
+        );
+    }
+
+    #[test]
+    fn current() {
+        let i: Current = Voltage::from_volts(5.0) * Conductance::from_millisiemens(2.0);
+        assert_almost_eq(i.as_milliamperes(), 10.0);
+        let g: Conductance = i / Voltage::from_volts(5.0);
+        assert_almost_eq(g.as_millisiemens(), 2.0);
+    }
+
+    #[test]
+    fn series_parallel() {
+        let g = [
+            Conductance::from_millisiemens(1.0),
+            Conductance::from_millisiemens(4.0),
+        ];
+        assert_almost_eq(
+            Conductance::series(g.iter().cloned()).as_millisiemens(),
+            0.8,
+        );
+        assert_almost_eq(
+            Conductance::parallel(g.iter().cloned()).as_millisiemens(),
+            5.0,
+        );
+    }
+
+    #[test]
+    fn display() {
+        let g = Conductance::from_microsiemens(40.0);
+        assert_eq!(format!("{}", g), "40\u{00A0}\u{00B5}S");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: Conductance = "2 mS".parse().unwrap();
+        let b: Conductance = "3 siemens".parse().unwrap();
+        assert_almost_eq(a.as_siemens(), 0.002);
+        assert_almost_eq(b.as_siemens(), 3.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Conductance::from_unit(1.0, ConductanceUnit::Millisiemens);
+        assert_almost_eq(a.as_unit(ConductanceUnit::Microsiemens), 1000.0);
+    }
+}
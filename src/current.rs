@@ -1,9 +1,12 @@
 //! Types and constants for handling electrical current.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use std::f64::consts::SQRT_2;
 
 /// The `Current` struct can be used to deal with electric potential difference
-/// in a common way.
+/// in a common way. For a sinusoidal alternating current, the `Current`
+/// holds the RMS value.
 ///
 /// # Example
 ///
@@ -60,6 +63,42 @@ impl Current {
     pub fn as_nanoamperes(&self) -> f64 {
         self.amperes * 1_000_000_000.0
     }
+
+    /// Create a new Current from the peak value in amperes of a sinusoidal
+    /// current
+    pub fn from_peak_amperes(peak_amperes: f64) -> Self {
+        Self::from_amperes(peak_amperes / SQRT_2)
+    }
+
+    /// Create a new Current from the peak-to-peak value in amperes of a
+    /// sinusoidal current
+    pub fn from_peak_to_peak_amperes(peak_to_peak_amperes: f64) -> Self {
+        Self::from_peak_amperes(peak_to_peak_amperes / 2.0)
+    }
+
+    /// Convert this Current into the peak value in amperes of a sinusoidal
+    /// current
+    pub fn as_peak_amperes(&self) -> f64 {
+        self.amperes * SQRT_2
+    }
+
+    /// Convert this Current into the peak-to-peak value in amperes of a
+    /// sinusoidal current
+    pub fn as_peak_to_peak_amperes(&self) -> f64 {
+        self.as_peak_amperes() * 2.0
+    }
+
+    /// Convert this phase Current of a balanced delta-connected three-phase
+    /// load into the line Current
+    pub fn delta_phase_to_line(&self) -> Self {
+        Self::from_amperes(self.amperes * ::SQRT_3)
+    }
+
+    /// Convert this line Current of a balanced delta-connected three-phase
+    /// load into the phase Current
+    pub fn delta_line_to_phase(&self) -> Self {
+        Self::from_amperes(self.amperes / ::SQRT_3)
+    }
 }
 
 impl Measurement for Current {
@@ -97,6 +136,55 @@ impl Measurement for Current {
 
 implement_measurement! { Current }
 
+implement_units! {
+    Current, CurrentUnit {
+        Nanoampere => ("nA", "nanoampere", "nanoamperes", 1e-9) in Si,
+        Microampere => ("\u{00B5}A", "microampere", "microamperes", 1e-6) in Si,
+        Milliampere => ("mA", "milliampere", "milliamperes", 1e-3) in Si,
+        Ampere => ("A", "ampere", "amperes", 1.0) in Si,
+    }
+}
+
+/// The unit names understood when parsing a `Current`.
+const UNIT_NAMES: &[UnitConstructor<'static, Current>] = &[
+    ("fA", |v| Current::from_amperes(v * 1e-15)),
+    ("pA", |v| Current::from_amperes(v * 1e-12)),
+    ("nA", |v| Current::from_amperes(v * 1e-9)),
+    ("\u{00B5}A", |v| Current::from_amperes(v * 1e-6)),
+    ("mA", |v| Current::from_amperes(v * 1e-3)),
+    ("A", Current::from_amperes),
+    ("kA", |v| Current::from_amperes(v * 1e3)),
+    ("MA", |v| Current::from_amperes(v * 1e6)),
+    ("GA", |v| Current::from_amperes(v * 1e9)),
+    ("TA", |v| Current::from_amperes(v * 1e12)),
+    ("PA", |v| Current::from_amperes(v * 1e15)),
+    ("EA", |v| Current::from_amperes(v * 1e18)),
+    ("ampere", Current::from_amperes),
+    ("amperes", Current::from_amperes),
+    ("amp", Current::from_amperes),
+    ("amps", Current::from_amperes),
+    ("milliampere", Current::from_milliamperes),
+    ("milliamperes", Current::from_milliamperes),
+    ("microampere", Current::from_microamperes),
+    ("microamperes", Current::from_microamperes),
+    ("nanoampere", Current::from_nanoamperes),
+    ("nanoamperes", Current::from_nanoamperes),
+];
+
+impl UnitNames for Current {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Current {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use current::*;
@@ -187,4 +275,43 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn peak() {
+        let i = Current::from_amperes(2.0);
+        assert_almost_eq(i.as_peak_amperes(), 2.828_427_124_746_190_3);
+        assert_almost_eq(i.as_peak_to_peak_amperes(), 5.656_854_249_492_381);
+        assert_almost_eq(
+            Current::from_peak_amperes(1.0).as_milliamperes(),
+            707.106_781_186_547_5,
+        );
+        assert_almost_eq(
+            Current::from_peak_to_peak_amperes(6.0).as_amperes(),
+            2.121_320_343_559_642_4,
+        );
+    }
+
+    #[test]
+    fn three_phase() {
+        let phase = Current::from_amperes(10.0);
+        assert_almost_eq(phase.delta_phase_to_line().as_amperes(), 17.320_508_075_688_775);
+        assert_almost_eq(
+            phase.delta_phase_to_line().delta_line_to_phase().as_amperes(),
+            10.0,
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let a: Current = "35 mA".parse().unwrap();
+        let b: Current = "2 uA".parse().unwrap();
+        assert_almost_eq(a.as_amperes(), 0.035);
+        assert_almost_eq(b.as_microamperes(), 2.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Current::from_unit(35.0, CurrentUnit::Milliampere);
+        assert_almost_eq(a.as_amperes(), 0.035);
+        assert_almost_eq(a.as_unit(CurrentUnit::Microampere), 35_000.0);
+    }
 }
@@ -1,25 +1,35 @@
 //! Types and constants for handling amounts of data (in octets, or bits).
 
+use super::display::write_measurement;
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use std::fmt;
 
 // Constants
-const OCTET_BIT_FACTOR: f64 = 0.125;
+pub(crate) const OCTET_BIT_FACTOR: f64 = 0.125;
 
 // Constants, legacy
-const OCTET_KILOOCTET_FACTOR: f64 = 1000.0;
-const OCTET_MEGAOCTET_FACTOR: f64 = 1000.0 * 1000.0;
-const OCTET_GIGAOCTET_FACTOR: f64 = 1000.0 * 1000.0 * 1000.0;
-const OCTET_TERAOCTET_FACTOR: f64 = 1000.0 * 1000.0 * 1000.0 * 1000.0;
+pub(crate) const OCTET_KILOOCTET_FACTOR: f64 = 1000.0;
+pub(crate) const OCTET_MEGAOCTET_FACTOR: f64 = 1000.0 * 1000.0;
+pub(crate) const OCTET_GIGAOCTET_FACTOR: f64 = 1000.0 * 1000.0 * 1000.0;
+pub(crate) const OCTET_TERAOCTET_FACTOR: f64 = 1000.0 * 1000.0 * 1000.0 * 1000.0;
+pub(crate) const OCTET_PETAOCTET_FACTOR: f64 = 1000.0 * 1000.0 * 1000.0 * 1000.0 * 1000.0;
+pub(crate) const OCTET_EXAOCTET_FACTOR: f64 = 1000.0 * 1000.0 * 1000.0 * 1000.0 * 1000.0 * 1000.0;
 
 // Constants, SI
-const OCTET_KIBIOCTET_FACTOR: f64 = 1024.0;
-const OCTET_MEBIOCTET_FACTOR: f64 = 1024.0 * 1024.0;
-const OCTET_GIBIOCTET_FACTOR: f64 = 1024.0 * 1024.0 * 1024.0;
-const OCTET_TEBIOCTET_FACTOR: f64 = 1024.0 * 1024.0 * 1024.0 * 1024.0;
+pub(crate) const OCTET_KIBIOCTET_FACTOR: f64 = 1024.0;
+pub(crate) const OCTET_MEBIOCTET_FACTOR: f64 = 1024.0 * 1024.0;
+pub(crate) const OCTET_GIBIOCTET_FACTOR: f64 = 1024.0 * 1024.0 * 1024.0;
+pub(crate) const OCTET_TEBIOCTET_FACTOR: f64 = 1024.0 * 1024.0 * 1024.0 * 1024.0;
+pub(crate) const OCTET_PEBIOCTET_FACTOR: f64 = 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0;
+pub(crate) const OCTET_EXBIOCTET_FACTOR: f64 = 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0;
 
 /// The `Data` struct can be used to deal with computer information in a common way.
 /// Common legacy and SI units are supported.
 ///
+/// `Display` uses binary prefixes (KiB, MiB, ...). Use `display_in` to show
+/// decimal prefixes or bits instead.
+///
 /// # Example
 ///
 /// ```
@@ -66,6 +76,16 @@ impl Data {
         Self::from_octets(teraoctets * OCTET_TERAOCTET_FACTOR)
     }
 
+    /// Create new Data from floating point value in Petaoctets (1e15 octets)
+    pub fn from_petaoctets(petaoctets: f64) -> Self {
+        Self::from_octets(petaoctets * OCTET_PETAOCTET_FACTOR)
+    }
+
+    /// Create new Data from floating point value in Exaoctets (1e18 octets)
+    pub fn from_exaoctets(exaoctets: f64) -> Self {
+        Self::from_octets(exaoctets * OCTET_EXAOCTET_FACTOR)
+    }
+
     /// Create new Data from floating point value in Kibioctets (1024 octets)
     pub fn from_kibioctets(kibioctets: f64) -> Self {
         Self::from_octets(kibioctets * OCTET_KIBIOCTET_FACTOR)
@@ -86,6 +106,16 @@ impl Data {
         Self::from_octets(tebioctets * OCTET_TEBIOCTET_FACTOR)
     }
 
+    /// Create new Data from floating point value in Pebioctets (1024**5 octets)
+    pub fn from_pebioctets(pebioctets: f64) -> Self {
+        Self::from_octets(pebioctets * OCTET_PEBIOCTET_FACTOR)
+    }
+
+    /// Create new Data from floating point value in Exbioctets (1024**6 octets)
+    pub fn from_exbioctets(exbioctets: f64) -> Self {
+        Self::from_octets(exbioctets * OCTET_EXBIOCTET_FACTOR)
+    }
+
     /// Convert this Data to a floating point value in Octets
     pub fn as_octets(&self) -> f64 {
         self.octets
@@ -116,6 +146,16 @@ impl Data {
         self.octets / OCTET_TERAOCTET_FACTOR
     }
 
+    /// Convert this Data to a floating point value in Petaoctets (1e15 octets)
+    pub fn as_petaoctets(&self) -> f64 {
+        self.octets / OCTET_PETAOCTET_FACTOR
+    }
+
+    /// Convert this Data to a floating point value in Exaoctets (1e18 octets)
+    pub fn as_exaoctets(&self) -> f64 {
+        self.octets / OCTET_EXAOCTET_FACTOR
+    }
+
     /// Convert this Data to a floating point value in Kibioctets (1024 octets)
     pub fn as_kibioctets(&self) -> f64 {
         self.octets / OCTET_KIBIOCTET_FACTOR
@@ -135,6 +175,110 @@ impl Data {
     pub fn as_tebioctets(&self) -> f64 {
         self.octets / OCTET_TEBIOCTET_FACTOR
     }
+
+    /// Convert this Data to a floating point value in Pebioctets (1024**5 octets)
+    pub fn as_pebioctets(&self) -> f64 {
+        self.octets / OCTET_PEBIOCTET_FACTOR
+    }
+
+    /// Convert this Data to a floating point value in Exbioctets (1024**6 octets)
+    pub fn as_exbioctets(&self) -> f64 {
+        self.octets / OCTET_EXBIOCTET_FACTOR
+    }
+
+    /// Returns the most appropriate units for this Data in the given display
+    /// mode, and its value in those units.
+    pub fn get_appropriate_units_in(&self, mode: DataDisplayMode) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = match mode {
+            DataDisplayMode::Iec => [
+                ("octets", 1.0),
+                ("KiB", OCTET_KIBIOCTET_FACTOR),
+                ("MiB", OCTET_MEBIOCTET_FACTOR),
+                ("GiB", OCTET_GIBIOCTET_FACTOR),
+                ("TiB", OCTET_TEBIOCTET_FACTOR),
+                ("PiB", OCTET_PEBIOCTET_FACTOR),
+                ("EiB", OCTET_EXBIOCTET_FACTOR),
+            ],
+            DataDisplayMode::Si => [
+                ("octets", 1.0),
+                ("kB", OCTET_KILOOCTET_FACTOR),
+                ("MB", OCTET_MEGAOCTET_FACTOR),
+                ("GB", OCTET_GIGAOCTET_FACTOR),
+                ("TB", OCTET_TERAOCTET_FACTOR),
+                ("PB", OCTET_PETAOCTET_FACTOR),
+                ("EB", OCTET_EXAOCTET_FACTOR),
+            ],
+            DataDisplayMode::Bits => [
+                ("bits", OCTET_BIT_FACTOR),
+                ("kbit", OCTET_BIT_FACTOR * OCTET_KILOOCTET_FACTOR),
+                ("Mbit", OCTET_BIT_FACTOR * OCTET_MEGAOCTET_FACTOR),
+                ("Gbit", OCTET_BIT_FACTOR * OCTET_GIGAOCTET_FACTOR),
+                ("Tbit", OCTET_BIT_FACTOR * OCTET_TERAOCTET_FACTOR),
+                ("Pbit", OCTET_BIT_FACTOR * OCTET_PETAOCTET_FACTOR),
+                ("Ebit", OCTET_BIT_FACTOR * OCTET_EXAOCTET_FACTOR),
+            ],
+        };
+        self.pick_appropriate_units(&list)
+    }
+
+    /// Display this Data in the units of the given mode, so
+    /// `data.display_in(DataDisplayMode::Si)` shows "1.5 GB" where `Display`
+    /// shows "1.4 GiB".
+    pub fn display_in(&self, mode: DataDisplayMode) -> DataDisplay {
+        DataDisplay {
+            data: *self,
+            exact: None,
+            mode,
+        }
+    }
+}
+
+/// The units `Data` can be displayed in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataDisplayMode {
+    /// Octets with binary prefixes (KiB, MiB, ...), as used for memory
+    Iec,
+    /// Octets with decimal prefixes (kB, MB, ...), as used for storage
+    Si,
+    /// Bits with decimal prefixes (kbit, Mbit, ...), as used for networks
+    Bits,
+}
+
+/// Displays `Data` in the units of a `DataDisplayMode`. Returned by
+/// `Data::display_in` and `DataSize::display_in`.
+#[derive(Copy, Clone, Debug)]
+pub struct DataDisplay {
+    data: Data,
+    /// The exact number of octets, when displaying a `DataSize`
+    exact: Option<u128>,
+    mode: DataDisplayMode,
+}
+
+impl DataDisplay {
+    /// Display an exact number of octets, which is shown as a whole number
+    /// when it is too small for a prefix.
+    pub(crate) fn exact(octets: u128, mode: DataDisplayMode) -> Self {
+        DataDisplay {
+            data: Data::from_octets(octets as f64),
+            exact: Some(octets),
+            mode,
+        }
+    }
+}
+
+impl fmt::Display for DataDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (unit, value) = self.data.get_appropriate_units_in(self.mode);
+        // An exact amount only goes unprefixed below a thousand or so, which
+        // an f64 holds exactly.
+        let (value, precision) = match (self.exact, unit) {
+            (Some(octets), "octets") => (octets as f64, None),
+            (Some(octets), "bits") => ((octets * 8) as f64, None),
+            _ => (value, f.precision()),
+        };
+        write_measurement(f, value, precision, '.', '\u{00A0}', &unit)
+    }
 }
 
 impl Measurement for Data {
@@ -151,22 +295,98 @@ impl Measurement for Data {
     }
 
     fn get_appropriate_units(&self) -> (&'static str, f64) {
-        // Smallest to largest
-        let list = [
-            ("octets", 1.0),
-            ("KiB", 1024.0),
-            ("MiB", 1024.0 * 1024.0),
-            ("GiB", 1024.0 * 1024.0 * 1024.0),
-            ("TiB", 1024.0 * 1024.0 * 1024.0 * 1024.0),
-            ("PiB", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
-            ("EiB", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
-        ];
-        self.pick_appropriate_units(&list)
+        self.get_appropriate_units_in(DataDisplayMode::Iec)
     }
 }
 
 implement_measurement! { Data }
 
+implement_units! {
+    Data, DataUnit {
+        Bit => ("bit", "bit", "bits", OCTET_BIT_FACTOR),
+        Octet => ("B", "octet", "octets", 1.0),
+        Kilooctet => ("kB", "kilooctet", "kilooctets", OCTET_KILOOCTET_FACTOR),
+        Megaoctet => ("MB", "megaoctet", "megaoctets", OCTET_MEGAOCTET_FACTOR),
+        Gigaoctet => ("GB", "gigaoctet", "gigaoctets", OCTET_GIGAOCTET_FACTOR),
+        Teraoctet => ("TB", "teraoctet", "teraoctets", OCTET_TERAOCTET_FACTOR),
+        Petaoctet => ("PB", "petaoctet", "petaoctets", OCTET_PETAOCTET_FACTOR),
+        Exaoctet => ("EB", "exaoctet", "exaoctets", OCTET_EXAOCTET_FACTOR),
+        Kibioctet => ("KiB", "kibioctet", "kibioctets", OCTET_KIBIOCTET_FACTOR),
+        Mebioctet => ("MiB", "mebioctet", "mebioctets", OCTET_MEBIOCTET_FACTOR),
+        Gibioctet => ("GiB", "gibioctet", "gibioctets", OCTET_GIBIOCTET_FACTOR),
+        Tebioctet => ("TiB", "tebioctet", "tebioctets", OCTET_TEBIOCTET_FACTOR),
+        Pebioctet => ("PiB", "pebioctet", "pebioctets", OCTET_PEBIOCTET_FACTOR),
+        Exbioctet => ("EiB", "exbioctet", "exbioctets", OCTET_EXBIOCTET_FACTOR),
+    }
+}
+
+/// The unit names understood when parsing a `Data`.
+const UNIT_NAMES: &[UnitConstructor<'static, Data>] = &[
+    ("octets", Data::from_octets),
+    ("octet", Data::from_octets),
+    ("B", Data::from_octets),
+    ("byte", Data::from_octets),
+    ("bytes", Data::from_octets),
+    ("bit", Data::from_bits),
+    ("bits", Data::from_bits),
+    ("kbit", |v| Data::from_bits(v * OCTET_KILOOCTET_FACTOR)),
+    ("Mbit", |v| Data::from_bits(v * OCTET_MEGAOCTET_FACTOR)),
+    ("Gbit", |v| Data::from_bits(v * OCTET_GIGAOCTET_FACTOR)),
+    ("Tbit", |v| Data::from_bits(v * OCTET_TERAOCTET_FACTOR)),
+    ("Pbit", |v| Data::from_bits(v * OCTET_PETAOCTET_FACTOR)),
+    ("Ebit", |v| Data::from_bits(v * OCTET_EXAOCTET_FACTOR)),
+    ("kB", Data::from_kilooctets),
+    ("MB", Data::from_megaoctets),
+    ("GB", Data::from_gigaoctets),
+    ("TB", Data::from_teraoctets),
+    ("PB", Data::from_petaoctets),
+    ("EB", Data::from_exaoctets),
+    ("KiB", Data::from_kibioctets),
+    ("MiB", Data::from_mebioctets),
+    ("GiB", Data::from_gibioctets),
+    ("TiB", Data::from_tebioctets),
+    ("PiB", Data::from_pebioctets),
+    ("EiB", Data::from_exbioctets),
+    ("kilooctet", Data::from_kilooctets),
+    ("kilooctets", Data::from_kilooctets),
+    ("megaoctet", Data::from_megaoctets),
+    ("megaoctets", Data::from_megaoctets),
+    ("gigaoctet", Data::from_gigaoctets),
+    ("gigaoctets", Data::from_gigaoctets),
+    ("teraoctet", Data::from_teraoctets),
+    ("teraoctets", Data::from_teraoctets),
+    ("petaoctet", Data::from_petaoctets),
+    ("petaoctets", Data::from_petaoctets),
+    ("exaoctet", Data::from_exaoctets),
+    ("exaoctets", Data::from_exaoctets),
+    ("kibioctet", Data::from_kibioctets),
+    ("kibioctets", Data::from_kibioctets),
+    ("mebioctet", Data::from_mebioctets),
+    ("mebioctets", Data::from_mebioctets),
+    ("gibioctet", Data::from_gibioctets),
+    ("gibioctets", Data::from_gibioctets),
+    ("tebioctet", Data::from_tebioctets),
+    ("tebioctets", Data::from_tebioctets),
+    ("pebioctet", Data::from_pebioctets),
+    ("pebioctets", Data::from_pebioctets),
+    ("exbioctet", Data::from_exbioctets),
+    ("exbioctets", Data::from_exbioctets),
+];
+
+impl UnitNames for Data {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Data {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use data::*;
@@ -282,6 +502,67 @@ mod test {
         assert_almost_eq(r2, 109951162777600.0);
     }
 
+    #[test]
+    fn petaoctets() {
+        let i1 = Data::from_petaoctets(2.0);
+        assert_almost_eq(i1.as_teraoctets(), 2000.0);
+        assert_almost_eq(i1.as_exaoctets(), 0.002);
+        let i2 = Data::from_exaoctets(1.0);
+        assert_almost_eq(i2.as_octets(), 1e18);
+    }
+
+    #[test]
+    fn pebioctets() {
+        let i1 = Data::from_pebioctets(2.0);
+        assert_almost_eq(i1.as_tebioctets(), 2048.0);
+        assert_almost_eq(i1.as_exbioctets(), 2.0 / 1024.0);
+        let i2 = Data::from_exbioctets(1.0);
+        assert_almost_eq(i2.as_octets(), 1_152_921_504_606_846_976.0);
+    }
+
+    #[test]
+    fn display_modes() {
+        let d = Data::from_gigaoctets(1.5);
+        assert_eq!(format!("{:.2}", d), "1.40\u{00A0}GiB");
+        assert_eq!(
+            format!("{:.2}", d.display_in(DataDisplayMode::Iec)),
+            "1.40\u{00A0}GiB"
+        );
+        assert_eq!(
+            format!("{}", d.display_in(DataDisplayMode::Si)),
+            "1.5\u{00A0}GB"
+        );
+        assert_eq!(
+            format!("{}", d.display_in(DataDisplayMode::Bits)),
+            "12\u{00A0}Gbit"
+        );
+        let d = Data::from_exaoctets(3.0);
+        assert_eq!(format!("{}", d.display_in(DataDisplayMode::Si)), "3\u{00A0}EB");
+        let d = Data::from_octets(100.0);
+        assert_eq!(
+            format!("{}", d.display_in(DataDisplayMode::Si)),
+            "100\u{00A0}octets"
+        );
+        let d = Data::from_octets(4.0);
+        assert_eq!(
+            format!("{}", d.display_in(DataDisplayMode::Bits)),
+            "32\u{00A0}bits"
+        );
+    }
+
+    #[test]
+    fn display_padding() {
+        let d = Data::from_kibioctets(1.5);
+        assert_eq!(
+            format!("{:>10.1}", d.display_in(DataDisplayMode::Iec)),
+            "   1.5\u{00A0}KiB"
+        );
+        assert_eq!(
+            format!("{:*<10}", d.display_in(DataDisplayMode::Si)),
+            "1.536\u{00A0}kB**"
+        );
+    }
+
     // Traits
     #[test]
     fn add() {
@@ -337,4 +618,22 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Data = "2.5 MiB".parse().unwrap();
+        let b: Data = "100 bits".parse().unwrap();
+        let c: Data = "2 PB".parse().unwrap();
+        let d: Data = "8 Mbit".parse().unwrap();
+        assert_almost_eq(a.as_mebioctets(), 2.5);
+        assert_almost_eq(b.as_octets(), 12.5);
+        assert_almost_eq(c.as_petaoctets(), 2.0);
+        assert_almost_eq(d.as_megaoctets(), 1.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Data::from_unit(2.0, DataUnit::Kibioctet);
+        assert_almost_eq(a.as_octets(), 2048.0);
+        assert_almost_eq(a.as_unit(DataUnit::Bit), 16384.0);
+    }
 }
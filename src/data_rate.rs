@@ -0,0 +1,324 @@
+//! Types and constants for handling data rates, such as link speeds and
+//! throughput.
+
+use super::data::*;
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::time;
+
+/// The `DataRate` struct can be used to deal with the rate at which data is
+/// transferred in a common way. Link speeds are usually given in bits per
+/// second and throughput in octets per second, and both are supported.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Data, DataRate};
+///
+/// let link = DataRate::from_megabits_per_second(100.0);
+/// let time = link.transfer_time(Data::from_gigaoctets(1.0));
+/// println!("A 1 GB download takes {} seconds at {}", time.as_secs(), link);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct DataRate {
+    octets_per_second: f64,
+}
+
+impl DataRate {
+    /// Create a new DataRate from a floating point value in octets per
+    /// second (B/s)
+    pub fn from_octets_per_second(octets_per_second: f64) -> Self {
+        DataRate { octets_per_second }
+    }
+
+    /// Create a new DataRate from a floating point value in bits per second
+    /// (bit/s)
+    pub fn from_bits_per_second(bits_per_second: f64) -> Self {
+        Self::from_octets_per_second(bits_per_second * OCTET_BIT_FACTOR)
+    }
+
+    /// Create a new DataRate from a floating point value in kilobits per
+    /// second (kbit/s, 1000 bits per second)
+    pub fn from_kilobits_per_second(kilobits_per_second: f64) -> Self {
+        Self::from_bits_per_second(kilobits_per_second * OCTET_KILOOCTET_FACTOR)
+    }
+
+    /// Create a new DataRate from a floating point value in megabits per
+    /// second (Mbit/s, 1e6 bits per second)
+    pub fn from_megabits_per_second(megabits_per_second: f64) -> Self {
+        Self::from_bits_per_second(megabits_per_second * OCTET_MEGAOCTET_FACTOR)
+    }
+
+    /// Create a new DataRate from a floating point value in gigabits per
+    /// second (Gbit/s, 1e9 bits per second)
+    pub fn from_gigabits_per_second(gigabits_per_second: f64) -> Self {
+        Self::from_bits_per_second(gigabits_per_second * OCTET_GIGAOCTET_FACTOR)
+    }
+
+    /// Create a new DataRate from a floating point value in kilooctets per
+    /// second (kB/s, 1000 octets per second)
+    pub fn from_kilooctets_per_second(kilooctets_per_second: f64) -> Self {
+        Self::from_octets_per_second(kilooctets_per_second * OCTET_KILOOCTET_FACTOR)
+    }
+
+    /// Create a new DataRate from a floating point value in megaoctets per
+    /// second (MB/s, 1e6 octets per second)
+    pub fn from_megaoctets_per_second(megaoctets_per_second: f64) -> Self {
+        Self::from_octets_per_second(megaoctets_per_second * OCTET_MEGAOCTET_FACTOR)
+    }
+
+    /// Create a new DataRate from a floating point value in kibioctets per
+    /// second (KiB/s, 1024 octets per second)
+    pub fn from_kibioctets_per_second(kibioctets_per_second: f64) -> Self {
+        Self::from_octets_per_second(kibioctets_per_second * OCTET_KIBIOCTET_FACTOR)
+    }
+
+    /// Create a new DataRate from a floating point value in mebioctets per
+    /// second (MiB/s, 1024**2 octets per second)
+    pub fn from_mebioctets_per_second(mebioctets_per_second: f64) -> Self {
+        Self::from_octets_per_second(mebioctets_per_second * OCTET_MEBIOCTET_FACTOR)
+    }
+
+    /// Create a new DataRate from the largest throughput a windowed protocol
+    /// such as TCP can reach: one window of data every round trip.
+    pub fn from_window(window: Data, round_trip_time: time::Duration) -> Self {
+        window / round_trip_time
+    }
+
+    /// Convert this DataRate into a floating point value in octets per
+    /// second (B/s)
+    pub fn as_octets_per_second(&self) -> f64 {
+        self.octets_per_second
+    }
+
+    /// Convert this DataRate into a floating point value in bits per second
+    /// (bit/s)
+    pub fn as_bits_per_second(&self) -> f64 {
+        self.octets_per_second / OCTET_BIT_FACTOR
+    }
+
+    /// Convert this DataRate into a floating point value in kilobits per
+    /// second (kbit/s, 1000 bits per second)
+    pub fn as_kilobits_per_second(&self) -> f64 {
+        self.as_bits_per_second() / OCTET_KILOOCTET_FACTOR
+    }
+
+    /// Convert this DataRate into a floating point value in megabits per
+    /// second (Mbit/s, 1e6 bits per second)
+    pub fn as_megabits_per_second(&self) -> f64 {
+        self.as_bits_per_second() / OCTET_MEGAOCTET_FACTOR
+    }
+
+    /// Convert this DataRate into a floating point value in gigabits per
+    /// second (Gbit/s, 1e9 bits per second)
+    pub fn as_gigabits_per_second(&self) -> f64 {
+        self.as_bits_per_second() / OCTET_GIGAOCTET_FACTOR
+    }
+
+    /// Convert this DataRate into a floating point value in kilooctets per
+    /// second (kB/s, 1000 octets per second)
+    pub fn as_kilooctets_per_second(&self) -> f64 {
+        self.octets_per_second / OCTET_KILOOCTET_FACTOR
+    }
+
+    /// Convert this DataRate into a floating point value in megaoctets per
+    /// second (MB/s, 1e6 octets per second)
+    pub fn as_megaoctets_per_second(&self) -> f64 {
+        self.octets_per_second / OCTET_MEGAOCTET_FACTOR
+    }
+
+    /// Convert this DataRate into a floating point value in kibioctets per
+    /// second (KiB/s, 1024 octets per second)
+    pub fn as_kibioctets_per_second(&self) -> f64 {
+        self.octets_per_second / OCTET_KIBIOCTET_FACTOR
+    }
+
+    /// Convert this DataRate into a floating point value in mebioctets per
+    /// second (MiB/s, 1024**2 octets per second)
+    pub fn as_mebioctets_per_second(&self) -> f64 {
+        self.octets_per_second / OCTET_MEBIOCTET_FACTOR
+    }
+
+    /// How long it takes to transfer an amount of Data at this rate
+    pub fn transfer_time(&self, data: Data) -> time::Duration {
+        data / *self
+    }
+
+    /// The bandwidth-delay product of a link with this rate and the given
+    /// round trip time: the amount of Data in flight when the link is full,
+    /// and so the smallest window which can keep it busy.
+    pub fn bandwidth_delay_product(&self, round_trip_time: time::Duration) -> Data {
+        *self * round_trip_time
+    }
+}
+
+impl Measurement for DataRate {
+    fn as_base_units(&self) -> f64 {
+        self.octets_per_second
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_octets_per_second(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "B/s"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = [
+            ("bit/s", OCTET_BIT_FACTOR),
+            ("kbit/s", OCTET_BIT_FACTOR * 1e3),
+            ("Mbit/s", OCTET_BIT_FACTOR * 1e6),
+            ("Gbit/s", OCTET_BIT_FACTOR * 1e9),
+            ("Tbit/s", OCTET_BIT_FACTOR * 1e12),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { DataRate }
+
+implement_units! {
+    DataRate, DataRateUnit {
+        BitPerSecond => ("bit/s", "bit per second", "bits per second", OCTET_BIT_FACTOR),
+        KilobitPerSecond => ("kbit/s", "kilobit per second", "kilobits per second",
+            OCTET_BIT_FACTOR * OCTET_KILOOCTET_FACTOR),
+        MegabitPerSecond => ("Mbit/s", "megabit per second", "megabits per second",
+            OCTET_BIT_FACTOR * OCTET_MEGAOCTET_FACTOR),
+        GigabitPerSecond => ("Gbit/s", "gigabit per second", "gigabits per second",
+            OCTET_BIT_FACTOR * OCTET_GIGAOCTET_FACTOR),
+        OctetPerSecond => ("B/s", "octet per second", "octets per second", 1.0),
+        KilooctetPerSecond => ("kB/s", "kilooctet per second", "kilooctets per second",
+            OCTET_KILOOCTET_FACTOR),
+        MegaoctetPerSecond => ("MB/s", "megaoctet per second", "megaoctets per second",
+            OCTET_MEGAOCTET_FACTOR),
+        KibioctetPerSecond => ("KiB/s", "kibioctet per second", "kibioctets per second",
+            OCTET_KIBIOCTET_FACTOR),
+        MebioctetPerSecond => ("MiB/s", "mebioctet per second", "mebioctets per second",
+            OCTET_MEBIOCTET_FACTOR),
+    }
+}
+
+/// The unit names understood when parsing a `DataRate`.
+const UNIT_NAMES: &[UnitConstructor<'static, DataRate>] = &[
+    ("bit/s", DataRate::from_bits_per_second),
+    ("kbit/s", DataRate::from_kilobits_per_second),
+    ("Mbit/s", DataRate::from_megabits_per_second),
+    ("Gbit/s", DataRate::from_gigabits_per_second),
+    ("Tbit/s", |v| DataRate::from_gigabits_per_second(v * 1e3)),
+    ("bps", DataRate::from_bits_per_second),
+    ("kbps", DataRate::from_kilobits_per_second),
+    ("Mbps", DataRate::from_megabits_per_second),
+    ("Gbps", DataRate::from_gigabits_per_second),
+    ("B/s", DataRate::from_octets_per_second),
+    ("kB/s", DataRate::from_kilooctets_per_second),
+    ("MB/s", DataRate::from_megaoctets_per_second),
+    ("GB/s", |v| DataRate::from_megaoctets_per_second(v * 1e3)),
+    ("KiB/s", DataRate::from_kibioctets_per_second),
+    ("MiB/s", DataRate::from_mebioctets_per_second),
+    ("GiB/s", |v| {
+        DataRate::from_mebioctets_per_second(v * 1024.0)
+    }),
+    ("bit per second", DataRate::from_bits_per_second),
+    ("bits per second", DataRate::from_bits_per_second),
+    ("kilobit per second", DataRate::from_kilobits_per_second),
+    ("kilobits per second", DataRate::from_kilobits_per_second),
+    ("megabit per second", DataRate::from_megabits_per_second),
+    ("megabits per second", DataRate::from_megabits_per_second),
+    ("gigabit per second", DataRate::from_gigabits_per_second),
+    ("gigabits per second", DataRate::from_gigabits_per_second),
+    ("octet per second", DataRate::from_octets_per_second),
+    ("octets per second", DataRate::from_octets_per_second),
+];
+
+impl UnitNames for DataRate {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for DataRate {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use data_rate::*;
+    use test_utils::assert_almost_eq;
+    use time::Duration;
+
+    #[test]
+    fn bits() {
+        let r = DataRate::from_megabits_per_second(100.0);
+        assert_almost_eq(r.as_octets_per_second(), 12.5e6);
+        assert_almost_eq(r.as_kilobits_per_second(), 1e5);
+        assert_almost_eq(r.as_gigabits_per_second(), 0.1);
+        assert_almost_eq(
+            DataRate::from_bits_per_second(8.0).as_octets_per_second(),
+            1.0,
+        );
+    }
+
+    #[test]
+    fn octets() {
+        let r = DataRate::from_mebioctets_per_second(1.0);
+        assert_almost_eq(r.as_kibioctets_per_second(), 1024.0);
+        assert_almost_eq(r.as_octets_per_second(), 1_048_576.0);
+        let r = DataRate::from_megaoctets_per_second(1.0);
+        assert_almost_eq(r.as_kilooctets_per_second(), 1000.0);
+        assert_almost_eq(r.as_megabits_per_second(), 8.0);
+    }
+
+    #[test]
+    fn duration() {
+        let r = DataRate::from_megabits_per_second(100.0);
+        let d: Data = r * Duration::new(8, 0);
+        assert_almost_eq(d.as_megaoctets(), 100.0);
+        let t: Duration = Data::from_gigaoctets(1.0) / r;
+        assert_eq!(t, Duration::new(80, 0));
+        assert_eq!(
+            r.transfer_time(Data::from_megaoctets(25.0)),
+            Duration::new(2, 0)
+        );
+        let r2: DataRate = Data::from_kibioctets(512.0) / Duration::new(2, 0);
+        assert_almost_eq(r2.as_kibioctets_per_second(), 256.0);
+    }
+
+    #[test]
+    fn bandwidth_delay() {
+        let r = DataRate::from_gigabits_per_second(1.0);
+        let bdp = r.bandwidth_delay_product(Duration::from_millis(20));
+        assert_almost_eq(bdp.as_megaoctets(), 2.5);
+        let window = DataRate::from_window(Data::from_kibioctets(64.0), Duration::from_millis(50));
+        assert_almost_eq(window.as_bits_per_second(), 10_485_760.0);
+    }
+
+    #[test]
+    fn display() {
+        let r = DataRate::from_megaoctets_per_second(1.0);
+        assert_eq!(format!("{}", r), "8\u{00A0}Mbit/s");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: DataRate = "100 Mbit/s".parse().unwrap();
+        let b: DataRate = "20 MiB/s".parse().unwrap();
+        let c: DataRate = "56 kbps".parse().unwrap();
+        assert_almost_eq(a.as_megabits_per_second(), 100.0);
+        assert_almost_eq(b.as_mebioctets_per_second(), 20.0);
+        assert_almost_eq(c.as_bits_per_second(), 56_000.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = DataRate::from_unit(1.0, DataRateUnit::MebioctetPerSecond);
+        assert_almost_eq(a.as_unit(DataRateUnit::KibioctetPerSecond), 1024.0);
+        assert_almost_eq(a.as_unit(DataRateUnit::BitPerSecond), 8_388_608.0);
+    }
+}
@@ -0,0 +1,358 @@
+//! Exact, integer-backed amounts of data.
+//!
+//! `Data` holds its octets in an `f64`, which is convenient for rates and
+//! averages but cannot count past 2^53 octets exactly, and can end up with
+//! fractions of an octet. A `DataSize` holds a whole number of octets in a
+//! `u128`, with checked and saturating arithmetic like the integer types, and
+//! converts to and from `Data` when floating point arithmetic is wanted.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::{Data, DataSize};
+//! use std::convert::TryFrom;
+//!
+//! let file = DataSize::from_octets(9_007_199_254_740_993);
+//! let total = file.checked_add(DataSize::from_kibioctets(4)).unwrap();
+//! assert_eq!(total.as_octets(), 9_007_199_254_740_993 + 4096);
+//! assert!(DataSize::try_from(Data::from_bits(12.0)).is_err());
+//! ```
+
+use super::data::{Data, DataDisplay, DataDisplayMode};
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Sub};
+
+/// A whole number of octets.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DataSize {
+    octets: u128,
+}
+
+impl DataSize {
+    /// The largest DataSize that can be held.
+    pub const MAX: DataSize = DataSize { octets: u128::MAX };
+
+    /// Create a new DataSize from a number of octets
+    pub const fn from_octets(octets: u128) -> Self {
+        DataSize { octets }
+    }
+
+    /// Create a new DataSize from a number of kilooctets (1000 octets)
+    pub const fn from_kilooctets(kilooctets: u64) -> Self {
+        Self::from_octets(kilooctets as u128 * 1000)
+    }
+
+    /// Create a new DataSize from a number of megaoctets (1e6 octets)
+    pub const fn from_megaoctets(megaoctets: u64) -> Self {
+        Self::from_octets(megaoctets as u128 * 1_000_000)
+    }
+
+    /// Create a new DataSize from a number of gigaoctets (1e9 octets)
+    pub const fn from_gigaoctets(gigaoctets: u64) -> Self {
+        Self::from_octets(gigaoctets as u128 * 1_000_000_000)
+    }
+
+    /// Create a new DataSize from a number of teraoctets (1e12 octets)
+    pub const fn from_teraoctets(teraoctets: u64) -> Self {
+        Self::from_octets(teraoctets as u128 * 1_000_000_000_000)
+    }
+
+    /// Create a new DataSize from a number of kibioctets (1024 octets)
+    pub const fn from_kibioctets(kibioctets: u64) -> Self {
+        Self::from_octets((kibioctets as u128) << 10)
+    }
+
+    /// Create a new DataSize from a number of mebioctets (1024**2 octets)
+    pub const fn from_mebioctets(mebioctets: u64) -> Self {
+        Self::from_octets((mebioctets as u128) << 20)
+    }
+
+    /// Create a new DataSize from a number of gibioctets (1024**3 octets)
+    pub const fn from_gibioctets(gibioctets: u64) -> Self {
+        Self::from_octets((gibioctets as u128) << 30)
+    }
+
+    /// Create a new DataSize from a number of tebioctets (1024**4 octets)
+    pub const fn from_tebioctets(tebioctets: u64) -> Self {
+        Self::from_octets((tebioctets as u128) << 40)
+    }
+
+    /// The number of octets in this DataSize
+    pub const fn as_octets(&self) -> u128 {
+        self.octets
+    }
+
+    /// The number of bits in this DataSize, or `None` if it would overflow
+    pub fn as_bits(&self) -> Option<u128> {
+        self.octets.checked_mul(8)
+    }
+
+    /// Add two DataSizes, or return `None` on overflow
+    pub fn checked_add(self, rhs: DataSize) -> Option<DataSize> {
+        self.octets.checked_add(rhs.octets).map(Self::from_octets)
+    }
+
+    /// Subtract a DataSize, or return `None` if the result would be negative
+    pub fn checked_sub(self, rhs: DataSize) -> Option<DataSize> {
+        self.octets.checked_sub(rhs.octets).map(Self::from_octets)
+    }
+
+    /// Multiply by a count, or return `None` on overflow
+    pub fn checked_mul(self, rhs: u128) -> Option<DataSize> {
+        self.octets.checked_mul(rhs).map(Self::from_octets)
+    }
+
+    /// Divide by a count, rounding down, or return `None` if the count is
+    /// zero
+    pub fn checked_div(self, rhs: u128) -> Option<DataSize> {
+        self.octets.checked_div(rhs).map(Self::from_octets)
+    }
+
+    /// Add two DataSizes, stopping at `DataSize::MAX`
+    pub fn saturating_add(self, rhs: DataSize) -> DataSize {
+        Self::from_octets(self.octets.saturating_add(rhs.octets))
+    }
+
+    /// Subtract a DataSize, stopping at zero
+    pub fn saturating_sub(self, rhs: DataSize) -> DataSize {
+        Self::from_octets(self.octets.saturating_sub(rhs.octets))
+    }
+
+    /// Multiply by a count, stopping at `DataSize::MAX`
+    pub fn saturating_mul(self, rhs: u128) -> DataSize {
+        Self::from_octets(self.octets.saturating_mul(rhs))
+    }
+
+    /// Display this DataSize in the units of the given mode. Sizes too small
+    /// for a prefix are shown as a whole number of octets or bits.
+    pub fn display_in(&self, mode: DataDisplayMode) -> DataDisplay {
+        DataDisplay::exact(self.octets, mode)
+    }
+}
+
+/// Converts to the nearest `Data`. Sizes above 2^53 octets may be rounded.
+impl From<DataSize> for Data {
+    fn from(size: DataSize) -> Self {
+        Data::from_octets(size.octets as f64)
+    }
+}
+
+/// Converts exactly, failing if the `Data` is not a whole number of octets.
+impl TryFrom<Data> for DataSize {
+    type Error = DataSizeError;
+
+    fn try_from(data: Data) -> Result<Self, DataSizeError> {
+        let octets = data.as_octets();
+        if octets.is_nan() {
+            Err(DataSizeError::NotANumber)
+        } else if octets < 0.0 {
+            Err(DataSizeError::Negative)
+        } else if octets >= u128::MAX as f64 {
+            Err(DataSizeError::TooLarge)
+        } else if (octets as u128) as f64 != octets {
+            Err(DataSizeError::Fractional)
+        } else {
+            Ok(Self::from_octets(octets as u128))
+        }
+    }
+}
+
+impl From<u64> for DataSize {
+    fn from(octets: u64) -> Self {
+        Self::from_octets(u128::from(octets))
+    }
+}
+
+impl From<DataSize> for u128 {
+    fn from(size: DataSize) -> Self {
+        size.octets
+    }
+}
+
+impl TryFrom<DataSize> for u64 {
+    type Error = DataSizeError;
+
+    fn try_from(size: DataSize) -> Result<Self, DataSizeError> {
+        u64::try_from(size.octets).map_err(|_| DataSizeError::TooLarge)
+    }
+}
+
+/// Adds two DataSizes.
+///
+/// # Panics
+///
+/// On overflow, as the integer types do in debug builds. Use `checked_add`
+/// or `saturating_add` to handle this.
+impl Add for DataSize {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("DataSize overflow")
+    }
+}
+
+/// Subtracts a DataSize.
+///
+/// # Panics
+///
+/// If the result would be negative. Use `checked_sub` or `saturating_sub`
+/// to handle this.
+impl Sub for DataSize {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("DataSize underflow")
+    }
+}
+
+/// Formats with binary prefixes, as `Data` does, but never shows a fraction
+/// of an octet.
+impl fmt::Display for DataSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.display_in(DataDisplayMode::Iec), f)
+    }
+}
+
+/// The error returned when `Data` cannot be converted into a `DataSize`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DataSizeError {
+    /// The amount of data is not a number.
+    NotANumber,
+    /// The amount of data is negative.
+    Negative,
+    /// The amount of data is not a whole number of octets.
+    Fractional,
+    /// The amount of data is too large for the target type.
+    TooLarge,
+}
+
+impl fmt::Display for DataSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match *self {
+            DataSizeError::NotANumber => "amount of data is not a number",
+            DataSizeError::Negative => "amount of data is negative",
+            DataSizeError::Fractional => "amount of data is not a whole number of octets",
+            DataSizeError::TooLarge => "amount of data is too large",
+        };
+        f.write_str(description)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl ::std::error::Error for DataSizeError {}
+
+#[cfg(test)]
+mod test {
+    use data_size::*;
+
+    #[test]
+    fn constructors() {
+        assert_eq!(DataSize::from_kilooctets(3).as_octets(), 3000);
+        assert_eq!(DataSize::from_gigaoctets(2).as_octets(), 2_000_000_000);
+        assert_eq!(DataSize::from_teraoctets(1).as_octets(), 1_000_000_000_000);
+        assert_eq!(DataSize::from_kibioctets(3).as_octets(), 3072);
+        assert_eq!(DataSize::from_mebioctets(1).as_octets(), 1_048_576);
+        assert_eq!(DataSize::from_gibioctets(1).as_octets(), 1_073_741_824);
+        assert_eq!(DataSize::from_tebioctets(1).as_octets(), 1_099_511_627_776);
+        assert_eq!(
+            DataSize::from_tebioctets(u64::MAX).as_octets(),
+            u128::from(u64::MAX) << 40
+        );
+        assert_eq!(DataSize::from_octets(3).as_bits(), Some(24));
+        assert_eq!(DataSize::MAX.as_bits(), None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = DataSize::from_octets((1 << 53) + 1);
+        let b = DataSize::from_octets(1);
+        assert_eq!((a + b).as_octets(), (1 << 53) + 2);
+        assert_eq!((a - b).as_octets(), 1 << 53);
+        assert_eq!(b.checked_sub(a), None);
+        assert_eq!(b.saturating_sub(a), DataSize::from_octets(0));
+        assert_eq!(DataSize::MAX.checked_add(b), None);
+        assert_eq!(DataSize::MAX.saturating_add(b), DataSize::MAX);
+        assert_eq!(b.checked_mul(10), Some(DataSize::from_octets(10)));
+        assert_eq!(DataSize::MAX.saturating_mul(2), DataSize::MAX);
+        assert_eq!(
+            DataSize::from_octets(10).checked_div(3),
+            Some(DataSize::from_octets(3))
+        );
+        assert_eq!(b.checked_div(0), None);
+        assert!(a > b);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sub_underflow() {
+        let _ = DataSize::from_octets(1) - DataSize::from_octets(2);
+    }
+
+    #[test]
+    fn conversions() {
+        let size = DataSize::try_from(Data::from_kibioctets(1.5)).unwrap();
+        assert_eq!(size.as_octets(), 1536);
+        assert_eq!(Data::from(size).as_octets(), 1536.0);
+        assert_eq!(
+            DataSize::try_from(Data::from_bits(12.0)),
+            Err(DataSizeError::Fractional)
+        );
+        assert_eq!(
+            DataSize::try_from(Data::from_octets(-1.0)),
+            Err(DataSizeError::Negative)
+        );
+        assert_eq!(
+            DataSize::try_from(Data::from_octets(f64::NAN)),
+            Err(DataSizeError::NotANumber)
+        );
+        assert_eq!(
+            DataSize::try_from(Data::from_octets(1e40)),
+            Err(DataSizeError::TooLarge)
+        );
+        assert_eq!(u64::try_from(DataSize::from(42u64)), Ok(42));
+        assert_eq!(u64::try_from(DataSize::MAX), Err(DataSizeError::TooLarge));
+        assert_eq!(u128::from(DataSize::MAX), u128::MAX);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", DataSize::from_octets(1000)),
+            "1000\u{00A0}octets"
+        );
+        assert_eq!(
+            format!("{:.3}", DataSize::from_octets(7)),
+            "7\u{00A0}octets"
+        );
+        assert_eq!(format!("{}", DataSize::from_kibioctets(3)), "3\u{00A0}KiB");
+        assert_eq!(
+            format!(
+                "{}",
+                DataSize::from_octets(999).display_in(DataDisplayMode::Si)
+            ),
+            "999\u{00A0}octets"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                DataSize::from_octets(100).display_in(DataDisplayMode::Bits)
+            ),
+            "800\u{00A0}bits"
+        );
+        assert_eq!(
+            format!(
+                "{:.1}",
+                DataSize::from_megaoctets(1500).display_in(DataDisplayMode::Si)
+            ),
+            "1.5\u{00A0}GB"
+        );
+        assert_eq!(
+            format!(
+                "{:^12.3}",
+                DataSize::from_octets(100).display_in(DataDisplayMode::Si)
+            ),
+            " 100\u{00A0}octets "
+        );
+    }
+}
@@ -0,0 +1,404 @@
+//! Logarithmic levels in decibels.
+//!
+//! A `Decibel` is a level together with the reference it is measured
+//! against: 1 mW for dBm, 1 V for dBV, 20 µPa for dB SPL and so on. Power
+//! levels are ten times the logarithm of a power ratio, and voltage and sound
+//! pressure levels twenty times the logarithm of an amplitude ratio, so that
+//! a level means the same power whatever its reference.
+//!
+//! `checked_add` sums the powers two levels represent, as for two
+//! uncorrelated noise sources, so 3 dBm + 3 dBm is 6 dBm; it fails if the
+//! references measure different kinds of quantity, such as dBm and dBV. To
+//! apply a gain or a loss, add or subtract a plain number of decibels.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::{Decibel, Power};
+//!
+//! let level = Decibel::from_power_dbm(Power::from_milliwatts(2.0));
+//! let amplified = level + 20.0;
+//! println!("{} is {}", amplified, amplified.to_power().unwrap());
+//! ```
+
+use super::parse::{parse_single_measurement, ParseMeasurementError, UnitConstructor};
+use super::{Power, Pressure, Voltage};
+use std::fmt;
+use std::ops::{Add, Sub};
+
+/// The reference level of 0 dBu, the voltage which dissipates 1 mW in 600 Ω.
+const DBU_REFERENCE_VOLTS: f64 = 0.774_596_669_241_483_4;
+
+/// The reference level of 0 dB SPL, the quietest sound a person can hear.
+const SPL_REFERENCE_PASCALS: f64 = 20e-6;
+
+/// The reference a decibel level is measured against.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DecibelReference {
+    /// A plain ratio, such as a gain, with no reference level
+    Relative,
+    /// Power relative to 1 mW
+    Dbm,
+    /// Power relative to 1 W
+    Dbw,
+    /// Voltage relative to 1 V
+    Dbv,
+    /// Voltage relative to 0.775 V, which dissipates 1 mW in 600 Ω
+    Dbu,
+    /// Sound pressure relative to 20 µPa
+    DbSpl,
+}
+
+impl DecibelReference {
+    /// The unit symbol, such as "dBm".
+    pub fn symbol(&self) -> &'static str {
+        match *self {
+            DecibelReference::Relative => "dB",
+            DecibelReference::Dbm => "dBm",
+            DecibelReference::Dbw => "dBW",
+            DecibelReference::Dbv => "dBV",
+            DecibelReference::Dbu => "dBu",
+            DecibelReference::DbSpl => "dB SPL",
+        }
+    }
+
+    /// The level of this reference in another one, or `None` if the two
+    /// measure different kinds of quantity. Relative levels measure nothing,
+    /// so they only convert to themselves.
+    fn offset_in(&self, other: DecibelReference) -> Option<f64> {
+        let base = |reference: DecibelReference| match reference {
+            DecibelReference::Relative => (0, 0.0),
+            DecibelReference::Dbm => (1, -30.0),
+            DecibelReference::Dbw => (1, 0.0),
+            DecibelReference::Dbv => (2, 0.0),
+            DecibelReference::Dbu => (2, 20.0 * DBU_REFERENCE_VOLTS.log10()),
+            DecibelReference::DbSpl => (3, 0.0),
+        };
+        let (kind, offset) = base(*self);
+        let (other_kind, other_offset) = base(other);
+        if kind == other_kind {
+            Some(offset - other_offset)
+        } else {
+            None
+        }
+    }
+}
+
+/// A level in decibels, measured against a `DecibelReference`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Decibel {
+    value: f64,
+    reference: DecibelReference,
+}
+
+impl Decibel {
+    /// Create a new Decibel from a floating point value in decibels and the
+    /// reference it is measured against
+    pub fn new(value: f64, reference: DecibelReference) -> Self {
+        Decibel { value, reference }
+    }
+
+    /// Create a new relative Decibel from a floating point value in dB
+    pub fn from_db(db: f64) -> Self {
+        Self::new(db, DecibelReference::Relative)
+    }
+
+    /// Create a new Decibel from a floating point value in dBm
+    pub fn from_dbm(dbm: f64) -> Self {
+        Self::new(dbm, DecibelReference::Dbm)
+    }
+
+    /// Create a new Decibel from a floating point value in dBW
+    pub fn from_dbw(dbw: f64) -> Self {
+        Self::new(dbw, DecibelReference::Dbw)
+    }
+
+    /// Create a new Decibel from a floating point value in dBV
+    pub fn from_dbv(dbv: f64) -> Self {
+        Self::new(dbv, DecibelReference::Dbv)
+    }
+
+    /// Create a new Decibel from a floating point value in dBu
+    pub fn from_dbu(dbu: f64) -> Self {
+        Self::new(dbu, DecibelReference::Dbu)
+    }
+
+    /// Create a new Decibel from a floating point value in dB SPL
+    pub fn from_db_spl(db_spl: f64) -> Self {
+        Self::new(db_spl, DecibelReference::DbSpl)
+    }
+
+    /// Create a new relative Decibel from a ratio of two powers
+    pub fn from_power_ratio(ratio: f64) -> Self {
+        Self::from_db(10.0 * ratio.log10())
+    }
+
+    /// Create a new relative Decibel from a ratio of two amplitudes, such as
+    /// voltages or sound pressures
+    pub fn from_amplitude_ratio(ratio: f64) -> Self {
+        Self::from_db(20.0 * ratio.log10())
+    }
+
+    /// Create a new Decibel in dBm from a Power
+    pub fn from_power_dbm(power: Power) -> Self {
+        Self::from_dbm(10.0 * (power.as_watts() / 1e-3).log10())
+    }
+
+    /// Create a new Decibel in dBW from a Power
+    pub fn from_power_dbw(power: Power) -> Self {
+        Self::from_dbw(10.0 * power.as_watts().log10())
+    }
+
+    /// Create a new Decibel in dBV from an RMS Voltage
+    pub fn from_voltage_dbv(voltage: Voltage) -> Self {
+        Self::from_dbv(20.0 * voltage.as_volts().log10())
+    }
+
+    /// Create a new Decibel in dBu from an RMS Voltage
+    pub fn from_voltage_dbu(voltage: Voltage) -> Self {
+        Self::from_dbu(20.0 * (voltage.as_volts() / DBU_REFERENCE_VOLTS).log10())
+    }
+
+    /// Create a new Decibel in dB SPL from an RMS sound Pressure
+    pub fn from_pressure_spl(pressure: Pressure) -> Self {
+        Self::from_db_spl(20.0 * (pressure.as_pascals() / SPL_REFERENCE_PASCALS).log10())
+    }
+
+    /// The level as a floating point value in decibels
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The reference this level is measured against
+    pub fn reference(&self) -> DecibelReference {
+        self.reference
+    }
+
+    /// The same level measured against another reference, such as dBm to
+    /// dBW, or `None` if the references measure different kinds of quantity.
+    pub fn to_reference(&self, reference: DecibelReference) -> Option<Decibel> {
+        self.reference
+            .offset_in(reference)
+            .map(|offset| Self::new(self.value + offset, reference))
+    }
+
+    /// Convert this level into the ratio of two powers
+    pub fn as_power_ratio(&self) -> f64 {
+        10f64.powf(self.value / 10.0)
+    }
+
+    /// Convert this level into the ratio of two amplitudes
+    pub fn as_amplitude_ratio(&self) -> f64 {
+        10f64.powf(self.value / 20.0)
+    }
+
+    /// Convert a dBm or dBW level into a Power
+    pub fn to_power(&self) -> Option<Power> {
+        self.to_reference(DecibelReference::Dbw)
+            .map(|dbw| Power::from_watts(dbw.as_power_ratio()))
+    }
+
+    /// Convert a dBV or dBu level into an RMS Voltage
+    pub fn to_voltage(&self) -> Option<Voltage> {
+        self.to_reference(DecibelReference::Dbv)
+            .map(|dbv| Voltage::from_volts(dbv.as_amplitude_ratio()))
+    }
+
+    /// Convert a dB SPL level into an RMS sound Pressure
+    pub fn to_pressure(&self) -> Option<Pressure> {
+        self.to_reference(DecibelReference::DbSpl)
+            .map(|spl| Pressure::from_pascals(SPL_REFERENCE_PASCALS * spl.as_amplitude_ratio()))
+    }
+
+    /// Add the powers of two levels, giving the result in this level's
+    /// reference, or `None` if the references measure different kinds of
+    /// quantity.
+    pub fn checked_add(self, rhs: Decibel) -> Option<Decibel> {
+        rhs.to_reference(self.reference).map(|rhs| {
+            Self::from_power_ratio(self.as_power_ratio() + rhs.as_power_ratio())
+                .with_reference(self.reference)
+        })
+    }
+
+    /// Add the powers of several levels, such as the noise from several
+    /// sources. All of the levels must be measured against the given
+    /// reference, or one which converts to it.
+    pub fn sum<I: IntoIterator<Item = Decibel>>(
+        levels: I,
+        reference: DecibelReference,
+    ) -> Option<Decibel> {
+        let mut total = 0.0;
+        for level in levels {
+            total += level.to_reference(reference)?.as_power_ratio();
+        }
+        Some(Self::from_power_ratio(total).with_reference(reference))
+    }
+
+    fn with_reference(self, reference: DecibelReference) -> Self {
+        Self::new(self.value, reference)
+    }
+}
+
+/// Applies a gain in dB.
+impl Add<f64> for Decibel {
+    type Output = Self;
+
+    fn add(self, rhs: f64) -> Self {
+        Self::new(self.value + rhs, self.reference)
+    }
+}
+
+/// Applies a loss in dB.
+impl Sub<f64> for Decibel {
+    type Output = Self;
+
+    fn sub(self, rhs: f64) -> Self {
+        Self::new(self.value - rhs, self.reference)
+    }
+}
+
+impl fmt::Display for Decibel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        write!(f, "\u{00A0}{}", self.reference.symbol())
+    }
+}
+
+/// The unit names understood when parsing a `Decibel`.
+const UNIT_NAMES: &[UnitConstructor<'static, Decibel>] = &[
+    ("dB", Decibel::from_db),
+    ("dBm", Decibel::from_dbm),
+    ("dBW", Decibel::from_dbw),
+    ("dBV", Decibel::from_dbv),
+    ("dBu", Decibel::from_dbu),
+    ("dB SPL", Decibel::from_db_spl),
+    ("dBSPL", Decibel::from_db_spl),
+];
+
+impl ::std::str::FromStr for Decibel {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_single_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use decibel::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn ratios() {
+        assert_almost_eq(Decibel::from_power_ratio(100.0).value(), 20.0);
+        assert_almost_eq(Decibel::from_amplitude_ratio(100.0).value(), 40.0);
+        assert_almost_eq(
+            Decibel::from_db(-3.0).as_power_ratio(),
+            0.501_187_233_627_272_3,
+        );
+        assert_almost_eq(
+            Decibel::from_db(6.0).as_amplitude_ratio(),
+            1.995_262_314_968_879_6,
+        );
+    }
+
+    #[test]
+    fn power() {
+        let level = Decibel::from_power_dbm(Power::from_watts(2.0));
+        assert_eq!(level.reference(), DecibelReference::Dbm);
+        assert_almost_eq(level.value(), 33.010_299_956_639_81);
+        assert_almost_eq(
+            Decibel::from_dbw(-30.0).to_power().unwrap().as_milliwatts(),
+            1.0,
+        );
+        assert_almost_eq(
+            Decibel::from_power_dbw(Power::from_kilowatts(1.0)).value(),
+            30.0,
+        );
+        assert_almost_eq(
+            Decibel::from_dbm(20.0)
+                .to_reference(DecibelReference::Dbw)
+                .unwrap()
+                .value(),
+            -10.0,
+        );
+        assert_eq!(Decibel::from_dbm(0.0).to_voltage(), None);
+    }
+
+    #[test]
+    fn voltage() {
+        let dbu = Decibel::from_voltage_dbu(Voltage::from_volts(1.0));
+        assert_almost_eq(dbu.value(), 2.218_487_496_163_564);
+        let dbv = dbu.to_reference(DecibelReference::Dbv).unwrap();
+        assert!(dbv.value().abs() < 1e-12);
+        assert_almost_eq(
+            Decibel::from_dbu(0.0).to_voltage().unwrap().as_volts(),
+            0.774_596_669_241_483_4,
+        );
+        assert_almost_eq(
+            Decibel::from_voltage_dbv(Voltage::from_volts(10.0)).value(),
+            20.0,
+        );
+        assert_eq!(Decibel::from_dbv(0.0).to_power(), None);
+    }
+
+    #[test]
+    fn pressure() {
+        let spl = Decibel::from_pressure_spl(Pressure::from_pascals(1.0));
+        assert_almost_eq(spl.value(), 93.979_400_086_720_38);
+        assert_almost_eq(
+            Decibel::from_db_spl(0.0)
+                .to_pressure()
+                .unwrap()
+                .as_pascals(),
+            20e-6,
+        );
+        assert_eq!(Decibel::from_db(0.0).to_pressure(), None);
+    }
+
+    #[test]
+    fn addition() {
+        let sum = Decibel::from_dbm(3.0).checked_add(Decibel::from_dbm(3.0));
+        assert_almost_eq(sum.unwrap().value(), 6.010_299_956_639_812);
+        let mixed = Decibel::from_dbm(30.0)
+            .checked_add(Decibel::from_dbw(0.0))
+            .unwrap();
+        assert_eq!(mixed.reference(), DecibelReference::Dbm);
+        assert_almost_eq(mixed.value(), 33.010_299_956_639_81);
+        assert_eq!(
+            Decibel::from_dbm(0.0).checked_add(Decibel::from_dbv(0.0)),
+            None
+        );
+        assert_eq!(
+            Decibel::from_dbm(0.0).checked_add(Decibel::from_db_spl(0.0)),
+            None
+        );
+        let total = Decibel::sum(
+            vec![Decibel::from_db_spl(90.0); 10],
+            DecibelReference::DbSpl,
+        );
+        assert_almost_eq(total.unwrap().value(), 100.0);
+        assert_almost_eq((Decibel::from_dbm(-10.0) + 13.0).value(), 3.0);
+        assert_almost_eq((Decibel::from_dbm(-10.0) - 3.0).value(), -13.0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Decibel::from_dbm(-3.5)), "-3.5\u{00A0}dBm");
+        assert_eq!(
+            format!("{:.1}", Decibel::from_db_spl(94.0)),
+            "94.0\u{00A0}dB SPL"
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let a: Decibel = "-10 dBm".parse().unwrap();
+        let b: Decibel = "94 dB SPL".parse().unwrap();
+        let c: Decibel = "+4 dBu".parse().unwrap();
+        assert_eq!(a, Decibel::from_dbm(-10.0));
+        assert_eq!(b, Decibel::from_db_spl(94.0));
+        assert_eq!(c, Decibel::from_dbu(4.0));
+        assert!("3 dBx".parse::<Decibel>().is_err());
+    }
+}
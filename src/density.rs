@@ -0,0 +1,354 @@
+//! Types and constants for handling density (mass per unit volume).
+
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::*;
+
+/// Number of kg/L in a kg/m³
+pub const KILOGRAMS_PER_LITER_KILOGRAMS_PER_CUBIC_METER_FACTOR: f64 = 1e-3;
+/// Number of kg/L in a lb/ft³
+pub const KILOGRAMS_PER_LITER_POUNDS_PER_CUBIC_FOOT_FACTOR: f64 =
+    volume::LITER_CUBIC_FEET_FACTOR / mass::KILOGRAM_POUNDS_FACTOR;
+/// Number of kg/L in a lb/gal (US)
+pub const KILOGRAMS_PER_LITER_POUNDS_PER_GALLON_FACTOR: f64 =
+    volume::LITER_GALLONS_FACTOR / mass::KILOGRAM_POUNDS_FACTOR;
+/// The density of water at 4 °C, in kg/L, which specific gravity is
+/// relative to
+pub const WATER_KILOGRAMS_PER_LITER: f64 = 0.999972;
+
+/// The `Density` struct can be used to deal with densities in a common way.
+/// Common metric and imperial units are supported, as is specific gravity
+/// (relative to water at 4 °C).
+///
+/// Like `Volume`, which is stored in litres, a Density is stored in kg/L
+/// (which is the same as g/cm³ and g/mL), so that Mass = Density × Volume.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Density, Volume};
+///
+/// let milk = Density::from_grams_per_milliliter(1.03);
+/// let mass = milk * Volume::from_liters(2.0);
+/// println!("Two litres of milk weigh {}.", mass);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Density {
+    kilograms_per_liter: f64,
+}
+
+impl Density {
+    /// Create a new Density from a floating point value in kilograms per
+    /// cubic meter
+    pub fn from_kilograms_per_cubic_meter(kilograms_per_cubic_meter: f64) -> Self {
+        Density::from_kilograms_per_liter(
+            kilograms_per_cubic_meter * KILOGRAMS_PER_LITER_KILOGRAMS_PER_CUBIC_METER_FACTOR,
+        )
+    }
+
+    /// Create a new Density from a floating point value in kilograms per
+    /// cubic metre
+    pub fn from_kilograms_per_cubic_metre(kilograms_per_cubic_metre: f64) -> Self {
+        Density::from_kilograms_per_cubic_meter(kilograms_per_cubic_metre)
+    }
+
+    /// Create a new Density from a floating point value in kilograms per
+    /// liter
+    pub fn from_kilograms_per_liter(kilograms_per_liter: f64) -> Self {
+        Density { kilograms_per_liter }
+    }
+
+    /// Create a new Density from a floating point value in kilograms per
+    /// litre
+    pub fn from_kilograms_per_litre(kilograms_per_litre: f64) -> Self {
+        Density::from_kilograms_per_liter(kilograms_per_litre)
+    }
+
+    /// Create a new Density from a floating point value in grams per cubic
+    /// centimeter
+    pub fn from_grams_per_cubic_centimeter(grams_per_cubic_centimeter: f64) -> Self {
+        Density::from_kilograms_per_liter(grams_per_cubic_centimeter)
+    }
+
+    /// Create a new Density from a floating point value in grams per cubic
+    /// centimetre
+    pub fn from_grams_per_cubic_centimetre(grams_per_cubic_centimetre: f64) -> Self {
+        Density::from_grams_per_cubic_centimeter(grams_per_cubic_centimetre)
+    }
+
+    /// Create a new Density from a floating point value in grams per
+    /// milliliter
+    pub fn from_grams_per_milliliter(grams_per_milliliter: f64) -> Self {
+        Density::from_kilograms_per_liter(grams_per_milliliter)
+    }
+
+    /// Create a new Density from a floating point value in grams per
+    /// millilitre
+    pub fn from_grams_per_millilitre(grams_per_millilitre: f64) -> Self {
+        Density::from_grams_per_milliliter(grams_per_millilitre)
+    }
+
+    /// Create a new Density from a floating point value in pounds per cubic
+    /// foot
+    pub fn from_pounds_per_cubic_foot(pounds_per_cubic_foot: f64) -> Self {
+        Density::from_kilograms_per_liter(
+            pounds_per_cubic_foot * KILOGRAMS_PER_LITER_POUNDS_PER_CUBIC_FOOT_FACTOR,
+        )
+    }
+
+    /// Create a new Density from a floating point value in pounds per (US)
+    /// gallon
+    pub fn from_pounds_per_gallon(pounds_per_gallon: f64) -> Self {
+        Density::from_kilograms_per_liter(
+            pounds_per_gallon * KILOGRAMS_PER_LITER_POUNDS_PER_GALLON_FACTOR,
+        )
+    }
+
+    /// Create a new Density from a specific gravity, which is the ratio of a
+    /// density to that of water at 4 °C
+    pub fn from_specific_gravity(specific_gravity: f64) -> Self {
+        Density::from_kilograms_per_liter(specific_gravity * WATER_KILOGRAMS_PER_LITER)
+    }
+
+    /// Convert this Density into a floating point value in kilograms per
+    /// cubic meter
+    pub fn as_kilograms_per_cubic_meter(&self) -> f64 {
+        self.kilograms_per_liter / KILOGRAMS_PER_LITER_KILOGRAMS_PER_CUBIC_METER_FACTOR
+    }
+
+    /// Convert this Density into a floating point value in kilograms per
+    /// cubic metre
+    pub fn as_kilograms_per_cubic_metre(&self) -> f64 {
+        self.as_kilograms_per_cubic_meter()
+    }
+
+    /// Convert this Density into a floating point value in kilograms per
+    /// liter
+    pub fn as_kilograms_per_liter(&self) -> f64 {
+        self.kilograms_per_liter
+    }
+
+    /// Convert this Density into a floating point value in kilograms per
+    /// litre
+    pub fn as_kilograms_per_litre(&self) -> f64 {
+        self.as_kilograms_per_liter()
+    }
+
+    /// Convert this Density into a floating point value in grams per cubic
+    /// centimeter
+    pub fn as_grams_per_cubic_centimeter(&self) -> f64 {
+        self.kilograms_per_liter
+    }
+
+    /// Convert this Density into a floating point value in grams per cubic
+    /// centimetre
+    pub fn as_grams_per_cubic_centimetre(&self) -> f64 {
+        self.as_grams_per_cubic_centimeter()
+    }
+
+    /// Convert this Density into a floating point value in grams per
+    /// milliliter
+    pub fn as_grams_per_milliliter(&self) -> f64 {
+        self.kilograms_per_liter
+    }
+
+    /// Convert this Density into a floating point value in grams per
+    /// millilitre
+    pub fn as_grams_per_millilitre(&self) -> f64 {
+        self.as_grams_per_milliliter()
+    }
+
+    /// Convert this Density into a floating point value in pounds per cubic
+    /// foot
+    pub fn as_pounds_per_cubic_foot(&self) -> f64 {
+        self.kilograms_per_liter / KILOGRAMS_PER_LITER_POUNDS_PER_CUBIC_FOOT_FACTOR
+    }
+
+    /// Convert this Density into a floating point value in pounds per (US)
+    /// gallon
+    pub fn as_pounds_per_gallon(&self) -> f64 {
+        self.kilograms_per_liter / KILOGRAMS_PER_LITER_POUNDS_PER_GALLON_FACTOR
+    }
+
+    /// Convert this Density into a specific gravity, which is the ratio of
+    /// this density to that of water at 4 °C
+    pub fn as_specific_gravity(&self) -> f64 {
+        self.kilograms_per_liter / WATER_KILOGRAMS_PER_LITER
+    }
+}
+
+impl Measurement for Density {
+    fn as_base_units(&self) -> f64 {
+        self.kilograms_per_liter
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_kilograms_per_liter(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "kg/L"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = [
+            ("mg/m\u{00B3}", 1e-9),
+            ("g/m\u{00B3}", 1e-6),
+            ("kg/m\u{00B3}", 1e-3),
+            ("g/cm\u{00B3}", 1e0),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { Density }
+
+implement_units! {
+    Density, DensityUnit {
+        KilogramPerCubicMeter => ("kg/m\u{00B3}", "kilogram per cubic meter", "kilograms per cubic meter", KILOGRAMS_PER_LITER_KILOGRAMS_PER_CUBIC_METER_FACTOR) in Si,
+        KilogramPerLiter => ("kg/L", "kilogram per liter", "kilograms per liter", 1.0),
+        GramPerCubicCentimeter => ("g/cm\u{00B3}", "gram per cubic centimeter", "grams per cubic centimeter", 1.0),
+        GramPerMilliliter => ("g/mL", "gram per milliliter", "grams per milliliter", 1.0),
+        PoundPerCubicFoot => ("lb/ft\u{00B3}", "pound per cubic foot", "pounds per cubic foot", KILOGRAMS_PER_LITER_POUNDS_PER_CUBIC_FOOT_FACTOR) in UsCustomary | Imperial,
+        PoundPerGallon => ("lb/gal", "pound per gallon", "pounds per gallon", KILOGRAMS_PER_LITER_POUNDS_PER_GALLON_FACTOR) in UsCustomary,
+        SpecificGravity => ("SG", "specific gravity", "specific gravity", WATER_KILOGRAMS_PER_LITER),
+    }
+}
+
+/// The unit names understood when parsing a `Density`.
+const UNIT_NAMES: &[UnitConstructor<'static, Density>] = &[
+    ("mg/m\u{00B3}", |v| {
+        Density::from_kilograms_per_liter(v * 1e-9)
+    }),
+    ("g/m\u{00B3}", |v| {
+        Density::from_kilograms_per_liter(v * 1e-6)
+    }),
+    ("kg/m\u{00B3}", Density::from_kilograms_per_cubic_meter),
+    ("kg/m3", Density::from_kilograms_per_cubic_meter),
+    ("kg/L", Density::from_kilograms_per_liter),
+    ("g/cm\u{00B3}", Density::from_grams_per_cubic_centimeter),
+    ("g/cm3", Density::from_grams_per_cubic_centimeter),
+    ("g/cc", Density::from_grams_per_cubic_centimeter),
+    ("g/mL", Density::from_grams_per_milliliter),
+    ("g/ml", Density::from_grams_per_milliliter),
+    ("lb/ft\u{00B3}", Density::from_pounds_per_cubic_foot),
+    ("lb/ft3", Density::from_pounds_per_cubic_foot),
+    ("lb/cu ft", Density::from_pounds_per_cubic_foot),
+    ("lb/gal", Density::from_pounds_per_gallon),
+    ("SG", Density::from_specific_gravity),
+    (
+        "kilograms per cubic meter",
+        Density::from_kilograms_per_cubic_meter,
+    ),
+    (
+        "kilograms per cubic metre",
+        Density::from_kilograms_per_cubic_meter,
+    ),
+    ("kilograms per liter", Density::from_kilograms_per_liter),
+    ("kilograms per litre", Density::from_kilograms_per_liter),
+    (
+        "grams per cubic centimeter",
+        Density::from_grams_per_cubic_centimeter,
+    ),
+    (
+        "grams per cubic centimetre",
+        Density::from_grams_per_cubic_centimeter,
+    ),
+    ("grams per milliliter", Density::from_grams_per_milliliter),
+    ("grams per millilitre", Density::from_grams_per_milliliter),
+    ("pounds per cubic foot", Density::from_pounds_per_cubic_foot),
+    ("pounds per gallon", Density::from_pounds_per_gallon),
+    ("specific gravity", Density::from_specific_gravity),
+];
+
+impl UnitNames for Density {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Density {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use density::*;
+    use mass::Mass;
+    use test_utils::assert_almost_eq;
+    use volume::Volume;
+
+    #[test]
+    fn kilograms_per_cubic_meter() {
+        let d = Density::from_kilograms_per_cubic_meter(1000.0);
+        assert_almost_eq(d.as_grams_per_cubic_centimeter(), 1.0);
+        assert_almost_eq(d.as_grams_per_milliliter(), 1.0);
+        assert_almost_eq(d.as_kilograms_per_liter(), 1.0);
+    }
+
+    #[test]
+    fn imperial() {
+        let water = Density::from_kilograms_per_cubic_meter(1000.0);
+        assert_almost_eq(water.as_pounds_per_cubic_foot(), 62.42796);
+        assert_almost_eq(water.as_pounds_per_gallon(), 8.345404);
+        let d = Density::from_pounds_per_cubic_foot(62.42796);
+        assert_almost_eq(d.as_kilograms_per_cubic_meter(), 1000.0);
+    }
+
+    #[test]
+    fn specific_gravity() {
+        let d = Density::from_specific_gravity(7.85);
+        assert_almost_eq(d.as_kilograms_per_cubic_meter(), 7849.7802);
+        assert_almost_eq(d.as_specific_gravity(), 7.85);
+    }
+
+    #[test]
+    fn mass_and_volume() {
+        let d = Density::from_kilograms_per_cubic_meter(800.0);
+        let m: Mass = d * Volume::from_cubic_meters(2.0);
+        assert_almost_eq(m.as_kilograms(), 1600.0);
+        let m: Mass = Volume::from_liters(500.0) * d;
+        assert_almost_eq(m.as_kilograms(), 400.0);
+        let v: Volume = Mass::from_kilograms(400.0) / d;
+        assert_almost_eq(v.as_liters(), 500.0);
+        let d: Density = Mass::from_grams(20.0) / Volume::from_milliliters(10.0);
+        assert_almost_eq(d.as_grams_per_cubic_centimeter(), 2.0);
+    }
+
+    #[test]
+    fn quantity() {
+        use quantity::Dimensioned;
+        let d = Density::from_grams_per_cubic_centimeter(2.0);
+        assert_almost_eq(d.to_quantity().value(), 2000.0);
+        let e: Density = ::expression::evaluate("4 kg / 2 L").unwrap().downcast().unwrap();
+        assert_almost_eq(e.as_kilograms_per_cubic_meter(), 2000.0);
+    }
+
+    #[test]
+    fn display() {
+        let air = Density::from_kilograms_per_cubic_meter(1.2);
+        assert_eq!(format!("{}", air), "1.2\u{00A0}kg/m\u{00B3}");
+        let steel = Density::from_grams_per_cubic_centimeter(7.85);
+        assert_eq!(format!("{}", steel), "7.85\u{00A0}g/cm\u{00B3}");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: Density = "1000 kg/m3".parse().unwrap();
+        let b: Density = "62.4 lb/ft\u{00B3}".parse().unwrap();
+        assert_almost_eq(a.as_grams_per_milliliter(), 1.0);
+        assert_almost_eq(b.as_pounds_per_cubic_foot(), 62.4);
+    }
+
+    #[test]
+    fn units() {
+        let a = Density::from_unit(1.0, DensityUnit::SpecificGravity);
+        assert_almost_eq(a.as_kilograms_per_cubic_meter(), 999.972);
+        assert_almost_eq(a.as_unit(DensityUnit::KilogramPerLiter), 0.999972);
+    }
+}
@@ -0,0 +1,351 @@
+//! Type-level SI dimensions, for use with `Quantity`.
+//!
+//! A dimension is a `Dim` whose seven type parameters are the exponents of
+//! the SI base dimensions: length, mass, time, electric current,
+//! thermodynamic temperature, amount of substance and luminous intensity.
+//! Each exponent is a type-level integer built from `Zero`, `Inc` and `Dec`,
+//! so a Force (kg·m·s⁻²) is `Dim<P1, P1, N2, Z0, Z0, Z0, Z0>`.
+//!
+//! The `Product` and `Quotient` traits work out the dimension of the product
+//! or quotient of two dimensions while the program is being compiled.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A type-level integer.
+pub trait Integer {
+    /// The value of this integer.
+    const VALUE: i32;
+    /// This integer plus one.
+    type Inc: Integer;
+    /// This integer minus one.
+    type Dec: Integer;
+    /// This integer negated.
+    type Neg: Integer;
+}
+
+/// The type-level integer zero.
+#[derive(Copy, Clone, Debug)]
+pub struct Zero;
+
+/// The type-level integer one more than `T`, where `T` is zero or positive.
+#[derive(Copy, Clone, Debug)]
+pub struct Inc<T>(PhantomData<T>);
+
+/// The type-level integer one less than `T`, where `T` is zero or negative.
+#[derive(Copy, Clone, Debug)]
+pub struct Dec<T>(PhantomData<T>);
+
+impl Integer for Zero {
+    const VALUE: i32 = 0;
+    type Inc = Inc<Zero>;
+    type Dec = Dec<Zero>;
+    type Neg = Zero;
+}
+
+impl<T: Integer> Integer for Inc<T> {
+    const VALUE: i32 = T::VALUE + 1;
+    type Inc = Inc<Inc<T>>;
+    type Dec = T;
+    type Neg = <T::Neg as Integer>::Dec;
+}
+
+impl<T: Integer> Integer for Dec<T> {
+    const VALUE: i32 = T::VALUE - 1;
+    type Inc = T;
+    type Dec = Dec<Dec<T>>;
+    type Neg = <T::Neg as Integer>::Inc;
+}
+
+/// Adds type-level integers.
+pub trait Sum<Rhs> {
+    /// The sum of `Self` and `Rhs`.
+    type Output: Integer;
+}
+
+impl<R: Integer> Sum<R> for Zero {
+    type Output = R;
+}
+
+impl<T: Integer + Sum<R>, R: Integer> Sum<R> for Inc<T> {
+    type Output = <<T as Sum<R>>::Output as Integer>::Inc;
+}
+
+impl<T: Integer + Sum<R>, R: Integer> Sum<R> for Dec<T> {
+    type Output = <<T as Sum<R>>::Output as Integer>::Dec;
+}
+
+/// Subtracts type-level integers.
+pub trait Difference<Rhs> {
+    /// `Self` minus `Rhs`.
+    type Output: Integer;
+}
+
+impl<L: Sum<R::Neg>, R: Integer> Difference<R> for L {
+    type Output = <L as Sum<R::Neg>>::Output;
+}
+
+/// Zero
+pub type Z0 = Zero;
+/// One
+pub type P1 = Inc<Z0>;
+/// Two
+pub type P2 = Inc<P1>;
+/// Three
+pub type P3 = Inc<P2>;
+/// Four
+pub type P4 = Inc<P3>;
+/// Minus one
+pub type N1 = Dec<Z0>;
+/// Minus two
+pub type N2 = Dec<N1>;
+/// Minus three
+pub type N3 = Dec<N2>;
+/// Minus four
+pub type N4 = Dec<N3>;
+
+/// A physical dimension, as the exponents of the seven SI base dimensions:
+/// length (`L`), mass (`M`), time (`T`), electric current (`I`),
+/// thermodynamic temperature (`Th`), amount of substance (`Mol`) and luminous
+/// intensity (`J`).
+#[derive(Copy, Clone, Debug)]
+pub struct Dim<L, M, T, I, Th, Mol, J>(PhantomData<(L, M, T, I, Th, Mol, J)>);
+
+/// Implemented by every `Dim`.
+pub trait Dimension {
+    /// The exponents of length, mass, time, electric current, thermodynamic
+    /// temperature, amount of substance and luminous intensity.
+    const EXPONENTS: [i32; 7];
+}
+
+impl<L, M, T, I, Th, Mol, J> Dimension for Dim<L, M, T, I, Th, Mol, J>
+where
+    L: Integer,
+    M: Integer,
+    T: Integer,
+    I: Integer,
+    Th: Integer,
+    Mol: Integer,
+    J: Integer,
+{
+    const EXPONENTS: [i32; 7] = [
+        L::VALUE,
+        M::VALUE,
+        T::VALUE,
+        I::VALUE,
+        Th::VALUE,
+        Mol::VALUE,
+        J::VALUE,
+    ];
+}
+
+/// Multiplies dimensions.
+pub trait Product<Rhs> {
+    /// The dimension of `Self` times `Rhs`.
+    type Output: Dimension;
+}
+
+/// Divides dimensions.
+pub trait Quotient<Rhs> {
+    /// The dimension of `Self` divided by `Rhs`.
+    type Output: Dimension;
+}
+
+impl<L1, M1, T1, I1, Th1, Mol1, J1, L2, M2, T2, I2, Th2, Mol2, J2>
+    Product<Dim<L2, M2, T2, I2, Th2, Mol2, J2>> for Dim<L1, M1, T1, I1, Th1, Mol1, J1>
+where
+    L1: Sum<L2>,
+    M1: Sum<M2>,
+    T1: Sum<T2>,
+    I1: Sum<I2>,
+    Th1: Sum<Th2>,
+    Mol1: Sum<Mol2>,
+    J1: Sum<J2>,
+{
+    type Output =
+        Dim<L1::Output, M1::Output, T1::Output, I1::Output, Th1::Output, Mol1::Output, J1::Output>;
+}
+
+impl<L1, M1, T1, I1, Th1, Mol1, J1, L2, M2, T2, I2, Th2, Mol2, J2>
+    Quotient<Dim<L2, M2, T2, I2, Th2, Mol2, J2>> for Dim<L1, M1, T1, I1, Th1, Mol1, J1>
+where
+    L1: Difference<L2>,
+    M1: Difference<M2>,
+    T1: Difference<T2>,
+    I1: Difference<I2>,
+    Th1: Difference<Th2>,
+    Mol1: Difference<Mol2>,
+    J1: Difference<J2>,
+{
+    type Output =
+        Dim<L1::Output, M1::Output, T1::Output, I1::Output, Th1::Output, Mol1::Output, J1::Output>;
+}
+
+/// No dimension: a plain number, or a ratio such as an angle in radians
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// Length (m)
+pub type Length = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// Mass (kg)
+pub type Mass = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+/// Time (s)
+pub type Time = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+/// Electric current (A)
+pub type Current = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+/// Thermodynamic temperature (K)
+pub type Temperature = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+/// Amount of substance (mol)
+pub type Amount = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+/// Luminous intensity (cd)
+pub type LuminousIntensity = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+/// Area (m²)
+pub type Area = Dim<P2, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// Volume (m³)
+pub type Volume = Dim<P3, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// Frequency (Hz)
+pub type Frequency = Dim<Z0, Z0, N1, Z0, Z0, Z0, Z0>;
+/// Speed (m/s)
+pub type Speed = Dim<P1, Z0, N1, Z0, Z0, Z0, Z0>;
+/// Acceleration (m/s²)
+pub type Acceleration = Dim<P1, Z0, N2, Z0, Z0, Z0, Z0>;
+/// Force (N)
+pub type Force = Dim<P1, P1, N2, Z0, Z0, Z0, Z0>;
+/// Pressure (Pa)
+pub type Pressure = Dim<N1, P1, N2, Z0, Z0, Z0, Z0>;
+/// Energy, and also Torque (J)
+pub type Energy = Dim<P2, P1, N2, Z0, Z0, Z0, Z0>;
+/// Power (W)
+pub type Power = Dim<P2, P1, N3, Z0, Z0, Z0, Z0>;
+/// Voltage (V)
+pub type Voltage = Dim<P2, P1, N3, N1, Z0, Z0, Z0>;
+/// Resistance (Ω)
+pub type Resistance = Dim<P2, P1, N3, N2, Z0, Z0, Z0>;
+/// Density (kg/m³)
+pub type Density = Dim<N3, P1, Z0, Z0, Z0, Z0, Z0>;
+/// Volumetric flow rate (m³/s)
+pub type VolumetricFlowRate = Dim<P3, Z0, N1, Z0, Z0, Z0, Z0>;
+/// Mass flow rate (kg/s)
+pub type MassFlowRate = Dim<Z0, P1, N1, Z0, Z0, Z0, Z0>;
+/// Electric charge (C)
+pub type ElectricCharge = Dim<Z0, Z0, P1, P1, Z0, Z0, Z0>;
+/// Capacitance (F)
+pub type Capacitance = Dim<N2, N1, P4, P2, Z0, Z0, Z0>;
+/// Inductance (H)
+pub type Inductance = Dim<P2, P1, N2, N2, Z0, Z0, Z0>;
+/// Conductance (S)
+pub type Conductance = Dim<N2, N1, P3, P2, Z0, Z0, Z0>;
+
+/// The names of coherent SI units with special symbols, and the exponents
+/// they stand for.
+const NAMED_UNITS: &[([i32; 7], &str)] = &[
+    ([1, 0, 0, 0, 0, 0, 0], "m"),
+    ([0, 1, 0, 0, 0, 0, 0], "kg"),
+    ([0, 0, 1, 0, 0, 0, 0], "s"),
+    ([0, 0, 0, 1, 0, 0, 0], "A"),
+    ([0, 0, 0, 0, 1, 0, 0], "K"),
+    ([0, 0, 0, 0, 0, 1, 0], "mol"),
+    ([0, 0, 0, 0, 0, 0, 1], "cd"),
+    ([2, 0, 0, 0, 0, 0, 0], "m\u{00B2}"),
+    ([3, 0, 0, 0, 0, 0, 0], "m\u{00B3}"),
+    ([0, 0, -1, 0, 0, 0, 0], "Hz"),
+    ([1, 0, -1, 0, 0, 0, 0], "m/s"),
+    ([1, 0, -2, 0, 0, 0, 0], "m/s\u{00B2}"),
+    ([1, 1, -2, 0, 0, 0, 0], "N"),
+    ([-1, 1, -2, 0, 0, 0, 0], "Pa"),
+    ([2, 1, -2, 0, 0, 0, 0], "J"),
+    ([2, 1, -3, 0, 0, 0, 0], "W"),
+    ([2, 1, -3, -1, 0, 0, 0], "V"),
+    ([2, 1, -3, -2, 0, 0, 0], "\u{2126}"),
+    ([-3, 1, 0, 0, 0, 0, 0], "kg/m\u{00B3}"),
+    ([3, 0, -1, 0, 0, 0, 0], "m\u{00B3}/s"),
+    ([0, 1, -1, 0, 0, 0, 0], "kg/s"),
+    ([0, 0, 1, 1, 0, 0, 0], "C"),
+    ([-2, -1, 4, 2, 0, 0, 0], "F"),
+    ([2, 1, -2, -2, 0, 0, 0], "H"),
+    ([-2, -1, 3, 2, 0, 0, 0], "S"),
+];
+
+/// The symbols of the SI base units, in the order they are written when a
+/// unit has no special name.
+const BASE_UNITS: &[(usize, &str)] = &[
+    (1, "kg"),
+    (0, "m"),
+    (2, "s"),
+    (3, "A"),
+    (4, "K"),
+    (5, "mol"),
+    (6, "cd"),
+];
+
+/// Write an exponent as superscript digits.
+fn write_superscript(f: &mut fmt::Formatter, exponent: i32) -> fmt::Result {
+    const DIGITS: [char; 10] = [
+        '\u{2070}', '\u{00B9}', '\u{00B2}', '\u{00B3}', '\u{2074}', '\u{2075}', '\u{2076}',
+        '\u{2077}', '\u{2078}', '\u{2079}',
+    ];
+    if exponent < 0 {
+        write!(f, "\u{207B}")?;
+    }
+    let magnitude = exponent.unsigned_abs();
+    if magnitude >= 10 {
+        write_superscript(f, (magnitude / 10) as i32)?;
+    }
+    write!(f, "{}", DIGITS[(magnitude % 10) as usize])
+}
+
+/// The symbol of the coherent SI unit with the given exponents, if it has a
+/// special one.
+pub(crate) fn unit_name(exponents: &[i32; 7]) -> Option<&'static str> {
+    NAMED_UNITS
+        .iter()
+        .find(|(e, _)| e == exponents)
+        .map(|&(_, name)| name)
+}
+
+/// Write the coherent SI unit with the given exponents, such as "N" or
+/// "kg·m⁻¹", after `prefix`. Nothing is written for a dimensionless value.
+pub(crate) fn write_unit(
+    f: &mut fmt::Formatter,
+    exponents: &[i32; 7],
+    prefix: &str,
+) -> fmt::Result {
+    if let Some(name) = unit_name(exponents) {
+        return write!(f, "{}{}", prefix, name);
+    }
+    let mut separator = prefix;
+    for &(index, symbol) in BASE_UNITS {
+        let exponent = exponents[index];
+        if exponent != 0 {
+            write!(f, "{}{}", separator, symbol)?;
+            if exponent != 1 {
+                write_superscript(f, exponent)?;
+            }
+            separator = "\u{00B7}";
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use dimension::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(<<P2 as Sum<N3>>::Output as Integer>::VALUE, -1);
+        assert_eq!(<<N2 as Difference<N3>>::Output as Integer>::VALUE, 1);
+        assert_eq!(<<P3 as Integer>::Neg as Integer>::VALUE, -3);
+    }
+
+    #[test]
+    fn exponents() {
+        assert_eq!(Force::EXPONENTS, [1, 1, -2, 0, 0, 0, 0]);
+        assert_eq!(
+            <Energy as Quotient<Mass>>::Output::EXPONENTS,
+            [2, 0, -2, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            <Mass as Product<Acceleration>>::Output::EXPONENTS,
+            Force::EXPONENTS
+        );
+    }
+}
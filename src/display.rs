@@ -0,0 +1,769 @@
+//! Configurable formatting of measurements.
+//!
+//! Formatting a measurement with `{}` picks a unit automatically and prints
+//! the value followed by the unit's symbol. Every measurement also has a
+//! `display()` method, which returns a `QuantityDisplay` that can be told
+//! which unit or system of units to use, how many digits to print and
+//! whether to spell out unit names.
+//!
+//! A display can also be split across several units, as in "5 ft 11 in",
+//! and the last unit can be rounded to a fraction such as 1/16.
+//!
+//! Unit names and the decimal separator can be taken from a `Locale`; see
+//! the `locale` module.
+//!
+//! The width, fill and alignment flags apply to the value and unit
+//! together, and a precision given in the format string is used unless the
+//! builder chose one.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::{Length, LengthUnit};
+//!
+//! let length = Length::from_meters(1.8);
+//! assert_eq!(length.display().imperial().long_names().sig_figs(3).to_string(), "1.97\u{00A0}yards");
+//! assert_eq!(length.display().unit(LengthUnit::Foot).precision(1).ascii_space().to_string(), "5.9 ft");
+//! assert_eq!(format!("[{:>8}]", length.display().ascii_space()), "[   1.8 m]");
+//!
+//! let feet_inches = length.display().compound(&[LengthUnit::Foot, LengthUnit::Inch]).fraction(16);
+//! assert_eq!(feet_inches.ascii_space().to_string(), "5 ft 10 7/8 in");
+//! ```
+
+use super::locale::{Locale, UnitName};
+use super::measurement::Measurement;
+use super::unit::{Unit, UnitSystem};
+use std::fmt::{self, Write};
+
+/// How the unit of a `QuantityDisplay` is chosen.
+#[derive(Copy, Clone, Debug)]
+enum UnitChoice<U: 'static> {
+    /// The unit `Display` would use.
+    Automatic,
+    /// The largest unit from this system in which the value is at least one.
+    System(UnitSystem),
+    /// Always this unit.
+    Fixed(U),
+    /// Whole numbers of each of these units but the last, largest first.
+    Compound(&'static [U]),
+}
+
+/// A measurement along with the options for displaying it. Created by the
+/// `display()` method of each measurement.
+#[derive(Copy, Clone, Debug)]
+pub struct QuantityDisplay<U: 'static> {
+    base_units: f64,
+    automatic: (&'static str, f64),
+    choice: UnitChoice<U>,
+    long_names: bool,
+    ascii_space: bool,
+    precision: Option<usize>,
+    sig_figs: Option<usize>,
+    fraction: Option<u32>,
+    locale: Option<&'static Locale>,
+}
+
+impl<U: Unit> QuantityDisplay<U> {
+    /// Display the given measurement, in the unit `Display` would use.
+    pub fn new<T: Measurement>(quantity: &T) -> Self {
+        QuantityDisplay {
+            base_units: quantity.as_base_units(),
+            automatic: quantity.get_appropriate_units(),
+            choice: UnitChoice::Automatic,
+            long_names: false,
+            ascii_space: false,
+            precision: None,
+            sig_figs: None,
+            fraction: None,
+            locale: None,
+        }
+    }
+
+    /// Always display in the given unit.
+    pub fn unit(mut self, unit: U) -> Self {
+        self.choice = UnitChoice::Fixed(unit);
+        self
+    }
+
+    /// Display in the largest unit from the given system in which the value
+    /// is at least one. If the measurement has no units in that system, the
+    /// unit `Display` would use is kept.
+    pub fn system(mut self, system: UnitSystem) -> Self {
+        self.choice = UnitChoice::System(system);
+        self
+    }
+
+    /// Display in SI units. See `system`.
+    pub fn si(self) -> Self {
+        self.system(UnitSystem::Si)
+    }
+
+    /// Display in US customary units. See `system`.
+    pub fn us_customary(self) -> Self {
+        self.system(UnitSystem::UsCustomary)
+    }
+
+    /// Display in imperial units. See `system`.
+    pub fn imperial(self) -> Self {
+        self.system(UnitSystem::Imperial)
+    }
+
+    /// Split the value across several units, largest first, as in "5 ft 11
+    /// in" or "1 h 23 min". Every unit but the last holds a whole number, and
+    /// units with nothing in them are left out. The precision or fraction
+    /// applies to the last unit; significant figures are not used.
+    pub fn compound(mut self, units: &'static [U]) -> Self {
+        self.choice = UnitChoice::Compound(units);
+        self
+    }
+
+    /// Round the value, or the last unit of a compound display, to the
+    /// nearest `1/denominator` and print it as a fraction in lowest terms,
+    /// like "11 3/16 in".
+    pub fn fraction(mut self, denominator: u32) -> Self {
+        self.fraction = Some(denominator.max(1));
+        self
+    }
+
+    /// Spell out the unit, for example "3 feet" rather than "3 ft". Units
+    /// which have no long name keep their symbol.
+    pub fn long_names(mut self) -> Self {
+        self.long_names = true;
+        self
+    }
+
+    /// Use the unit names, symbols and decimal separator of a locale.
+    pub fn locale(mut self, locale: &'static Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Separate the value and unit with an ASCII space rather than a
+    /// non-breaking space.
+    pub fn ascii_space(mut self) -> Self {
+        self.ascii_space = true;
+        self
+    }
+
+    /// Print the value with this many decimal places, up to 65535. Replaces
+    /// any significant figures set earlier.
+    pub fn precision(mut self, decimals: usize) -> Self {
+        self.precision = Some(decimals.min(MAX_DIGITS));
+        self.sig_figs = None;
+        self
+    }
+
+    /// Print the value to this many significant figures, up to 65535. Digits
+    /// before the decimal point are never dropped, so 1234 to two figures is
+    /// "1234". Replaces any precision set earlier.
+    pub fn sig_figs(mut self, sig_figs: usize) -> Self {
+        self.sig_figs = Some(sig_figs.min(MAX_DIGITS));
+        self.precision = None;
+        self
+    }
+
+    /// The unit to display in, if it is one of `U`, and the value in it.
+    fn chosen_unit(&self) -> Option<(U, f64)> {
+        match self.choice {
+            UnitChoice::Automatic | UnitChoice::Compound(_) => {
+                let (symbol, value) = self.automatic;
+                U::iter().find(|u| u.symbol() == symbol).map(|u| (u, value))
+            }
+            UnitChoice::System(system) => {
+                let mut smallest: Option<U> = None;
+                let mut largest_fitting: Option<U> = None;
+                for unit in U::iter().filter(|u| u.in_system(system)) {
+                    if smallest.is_none_or(|s| unit.factor() < s.factor()) {
+                        smallest = Some(unit);
+                    }
+                    let fits = absolute(unit.convert_from_base(self.base_units)) >= 1.0;
+                    if fits && largest_fitting.is_none_or(|l| unit.factor() > l.factor()) {
+                        largest_fitting = Some(unit);
+                    }
+                }
+                largest_fitting
+                    .or(smallest)
+                    .map(|u| (u, u.convert_from_base(self.base_units)))
+            }
+            UnitChoice::Fixed(unit) => Some((unit, unit.convert_from_base(self.base_units))),
+        }
+    }
+
+    /// The name to print for `value` of a unit, or for the automatic unit if
+    /// that is not one of `U`.
+    fn name(&self, unit: Option<U>, value: f64) -> UnitName {
+        let unit = match unit {
+            Some(unit) => unit,
+            None => return UnitName::as_written(self.automatic.0),
+        };
+        match self.locale {
+            Some(locale) if self.long_names => locale.name(unit, value),
+            Some(locale) => UnitName::as_written(locale.symbol(unit)),
+            None if !self.long_names => UnitName::as_written(unit.symbol()),
+            None if value == 1.0 || value == -1.0 => UnitName::as_written(unit.singular()),
+            None => UnitName::as_written(unit.plural()),
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        self.locale.map_or('.', |locale| locale.decimal_separator)
+    }
+
+    fn separator(&self) -> char {
+        if self.ascii_space {
+            ' '
+        } else {
+            '\u{00A0}'
+        }
+    }
+
+    /// Write whole numbers of each of `units` but `last`, which takes the
+    /// remainder.
+    fn fmt_compound(&self, f: &mut fmt::Formatter, units: &[U], last: U) -> fmt::Result {
+        let decimals = self.precision.or_else(|| f.precision());
+        let per_unit = match (self.fraction, decimals) {
+            (Some(denominator), _) => Some(f64::from(denominator)),
+            (None, Some(decimals)) => Some(power_of_ten(decimals.min(MAX_DIGITS) as i32)),
+            (None, None) => None,
+        };
+        // Round the last unit before splitting, so that 5 ft 11.99 in to no
+        // decimal places carries to 6 ft.
+        let mut total = self.base_units / last.factor();
+        if let Some(per_unit) = per_unit {
+            total = round_to(total, per_unit);
+        }
+        let sign = if total < 0.0 {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+        let separator = self.separator();
+        let decimal_separator = self.decimal_separator();
+        write_padded(f, &|w| {
+            w.write_str(sign)?;
+            let mut remaining = absolute(total);
+            let mut written = false;
+            for &unit in &units[..units.len() - 1] {
+                let ratio = unit.factor() / last.factor();
+                let whole_ratio = round_to(ratio, 1.0);
+                let ratio = if absolute(ratio - whole_ratio) < 1e-9 * ratio {
+                    whole_ratio
+                } else {
+                    ratio
+                };
+                let (count, rest) = split_whole(remaining, ratio);
+                remaining = rest;
+                if count == 0.0 {
+                    continue;
+                }
+                if written {
+                    w.write_char(separator)?;
+                }
+                write!(w, "{}", count)?;
+                w.write_char(separator)?;
+                write!(w, "{}", self.name(Some(unit), count))?;
+                written = true;
+            }
+            if let Some(per_unit) = per_unit {
+                remaining = round_to(remaining, per_unit);
+            }
+            if written && remaining == 0.0 {
+                return Ok(());
+            }
+            if written {
+                w.write_char(separator)?;
+            }
+            match self.fraction {
+                Some(denominator) => write_fraction(w, remaining, denominator, separator)?,
+                None => write_number(w, remaining, decimals, decimal_separator)?,
+            }
+            w.write_char(separator)?;
+            write!(w, "{}", self.name(Some(last), remaining))
+        })
+    }
+}
+
+impl<U: Unit> fmt::Display for QuantityDisplay<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let UnitChoice::Compound(units) = self.choice {
+            if let Some(&last) = units.last() {
+                return self.fmt_compound(f, units, last);
+            }
+        }
+        let (unit, value) = match self.chosen_unit() {
+            Some((unit, value)) => (Some(unit), value),
+            None => (None, self.automatic.1),
+        };
+        let separator = self.separator();
+        if let Some(denominator) = self.fraction {
+            let value = round_to(value, f64::from(denominator));
+            let name = self.name(unit, value);
+            let plus = f.sign_plus();
+            return write_padded(f, &|w| {
+                if plus && value >= 0.0 {
+                    w.write_char('+')?;
+                }
+                write_fraction(w, value, denominator, separator)?;
+                w.write_char(separator)?;
+                write!(w, "{}", name)
+            });
+        }
+        let precision = match (self.precision, self.sig_figs) {
+            (Some(decimals), _) => Some(decimals),
+            (None, Some(sig_figs)) => Some(decimals_for_sig_figs(value, sig_figs)),
+            (None, None) => f.precision(),
+        };
+        write_measurement(
+            f,
+            value,
+            precision,
+            self.decimal_separator(),
+            separator,
+            &self.name(unit, value),
+        )
+    }
+}
+
+/// Write a value and unit, padding them together to the formatter's width.
+/// This is used by `implement_display!` and is not part of the public API.
+#[doc(hidden)]
+pub fn write_measurement(
+    f: &mut fmt::Formatter,
+    value: f64,
+    precision: Option<usize>,
+    decimal_separator: char,
+    separator: char,
+    unit: &dyn fmt::Display,
+) -> fmt::Result {
+    let plus = f.sign_plus();
+    write_padded(f, &|w| {
+        if plus && value >= 0.0 {
+            w.write_char('+')?;
+        }
+        write_number(w, value, precision, decimal_separator)?;
+        w.write_char(separator)?;
+        write!(w, "{}", unit)
+    })
+}
+
+/// Write a number with `decimal_separator` in place of the decimal point.
+fn write_number(
+    w: &mut dyn fmt::Write,
+    value: f64,
+    precision: Option<usize>,
+    decimal_separator: char,
+) -> fmt::Result {
+    let mut w = DecimalSeparator {
+        inner: w,
+        separator: decimal_separator,
+    };
+    match precision {
+        Some(decimals) => write!(w, "{:.*}", decimals, value),
+        None => write!(w, "{}", value),
+    }
+}
+
+/// Replaces the decimal point in the numbers written through it.
+struct DecimalSeparator<'a> {
+    inner: &'a mut dyn fmt::Write,
+    separator: char,
+}
+
+impl<'a> fmt::Write for DecimalSeparator<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut parts = s.split('.');
+        if let Some(first) = parts.next() {
+            self.inner.write_str(first)?;
+        }
+        for part in parts {
+            self.inner.write_char(self.separator)?;
+            self.inner.write_str(part)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the output of `write`, padded to the formatter's width with its
+/// fill and alignment.
+fn write_padded(
+    f: &mut fmt::Formatter,
+    write: &dyn Fn(&mut dyn fmt::Write) -> fmt::Result,
+) -> fmt::Result {
+    let width = match f.width() {
+        Some(width) => width,
+        None => return write(f),
+    };
+    let mut counter = CharCounter(0);
+    write(&mut counter)?;
+    let padding = width.saturating_sub(counter.0);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Write a value as a whole number and a fraction with the given
+/// denominator in lowest terms, for example "11 3/16".
+fn write_fraction(
+    w: &mut dyn fmt::Write,
+    value: f64,
+    denominator: u32,
+    separator: char,
+) -> fmt::Result {
+    let denominator = u64::from(denominator);
+    let ticks = round_to(absolute(value) * denominator as f64, 1.0) as u64;
+    let (whole, numerator) = (ticks / denominator, ticks % denominator);
+    if value < 0.0 && ticks > 0 {
+        w.write_char('-')?;
+    }
+    if whole > 0 || numerator == 0 {
+        write!(w, "{}", whole)?;
+    }
+    if numerator > 0 {
+        if whole > 0 {
+            w.write_char(separator)?;
+        }
+        let divisor = greatest_common_divisor(numerator, denominator);
+        write!(w, "{}/{}", numerator / divisor, denominator / divisor)?;
+    }
+    Ok(())
+}
+
+fn greatest_common_divisor(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
+/// Counts the characters written to it.
+struct CharCounter(usize);
+
+impl fmt::Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// The most decimal places or significant figures a display will print,
+/// which is also the largest precision `fmt` accepts.
+pub(crate) const MAX_DIGITS: usize = u16::MAX as usize;
+
+/// The number of decimal places needed to show `value` to `sig_figs`
+/// significant figures, which is limited to `MAX_DIGITS`. Large values get
+/// no decimal places rather than a negative number.
+pub(crate) fn decimals_for_sig_figs(value: f64, sig_figs: usize) -> usize {
+    let sig_figs = sig_figs.clamp(1, MAX_DIGITS) as i32;
+    let magnitude = absolute(value);
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return (sig_figs - 1) as usize;
+    }
+    let mut exponent = decimal_exponent(magnitude);
+    let power = power_of_ten(exponent);
+    // Rounding may carry into a new leading digit, as 9.996 does at three
+    // figures, which then needs one decimal place fewer.
+    let decimals = sig_figs - 1 - exponent;
+    if magnitude + 0.5 * power_of_ten(-decimals) >= power * 10.0 {
+        exponent += 1;
+    }
+    let decimals = sig_figs - 1 - exponent;
+    if decimals > 0 {
+        decimals as usize
+    } else {
+        0
+    }
+}
+
+/// The power of ten of the leading digit of a positive, finite number, for
+/// example 2 for 123.4 and -3 for 0.00567.
+pub(crate) fn decimal_exponent(magnitude: f64) -> i32 {
+    let mut exponent = 0;
+    let mut power = 1.0;
+    while magnitude >= power * 10.0 {
+        power *= 10.0;
+        exponent += 1;
+    }
+    while magnitude < power {
+        power /= 10.0;
+        exponent -= 1;
+    }
+    exponent
+}
+
+/// Raise a value to an integer power by repeated squaring, without needing
+/// `f64::powi`.
+pub(crate) fn powi(base: f64, exponent: i32) -> f64 {
+    let mut result = 1.0;
+    let mut square = base;
+    let mut remaining = exponent.unsigned_abs();
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result *= square;
+        }
+        square *= square;
+        remaining >>= 1;
+    }
+    if exponent < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+/// Ten raised to the given power.
+pub(crate) fn power_of_ten(exponent: i32) -> f64 {
+    powi(10.0, exponent)
+}
+
+/// Round to the nearest multiple of `1 / per_unit`, halves away from zero,
+/// without needing `f64::round`. Values too large to round are returned
+/// unchanged.
+pub(crate) fn round_to(value: f64, per_unit: f64) -> f64 {
+    let scaled = absolute(value) * per_unit + 0.5;
+    if scaled.is_nan() || scaled >= u64::MAX as f64 {
+        return value;
+    }
+    let rounded = (scaled as u64) as f64 / per_unit;
+    if value < 0.0 {
+        -rounded
+    } else {
+        rounded
+    }
+}
+
+/// Split an amount of a small unit into whole large units of `per_large`
+/// small units each, and the small units left over. Both parts have the
+/// sign of `value`, and a remainder within a rounding error of a whole large
+/// unit is carried into it.
+pub(crate) fn split_whole(value: f64, per_large: f64) -> (f64, f64) {
+    let magnitude = absolute(value);
+    let quotient = magnitude / per_large;
+    let mut whole = if quotient < u64::MAX as f64 {
+        (quotient as u64) as f64
+    } else {
+        quotient
+    };
+    let mut rest = magnitude - whole * per_large;
+    if per_large - rest < 1e-9 * per_large {
+        whole += 1.0;
+        rest = 0.0;
+    } else if rest < 0.0 {
+        rest = 0.0;
+    }
+    if value < 0.0 {
+        (-whole, -rest)
+    } else {
+        (whole, rest)
+    }
+}
+
+/// The absolute value, without needing `f64::abs`.
+pub(crate) fn absolute(value: f64) -> f64 {
+    if value < 0.0 {
+        -value
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use capacitance::Capacitance;
+    use display::*;
+    use energy::Energy;
+    use length::{Length, LengthUnit};
+    use mass::Mass;
+    use temperature::Temperature;
+    use volume::Volume;
+
+    #[test]
+    fn default_matches_display() {
+        let length = Length::from_meters(1234.5);
+        assert_eq!(length.display().to_string(), length.to_string());
+        assert_eq!(format!("{:.1}", length.display()), format!("{:.1}", length));
+    }
+
+    #[test]
+    fn fixed_unit() {
+        let length = Length::from_feet(3.0);
+        assert_eq!(
+            length
+                .display()
+                .unit(LengthUnit::Inch)
+                .ascii_space()
+                .to_string(),
+            "36 in"
+        );
+        assert_eq!(
+            length
+                .display()
+                .unit(LengthUnit::Foot)
+                .long_names()
+                .to_string(),
+            "3\u{00A0}feet"
+        );
+        assert_eq!(
+            Length::from_feet(1.0)
+                .display()
+                .unit(LengthUnit::Foot)
+                .long_names()
+                .to_string(),
+            "1\u{00A0}foot"
+        );
+    }
+
+    #[test]
+    fn systems() {
+        let mass = Mass::from_kilograms(70.0);
+        assert_eq!(
+            format!("{:.1}", mass.display().imperial().ascii_space()),
+            "11.0 st"
+        );
+        assert_eq!(
+            format!("{:.1}", mass.display().us_customary().ascii_space()),
+            "154.3 lb"
+        );
+        assert_eq!(mass.display().si().ascii_space().to_string(), "70 kg");
+
+        let volume = Volume::from_liters(10.0);
+        assert_eq!(
+            format!("{:.2}", volume.display().us_customary().ascii_space()),
+            "2.64 gal"
+        );
+        assert_eq!(
+            format!("{:.2}", volume.display().imperial().ascii_space()),
+            "2.20 gal (UK)"
+        );
+
+        let small = Length::from_millimeters(5.0);
+        assert_eq!(
+            format!("{:.3}", small.display().imperial().ascii_space()),
+            "0.197 in"
+        );
+    }
+
+    #[test]
+    fn systems_with_offsets() {
+        let body = Temperature::from_celsius(37.0);
+        assert_eq!(
+            format!("{:.1}", body.display().us_customary().ascii_space()),
+            "98.6 \u{00B0}F"
+        );
+        assert_eq!(
+            body.display().si().long_names().ascii_space().to_string(),
+            "37 degrees Celsius"
+        );
+    }
+
+    #[test]
+    fn missing_system_keeps_automatic_unit() {
+        let capacitance = Capacitance::from_microfarads(4.7);
+        assert_eq!(
+            capacitance.display().imperial().to_string(),
+            capacitance.to_string()
+        );
+    }
+
+    #[test]
+    fn long_names_without_unit() {
+        // kJ is displayed but is not one of the energy units.
+        let energy = Energy::from_joules(2000.0);
+        assert_eq!(
+            energy.display().long_names().ascii_space().to_string(),
+            "2 kJ"
+        );
+    }
+
+    #[test]
+    fn sig_figs() {
+        let length = Length::from_meters(1.23456);
+        assert_eq!(
+            length.display().sig_figs(3).ascii_space().to_string(),
+            "1.23 m"
+        );
+        assert_eq!(
+            Length::from_meters(9.996)
+                .display()
+                .sig_figs(3)
+                .ascii_space()
+                .to_string(),
+            "10.0 m"
+        );
+        assert_eq!(
+            Length::from_meters(0.0)
+                .display()
+                .unit(LengthUnit::Meter)
+                .sig_figs(2)
+                .ascii_space()
+                .to_string(),
+            "0.0 m"
+        );
+        assert_eq!(
+            Length::from_meters(123.0)
+                .display()
+                .sig_figs(2)
+                .ascii_space()
+                .to_string(),
+            "123 m"
+        );
+        assert_eq!(
+            format!("{:.5}", length.display().sig_figs(2).ascii_space()),
+            "1.2 m"
+        );
+        assert_eq!(
+            format!("{:.1}", length.display().precision(3).ascii_space()),
+            "1.235 m"
+        );
+        let long = length.display().sig_figs(3_000_000_000).to_string();
+        assert_eq!(long.len(), MAX_DIGITS + ".\u{00A0}m".len());
+        let long = length.display().precision(usize::MAX).to_string();
+        assert_eq!(long.len(), MAX_DIGITS + "1.\u{00A0}m".len());
+    }
+
+    #[test]
+    fn decimals() {
+        assert_eq!(decimals_for_sig_figs(0.001234, 3), 5);
+        assert_eq!(decimals_for_sig_figs(-45.6, 3), 1);
+        assert_eq!(decimals_for_sig_figs(99.96, 3), 0);
+        assert_eq!(decimals_for_sig_figs(5e6, 3), 0);
+        assert_eq!(decimals_for_sig_figs(1.0, 0), 0);
+        assert_eq!(decimals_for_sig_figs(1.0, 3_000_000_000), MAX_DIGITS - 1);
+        assert_eq!(decimals_for_sig_figs(0.0, usize::MAX), MAX_DIGITS - 1);
+        assert_eq!(power_of_ten(i32::MAX), f64::INFINITY);
+        assert_eq!(power_of_ten(i32::MIN), 0.0);
+    }
+
+    #[test]
+    fn padding() {
+        let length = Length::from_meters(1.5);
+        assert_eq!(
+            format!("[{:10}]", length.display().ascii_space()),
+            "[1.5 m     ]"
+        );
+        assert_eq!(
+            format!("[{:>10}]", length.display().ascii_space()),
+            "[     1.5 m]"
+        );
+        assert_eq!(
+            format!("[{:*^9}]", length.display().ascii_space()),
+            "[**1.5 m**]"
+        );
+        assert_eq!(format!("[{:>6.2}]", length), "[1.50\u{00A0}m]");
+        assert_eq!(format!("[{:>8}]", length), "[   1.5\u{00A0}m]");
+        assert_eq!(format!("{:+}", length), "+1.5\u{00A0}m");
+    }
+}
@@ -0,0 +1,284 @@
+//! Units, parsing and display for `std::time::Duration`, which this crate
+//! treats as the measurement of time.
+//!
+//! `Duration` is defined outside this crate, so it cannot be given
+//! `from_unit`, `display` or `FromStr` like the other measurements. The
+//! functions in this module stand in for them.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::duration::{self, DurationUnit};
+//!
+//! let lap = duration::parse("1 h 23 min").unwrap();
+//! assert_eq!(lap.as_secs(), 4980);
+//!
+//! let hours_minutes = &[DurationUnit::Hour, DurationUnit::Minute];
+//! let text = duration::display(&lap).compound(hours_minutes).ascii_space().to_string();
+//! assert_eq!(text, "1 h 23 min");
+//! ```
+
+use super::display::QuantityDisplay;
+use super::measurement::Measurement;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::unit::{parse_unit, Unit, UnitInfo, UnitSystem};
+use std::fmt;
+use time::Duration;
+
+/// Number of seconds in a minute
+const SECONDS_MINUTE_FACTOR: f64 = 60.0;
+/// Number of seconds in an hour
+const SECONDS_HOUR_FACTOR: f64 = 60.0 * SECONDS_MINUTE_FACTOR;
+/// Number of seconds in a day
+const SECONDS_DAY_FACTOR: f64 = 24.0 * SECONDS_HOUR_FACTOR;
+
+/// The units in which a `Duration` can be expressed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DurationUnit {
+    /// The nanosecond (ns)
+    Nanosecond,
+    /// The microsecond (µs)
+    Microsecond,
+    /// The millisecond (ms)
+    Millisecond,
+    /// The second (s)
+    Second,
+    /// The minute (min)
+    Minute,
+    /// The hour (h)
+    Hour,
+    /// The day (d)
+    Day,
+}
+
+impl Unit for DurationUnit {
+    fn all() -> &'static [Self] {
+        &[
+            DurationUnit::Nanosecond,
+            DurationUnit::Microsecond,
+            DurationUnit::Millisecond,
+            DurationUnit::Second,
+            DurationUnit::Minute,
+            DurationUnit::Hour,
+            DurationUnit::Day,
+        ]
+    }
+
+    fn info(self) -> &'static UnitInfo {
+        const fn info(
+            symbol: &'static str,
+            singular: &'static str,
+            plural: &'static str,
+            factor: f64,
+        ) -> UnitInfo {
+            UnitInfo {
+                symbol,
+                singular,
+                plural,
+                factor,
+                offset: 0.0,
+                systems: &[
+                    UnitSystem::Si,
+                    UnitSystem::UsCustomary,
+                    UnitSystem::Imperial,
+                ],
+            }
+        }
+        const NANOSECOND: UnitInfo = info("ns", "nanosecond", "nanoseconds", 1e-9);
+        const MICROSECOND: UnitInfo = info("\u{00B5}s", "microsecond", "microseconds", 1e-6);
+        const MILLISECOND: UnitInfo = info("ms", "millisecond", "milliseconds", 1e-3);
+        const SECOND: UnitInfo = info("s", "second", "seconds", 1.0);
+        const MINUTE: UnitInfo = info("min", "minute", "minutes", SECONDS_MINUTE_FACTOR);
+        const HOUR: UnitInfo = info("h", "hour", "hours", SECONDS_HOUR_FACTOR);
+        const DAY: UnitInfo = info("d", "day", "days", SECONDS_DAY_FACTOR);
+        match self {
+            DurationUnit::Nanosecond => &NANOSECOND,
+            DurationUnit::Microsecond => &MICROSECOND,
+            DurationUnit::Millisecond => &MILLISECOND,
+            DurationUnit::Second => &SECOND,
+            DurationUnit::Minute => &MINUTE,
+            DurationUnit::Hour => &HOUR,
+            DurationUnit::Day => &DAY,
+        }
+    }
+}
+
+impl fmt::Display for DurationUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl ::std::str::FromStr for DurationUnit {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_unit(s)
+    }
+}
+
+/// Create a new Duration from a floating point value in the given unit
+pub fn from_unit(value: f64, unit: DurationUnit) -> Duration {
+    Duration::from_base_units(unit.convert_to_base(value))
+}
+
+/// Convert a Duration into a floating point value in the given unit
+pub fn as_unit(duration: &Duration, unit: DurationUnit) -> f64 {
+    unit.convert_from_base(duration.as_base_units())
+}
+
+/// Create a new Duration from hours, minutes and seconds, or `None` if it
+/// would be too long for a Duration or the seconds are negative.
+pub fn from_hours_minutes_seconds(hours: u64, minutes: u64, seconds: f64) -> Option<Duration> {
+    let whole = hours
+        .checked_mul(3600)?
+        .checked_add(minutes.checked_mul(60)?)?;
+    let seconds = Duration::try_from_secs_f64(seconds).ok()?;
+    Duration::from_secs(whole).checked_add(seconds)
+}
+
+/// Convert a Duration into whole hours, whole minutes and the seconds left
+/// over.
+pub fn as_hours_minutes_seconds(duration: &Duration) -> (u64, u64, f64) {
+    let secs = duration.as_secs();
+    let seconds = (secs % 60) as f64 + f64::from(duration.subsec_nanos()) * 1e-9;
+    (secs / 3600, secs % 3600 / 60, seconds)
+}
+
+/// Choose how a Duration is displayed, for example its unit or precision.
+pub fn display(duration: &Duration) -> QuantityDisplay<DurationUnit> {
+    QuantityDisplay::new(duration)
+}
+
+/// Parse a Duration from one or more value/unit terms, such as "90 s" or
+/// "1 h 23 min".
+pub fn parse(input: &str) -> Result<Duration, ParseMeasurementError> {
+    parse_measurement(input, Duration::unit_names())
+}
+
+/// The unit names understood when parsing a `Duration`.
+const UNIT_NAMES: &[UnitConstructor<'static, Duration>] = &[
+    ("ns", from_nanoseconds),
+    ("\u{00B5}s", from_microseconds),
+    ("ms", from_milliseconds),
+    ("s", Duration::from_base_units),
+    ("min", from_minutes),
+    ("h", from_hours),
+    ("d", from_days),
+    ("nanosecond", from_nanoseconds),
+    ("nanoseconds", from_nanoseconds),
+    ("microsecond", from_microseconds),
+    ("microseconds", from_microseconds),
+    ("millisecond", from_milliseconds),
+    ("milliseconds", from_milliseconds),
+    ("second", Duration::from_base_units),
+    ("seconds", Duration::from_base_units),
+    ("minute", from_minutes),
+    ("minutes", from_minutes),
+    ("hour", from_hours),
+    ("hours", from_hours),
+    ("day", from_days),
+    ("days", from_days),
+];
+
+fn from_nanoseconds(nanoseconds: f64) -> Duration {
+    Duration::from_base_units(nanoseconds * 1e-9)
+}
+
+fn from_microseconds(microseconds: f64) -> Duration {
+    Duration::from_base_units(microseconds * 1e-6)
+}
+
+fn from_milliseconds(milliseconds: f64) -> Duration {
+    Duration::from_base_units(milliseconds * 1e-3)
+}
+
+fn from_minutes(minutes: f64) -> Duration {
+    Duration::from_base_units(minutes * SECONDS_MINUTE_FACTOR)
+}
+
+fn from_hours(hours: f64) -> Duration {
+    Duration::from_base_units(hours * SECONDS_HOUR_FACTOR)
+}
+
+fn from_days(days: f64) -> Duration {
+    Duration::from_base_units(days * SECONDS_DAY_FACTOR)
+}
+
+impl UnitNames for Duration {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use duration::*;
+    use test_utils::assert_almost_eq;
+
+    const HOURS_MINUTES: &[DurationUnit] = &[DurationUnit::Hour, DurationUnit::Minute];
+
+    #[test]
+    fn units() {
+        let d = from_unit(1.5, DurationUnit::Hour);
+        assert_eq!(d, Duration::from_secs(5400));
+        assert_almost_eq(as_unit(&d, DurationUnit::Minute), 90.0);
+        assert_eq!("min".parse::<DurationUnit>(), Ok(DurationUnit::Minute));
+        assert_eq!(DurationUnit::Hour.to_string(), "h");
+    }
+
+    #[test]
+    fn hours_minutes_seconds() {
+        let d = from_hours_minutes_seconds(1, 23, 4.5).unwrap();
+        assert_eq!(d, Duration::new(4984, 500_000_000));
+        assert_eq!(as_hours_minutes_seconds(&d), (1, 23, 4.5));
+        assert_eq!(from_hours_minutes_seconds(u64::MAX, 0, 0.0), None);
+        assert_eq!(from_hours_minutes_seconds(0, u64::MAX, 0.0), None);
+        assert_eq!(from_hours_minutes_seconds(0, 0, 1e30), None);
+        assert_eq!(from_hours_minutes_seconds(0, 0, -1.0), None);
+        let max = u64::MAX / 3600;
+        assert_eq!(from_hours_minutes_seconds(max, 59, 59.0), None);
+        assert!(from_hours_minutes_seconds(max, 0, 0.0).is_some());
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(parse("1 h 23 min").unwrap(), Duration::from_secs(4980));
+        assert_eq!(parse("2 days").unwrap(), Duration::from_secs(172_800));
+        assert_eq!(parse("1 1/2 h").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse("250 ms").unwrap(), Duration::from_millis(250));
+        assert!(parse("3 parsecs").is_err());
+    }
+
+    #[test]
+    fn compound_display() {
+        let d = Duration::from_secs(4980);
+        assert_eq!(
+            display(&d)
+                .compound(HOURS_MINUTES)
+                .ascii_space()
+                .to_string(),
+            "1 h 23 min"
+        );
+        let text = display(&d).compound(HOURS_MINUTES).long_names().to_string();
+        assert_eq!(text, "1\u{00A0}hour\u{00A0}23\u{00A0}minutes");
+        assert_eq!(parse(&text).unwrap(), d);
+
+        let seconds = &[
+            DurationUnit::Hour,
+            DurationUnit::Minute,
+            DurationUnit::Second,
+        ];
+        let d = Duration::from_secs(3605);
+        assert_eq!(
+            display(&d).compound(seconds).ascii_space().to_string(),
+            "1 h 5 s"
+        );
+    }
+
+    #[test]
+    fn system_display() {
+        let d = Duration::from_secs(5400);
+        assert_eq!(display(&d).si().ascii_space().to_string(), "1.5 h");
+    }
+}
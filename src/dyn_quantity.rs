@@ -0,0 +1,519 @@
+//! A quantity whose dimension is only known at runtime.
+//!
+//! `Quantity<D>` checks dimensions while the program is compiled, which is no
+//! help when the kind of a value comes from a file or a database. A
+//! `DynQuantity` holds a value in coherent SI units together with a
+//! `DynDimension`, the exponents of the seven SI base dimensions, and checks
+//! them as it goes. Once the kind of quantity is known, `TryFrom` converts it
+//! into the matching named type, or explains why it cannot.
+//!
+//! Types which share a dimension convert into each other freely: an Energy
+//! and a Torque are both kg·m²·s⁻². The same types as for `Quantity` are
+//! left out: `Data` and `DataRate` have no SI dimension, and an
+//! `AngularVelocity` would be mistaken for a `Frequency` 2π times larger.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::{DynDimension, DynQuantity, Pressure};
+//! use std::convert::TryFrom;
+//!
+//! let force = DynQuantity::new(100.0, DynDimension::MASS * DynDimension::LENGTH
+//!     / DynDimension::TIME.powi(2).unwrap());
+//! let area = DynQuantity::new(2.0, DynDimension::LENGTH.powi(2).unwrap());
+//! let pressure = Pressure::try_from(force / area).unwrap();
+//! assert_eq!(pressure.as_pascals(), 50.0);
+//! ```
+
+use super::dimension::{self, Dimension};
+use super::display::powi;
+use super::quantity::Dimensioned;
+use super::*;
+use std::convert::TryFrom;
+use std::fmt;
+use time::Duration;
+
+/// The exponents of the seven SI base dimensions: length, mass, time,
+/// electric current, thermodynamic temperature, amount of substance and
+/// luminous intensity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynDimension {
+    exponents: [i32; 7],
+}
+
+impl DynDimension {
+    /// No dimension
+    pub const DIMENSIONLESS: DynDimension = DynDimension::new([0, 0, 0, 0, 0, 0, 0]);
+    /// Length (m)
+    pub const LENGTH: DynDimension = DynDimension::new([1, 0, 0, 0, 0, 0, 0]);
+    /// Mass (kg)
+    pub const MASS: DynDimension = DynDimension::new([0, 1, 0, 0, 0, 0, 0]);
+    /// Time (s)
+    pub const TIME: DynDimension = DynDimension::new([0, 0, 1, 0, 0, 0, 0]);
+    /// Electric current (A)
+    pub const CURRENT: DynDimension = DynDimension::new([0, 0, 0, 1, 0, 0, 0]);
+    /// Thermodynamic temperature (K)
+    pub const TEMPERATURE: DynDimension = DynDimension::new([0, 0, 0, 0, 1, 0, 0]);
+    /// Amount of substance (mol)
+    pub const AMOUNT: DynDimension = DynDimension::new([0, 0, 0, 0, 0, 1, 0]);
+    /// Luminous intensity (cd)
+    pub const LUMINOUS_INTENSITY: DynDimension = DynDimension::new([0, 0, 0, 0, 0, 0, 1]);
+
+    /// Create a new DynDimension from the exponents of length, mass, time,
+    /// electric current, thermodynamic temperature, amount of substance and
+    /// luminous intensity.
+    pub const fn new(exponents: [i32; 7]) -> Self {
+        DynDimension { exponents }
+    }
+
+    /// The DynDimension matching the type-level dimension `D`.
+    pub fn of<D: Dimension>() -> Self {
+        DynDimension::new(D::EXPONENTS)
+    }
+
+    /// The exponents of length, mass, time, electric current, thermodynamic
+    /// temperature, amount of substance and luminous intensity.
+    pub fn exponents(&self) -> [i32; 7] {
+        self.exponents
+    }
+
+    /// Whether every exponent is zero.
+    pub fn is_dimensionless(&self) -> bool {
+        *self == DynDimension::DIMENSIONLESS
+    }
+
+    /// The product of two dimensions, or an error if an exponent would
+    /// overflow.
+    pub fn try_mul(self, rhs: DynDimension) -> Result<Self, DimensionError> {
+        self.combine(rhs, i32::checked_add)
+    }
+
+    /// The quotient of two dimensions, or an error if an exponent would
+    /// overflow.
+    pub fn try_div(self, rhs: DynDimension) -> Result<Self, DimensionError> {
+        self.combine(rhs, i32::checked_sub)
+    }
+
+    /// This dimension raised to an integer power, or an error if an exponent
+    /// would overflow.
+    pub fn powi(self, exponent: i32) -> Result<Self, DimensionError> {
+        self.combine(DynDimension::DIMENSIONLESS, |e, _| e.checked_mul(exponent))
+    }
+
+    fn combine(
+        self,
+        other: DynDimension,
+        f: impl Fn(i32, i32) -> Option<i32>,
+    ) -> Result<Self, DimensionError> {
+        let mut exponents = self.exponents;
+        for (e, o) in exponents.iter_mut().zip(other.exponents.iter()) {
+            *e = f(*e, *o).ok_or(DimensionError::Overflow)?;
+        }
+        Ok(DynDimension::new(exponents))
+    }
+}
+
+/// Multiplies two dimensions.
+///
+/// # Panics
+///
+/// If an exponent overflows. Use `try_mul` to handle this.
+impl ::std::ops::Mul for DynDimension {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.try_mul(rhs).expect("dimension exponent overflow")
+    }
+}
+
+/// Divides one dimension by another.
+///
+/// # Panics
+///
+/// If an exponent overflows. Use `try_div` to handle this.
+impl ::std::ops::Div for DynDimension {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.try_div(rhs).expect("dimension exponent overflow")
+    }
+}
+
+impl fmt::Display for DynDimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_dimensionless() {
+            f.write_str("dimensionless")
+        } else {
+            dimension::write_unit(f, &self.exponents, "")
+        }
+    }
+}
+
+/// The error returned when quantities of different dimensions are mixed, or
+/// a dimension cannot be represented.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DimensionError {
+    /// A quantity did not have the dimension which was needed.
+    Mismatch {
+        /// The dimension which was needed
+        expected: DynDimension,
+        /// The dimension which was given
+        found: DynDimension,
+        /// The name of the type being converted into, if the error came
+        /// from a conversion
+        target: Option<&'static str>,
+    },
+    /// An exponent of a dimension went beyond the range of an `i32`.
+    Overflow,
+}
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DimensionError::Mismatch {
+                expected,
+                found,
+                target: Some(target),
+            } => write!(
+                f,
+                "cannot convert a quantity in {} into a {}, which is measured in {}",
+                found, target, expected
+            ),
+            DimensionError::Mismatch {
+                expected, found, ..
+            } => write!(
+                f,
+                "mismatched dimensions: expected {}, found {}",
+                expected, found
+            ),
+            DimensionError::Overflow => f.write_str("dimension exponent out of range"),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl ::std::error::Error for DimensionError {}
+
+/// A value in coherent SI units, with a dimension known only at runtime.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DynQuantity {
+    value: f64,
+    dimension: DynDimension,
+}
+
+impl DynQuantity {
+    /// Create a new DynQuantity from a floating point value in coherent SI
+    /// units and its dimension.
+    pub fn new(value: f64, dimension: DynDimension) -> Self {
+        DynQuantity { value, dimension }
+    }
+
+    /// Convert this DynQuantity to a floating point value in coherent SI
+    /// units
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The dimension of this DynQuantity.
+    pub fn dimension(&self) -> DynDimension {
+        self.dimension
+    }
+
+    /// Add two quantities, or return an error if their dimensions differ.
+    pub fn try_add(self, rhs: DynQuantity) -> Result<DynQuantity, DimensionError> {
+        rhs.check(self.dimension, None)?;
+        Ok(DynQuantity::new(self.value + rhs.value, self.dimension))
+    }
+
+    /// Subtract one quantity from another, or return an error if their
+    /// dimensions differ.
+    pub fn try_sub(self, rhs: DynQuantity) -> Result<DynQuantity, DimensionError> {
+        rhs.check(self.dimension, None)?;
+        Ok(DynQuantity::new(self.value - rhs.value, self.dimension))
+    }
+
+    /// Multiply two quantities, or return an error if an exponent of the
+    /// dimension would overflow.
+    pub fn try_mul(self, rhs: DynQuantity) -> Result<DynQuantity, DimensionError> {
+        let dimension = self.dimension.try_mul(rhs.dimension)?;
+        Ok(DynQuantity::new(self.value * rhs.value, dimension))
+    }
+
+    /// Divide one quantity by another, or return an error if an exponent of
+    /// the dimension would overflow.
+    pub fn try_div(self, rhs: DynQuantity) -> Result<DynQuantity, DimensionError> {
+        let dimension = self.dimension.try_div(rhs.dimension)?;
+        Ok(DynQuantity::new(self.value / rhs.value, dimension))
+    }
+
+    /// This quantity raised to an integer power, or an error if an exponent
+    /// of its dimension would overflow.
+    pub fn powi(self, exponent: i32) -> Result<DynQuantity, DimensionError> {
+        let dimension = self.dimension.powi(exponent)?;
+        Ok(DynQuantity::new(powi(self.value, exponent), dimension))
+    }
+
+    /// Return an error unless this quantity has the dimension `expected`.
+    fn check(
+        &self,
+        expected: DynDimension,
+        target: Option<&'static str>,
+    ) -> Result<(), DimensionError> {
+        if self.dimension == expected {
+            Ok(())
+        } else {
+            Err(DimensionError::Mismatch {
+                expected,
+                found: self.dimension,
+                target,
+            })
+        }
+    }
+
+    fn to_quantity<D: Dimension>(
+        self,
+        target: &'static str,
+    ) -> Result<Quantity<D>, DimensionError> {
+        self.check(DynDimension::of::<D>(), Some(target))?;
+        Ok(Quantity::new(self.value))
+    }
+}
+
+impl fmt::Display for DynQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        dimension::write_unit(f, &self.dimension.exponents, "\u{00A0}")
+    }
+}
+
+impl ::std::cmp::PartialOrd for DynQuantity {
+    /// Quantities of different dimensions cannot be compared.
+    fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+        if self.dimension == other.dimension {
+            self.value.partial_cmp(&other.value)
+        } else {
+            None
+        }
+    }
+}
+
+/// Multiplies two quantities.
+///
+/// # Panics
+///
+/// If an exponent of the dimension overflows. Use `try_mul` to handle this.
+impl ::std::ops::Mul for DynQuantity {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        DynQuantity::new(self.value * rhs.value, self.dimension * rhs.dimension)
+    }
+}
+
+/// Divides one quantity by another.
+///
+/// # Panics
+///
+/// If an exponent of the dimension overflows. Use `try_div` to handle this.
+impl ::std::ops::Div for DynQuantity {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        DynQuantity::new(self.value / rhs.value, self.dimension / rhs.dimension)
+    }
+}
+
+impl ::std::ops::Mul<f64> for DynQuantity {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        DynQuantity::new(self.value * rhs, self.dimension)
+    }
+}
+
+impl ::std::ops::Mul<DynQuantity> for f64 {
+    type Output = DynQuantity;
+
+    fn mul(self, rhs: DynQuantity) -> DynQuantity {
+        rhs * self
+    }
+}
+
+impl ::std::ops::Div<f64> for DynQuantity {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        DynQuantity::new(self.value / rhs, self.dimension)
+    }
+}
+
+impl ::std::ops::Neg for DynQuantity {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        DynQuantity::new(-self.value, self.dimension)
+    }
+}
+
+impl<D: Dimension> From<Quantity<D>> for DynQuantity {
+    fn from(quantity: Quantity<D>) -> Self {
+        DynQuantity::new(quantity.value(), DynDimension::of::<D>())
+    }
+}
+
+impl<D: Dimension> TryFrom<DynQuantity> for Quantity<D> {
+    type Error = DimensionError;
+
+    fn try_from(quantity: DynQuantity) -> Result<Self, DimensionError> {
+        quantity.to_quantity("Quantity")
+    }
+}
+
+/// Implement conversions between `DynQuantity` and named types which
+/// implement `Dimensioned`.
+macro_rules! implement_dyn_quantity {
+    ($($t:ident)*) => ($(
+        impl From<$t> for DynQuantity {
+            fn from(measurement: $t) -> Self {
+                DynQuantity::from(measurement.to_quantity())
+            }
+        }
+
+        impl TryFrom<DynQuantity> for $t {
+            type Error = DimensionError;
+
+            fn try_from(quantity: DynQuantity) -> Result<Self, DimensionError> {
+                quantity.to_quantity(stringify!($t)).map($t::from_quantity)
+            }
+        }
+    )*)
+}
+
+implement_dyn_quantity! {
+    Length Area Volume Mass Duration TemperatureDelta Speed Acceleration
+    Force Pressure Energy Torque TorqueEnergy Power Voltage Current
+    Resistance Frequency Angle Density VolumetricFlowRate MassFlowRate
+    ElectricCharge Capacitance Inductance Conductance ApparentPower
+    ReactivePower
+}
+
+impl From<Temperature> for DynQuantity {
+    fn from(temperature: Temperature) -> Self {
+        DynQuantity::new(temperature.as_kelvin(), DynDimension::TEMPERATURE)
+    }
+}
+
+impl TryFrom<DynQuantity> for Temperature {
+    type Error = DimensionError;
+
+    fn try_from(quantity: DynQuantity) -> Result<Self, DimensionError> {
+        quantity.check(DynDimension::TEMPERATURE, Some("Temperature"))?;
+        Ok(Temperature::from_kelvin(quantity.value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use dyn_quantity::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn arithmetic() {
+        let force = DynQuantity::from(Force::from_newtons(10.0));
+        let length = DynQuantity::from(Length::from_meters(2.0));
+        let energy = force * length;
+        assert_eq!(
+            energy.dimension(),
+            DynDimension::new([2, 1, -2, 0, 0, 0, 0])
+        );
+        assert_almost_eq(Energy::try_from(energy).unwrap().as_joules(), 20.0);
+        assert_almost_eq(Torque::try_from(energy).unwrap().as_newton_metres(), 20.0);
+        let sum = length.try_add(length * 2.0).unwrap();
+        assert_almost_eq(sum.value(), 6.0);
+        assert!(length.try_sub(force).is_err());
+        assert_eq!((length / length).dimension(), DynDimension::DIMENSIONLESS);
+        assert_eq!(
+            length.powi(3).unwrap().dimension(),
+            DynDimension::LENGTH.powi(3).unwrap()
+        );
+        assert_almost_eq(length.powi(-2).unwrap().value(), 0.25);
+        assert!(length < length * 2.0);
+        assert_eq!(length.partial_cmp(&force), None);
+    }
+
+    #[test]
+    fn conversions() {
+        let v = DynQuantity::from(Volume::from_liters(1000.0));
+        assert_almost_eq(v.value(), 1.0);
+        assert_almost_eq(Volume::try_from(v).unwrap().as_liters(), 1000.0);
+        let t = DynQuantity::from(Temperature::from_celsius(20.0));
+        assert_almost_eq(Temperature::try_from(t).unwrap().as_celsius(), 20.0);
+        let r = DynQuantity::from(Length::from_meters(2.0))
+            / DynQuantity::from(time::Duration::new(2, 0));
+        assert_almost_eq(Speed::try_from(r).unwrap().as_meters_per_second(), 1.0);
+        let q = Quantity::<dimension::Speed>::try_from(DynQuantity::new(
+            3.0,
+            DynDimension::LENGTH / DynDimension::TIME,
+        ))
+        .unwrap();
+        assert_almost_eq(Speed::from_quantity(q).as_meters_per_second(), 3.0);
+    }
+
+    #[test]
+    fn errors() {
+        let force = DynQuantity::from(Force::from_newtons(10.0));
+        let e = Pressure::try_from(force).unwrap_err();
+        assert_eq!(
+            e,
+            DimensionError::Mismatch {
+                expected: DynDimension::of::<dimension::Pressure>(),
+                found: DynDimension::of::<dimension::Force>(),
+                target: Some("Pressure"),
+            }
+        );
+        assert_eq!(
+            format!("{}", e),
+            "cannot convert a quantity in N into a Pressure, which is measured in Pa"
+        );
+        let e = force.try_add(DynQuantity::new(1.0, DynDimension::DIMENSIONLESS));
+        assert_eq!(
+            format!("{}", e.unwrap_err()),
+            "mismatched dimensions: expected N, found dimensionless"
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let area = DynQuantity::from(Area::from_square_meters(2.0));
+        assert_eq!(area.powi(i32::MIN), Err(DimensionError::Overflow));
+        assert_eq!(
+            format!("{}", DimensionError::Overflow),
+            "dimension exponent out of range"
+        );
+        assert!(DynDimension::TIME.powi(-1).unwrap().powi(i32::MIN).is_err());
+        let huge = DynDimension::LENGTH.powi(i32::MAX).unwrap();
+        assert_eq!(
+            huge.try_mul(DynDimension::LENGTH),
+            Err(DimensionError::Overflow)
+        );
+        assert_eq!(
+            huge.try_div(DynDimension::LENGTH),
+            Ok(DynDimension::new([i32::MAX - 1, 0, 0, 0, 0, 0, 0]))
+        );
+        let huge = DynQuantity::new(1.0, huge);
+        let length = DynQuantity::from(Length::from_meters(2.0));
+        assert_eq!(huge.try_mul(length), Err(DimensionError::Overflow));
+        let tiny = DynQuantity::new(1.0, DynDimension::LENGTH.powi(i32::MIN).unwrap());
+        assert_eq!(length.try_div(tiny), Err(DimensionError::Overflow));
+        assert_almost_eq(length.try_mul(length).unwrap().value(), 4.0);
+        let dimensionless = DynQuantity::new(1.0, DynDimension::DIMENSIONLESS);
+        assert_almost_eq(dimensionless.powi(i32::MIN).unwrap().value(), 1.0);
+    }
+
+    #[test]
+    fn display() {
+        let q = DynQuantity::new(2.0, DynDimension::MASS / DynDimension::LENGTH);
+        assert_eq!(format!("{}", q), "2\u{00A0}kg\u{00B7}m\u{207B}\u{00B9}");
+        assert_eq!(format!("{}", DynDimension::TIME.powi(-1).unwrap()), "Hz");
+    }
+}
@@ -0,0 +1,164 @@
+//! The IEC 60063 E-series of preferred numbers, used for the standard values
+//! of resistors, capacitors and inductors.
+
+/// The E24 series, from which E6 and E12 are taken.
+const E24: [f64; 24] = [
+    1.0, 1.1, 1.2, 1.3, 1.5, 1.6, 1.8, 2.0, 2.2, 2.4, 2.7, 3.0, 3.3, 3.6, 3.9, 4.3, 4.7, 5.1, 5.6,
+    6.2, 6.8, 7.5, 8.2, 9.1,
+];
+
+/// The E192 series, from which E48 and E96 are taken.
+const E192: [f64; 192] = [
+    1.00, 1.01, 1.02, 1.04, 1.05, 1.06, 1.07, 1.09, 1.10, 1.11, 1.13, 1.14, 1.15, 1.17, 1.18, 1.20,
+    1.21, 1.23, 1.24, 1.26, 1.27, 1.29, 1.30, 1.32, 1.33, 1.35, 1.37, 1.38, 1.40, 1.42, 1.43, 1.45,
+    1.47, 1.49, 1.50, 1.52, 1.54, 1.56, 1.58, 1.60, 1.62, 1.64, 1.65, 1.67, 1.69, 1.72, 1.74, 1.76,
+    1.78, 1.80, 1.82, 1.84, 1.87, 1.89, 1.91, 1.93, 1.96, 1.98, 2.00, 2.03, 2.05, 2.08, 2.10, 2.13,
+    2.15, 2.18, 2.21, 2.23, 2.26, 2.29, 2.32, 2.34, 2.37, 2.40, 2.43, 2.46, 2.49, 2.52, 2.55, 2.58,
+    2.61, 2.64, 2.67, 2.71, 2.74, 2.77, 2.80, 2.84, 2.87, 2.91, 2.94, 2.98, 3.01, 3.05, 3.09, 3.12,
+    3.16, 3.20, 3.24, 3.28, 3.32, 3.36, 3.40, 3.44, 3.48, 3.52, 3.57, 3.61, 3.65, 3.70, 3.74, 3.79,
+    3.83, 3.88, 3.92, 3.97, 4.02, 4.07, 4.12, 4.17, 4.22, 4.27, 4.32, 4.37, 4.42, 4.48, 4.53, 4.59,
+    4.64, 4.70, 4.75, 4.81, 4.87, 4.93, 4.99, 5.05, 5.11, 5.17, 5.23, 5.30, 5.36, 5.42, 5.49, 5.56,
+    5.62, 5.69, 5.76, 5.83, 5.90, 5.97, 6.04, 6.12, 6.19, 6.26, 6.34, 6.42, 6.49, 6.57, 6.65, 6.73,
+    6.81, 6.90, 6.98, 7.06, 7.15, 7.23, 7.32, 7.41, 7.50, 7.59, 7.68, 7.77, 7.87, 7.96, 8.06, 8.16,
+    8.25, 8.35, 8.45, 8.56, 8.66, 8.76, 8.87, 8.98, 9.09, 9.20, 9.31, 9.42, 9.53, 9.65, 9.76, 9.88,
+];
+
+/// One of the E-series of preferred numbers. Each series divides a decade
+/// into a fixed number of steps, so that every value is within the series'
+/// tolerance of a preferred one.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{ESeries, Resistance};
+///
+/// let (r, error) = Resistance::from_ohms(4600.0).to_e_series(ESeries::E12);
+/// println!("Use a {} resistor, {:.1}% out", r, error * 100.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ESeries {
+    /// 6 values per decade, for 20% parts
+    E6,
+    /// 12 values per decade, for 10% parts
+    E12,
+    /// 24 values per decade, for 5% parts
+    E24,
+    /// 48 values per decade, for 2% parts
+    E48,
+    /// 96 values per decade, for 1% parts
+    E96,
+    /// 192 values per decade, for 0.5% parts and better
+    E192,
+}
+
+impl ESeries {
+    /// The number of values in each decade.
+    pub fn values_per_decade(&self) -> usize {
+        match *self {
+            ESeries::E6 => 6,
+            ESeries::E12 => 12,
+            ESeries::E24 => 24,
+            ESeries::E48 => 48,
+            ESeries::E96 => 96,
+            ESeries::E192 => 192,
+        }
+    }
+
+    /// The tolerance of the parts this series is intended for, as a
+    /// fraction (so 0.05 for E24).
+    pub fn tolerance(&self) -> f64 {
+        match *self {
+            ESeries::E6 => 0.2,
+            ESeries::E12 => 0.1,
+            ESeries::E24 => 0.05,
+            ESeries::E48 => 0.02,
+            ESeries::E96 => 0.01,
+            ESeries::E192 => 0.005,
+        }
+    }
+
+    /// The values in the decade from 1 to 10, smallest first.
+    pub fn values(&self) -> impl Iterator<Item = f64> {
+        let (table, step): (&'static [f64], usize) = match *self {
+            ESeries::E6 => (&E24, 4),
+            ESeries::E12 => (&E24, 2),
+            ESeries::E24 => (&E24, 1),
+            ESeries::E48 => (&E192, 4),
+            ESeries::E96 => (&E192, 2),
+            ESeries::E192 => (&E192, 1),
+        };
+        table.iter().step_by(step).cloned()
+    }
+
+    /// The preferred value nearest to `value`, measured by ratio rather than
+    /// difference so that the values either side of a decade boundary are
+    /// treated alike. Zero, infinite and NaN values are returned unchanged,
+    /// and negative values are rounded as if they were positive.
+    pub fn nearest(&self, value: f64) -> f64 {
+        if value < 0.0 {
+            return -self.nearest(-value);
+        }
+        if value == 0.0 || !value.is_finite() {
+            return value;
+        }
+        let mut scale = 1.0;
+        while value / scale >= 10.0 {
+            scale *= 10.0;
+        }
+        while value / scale < 1.0 {
+            scale /= 10.0;
+        }
+        let mantissa = value / scale;
+        let ratio = |candidate: f64| {
+            if candidate > mantissa {
+                candidate / mantissa
+            } else {
+                mantissa / candidate
+            }
+        };
+        let mut best = 10.0;
+        for candidate in self.values() {
+            if ratio(candidate) < ratio(best) {
+                best = candidate;
+            }
+        }
+        best * scale
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use e_series::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn values() {
+        assert_eq!(
+            ESeries::E6.values().collect::<Vec<_>>(),
+            vec![1.0, 1.5, 2.2, 3.3, 4.7, 6.8]
+        );
+        for &series in &[
+            ESeries::E6,
+            ESeries::E12,
+            ESeries::E24,
+            ESeries::E48,
+            ESeries::E96,
+            ESeries::E192,
+        ] {
+            assert_eq!(series.values().count(), series.values_per_decade());
+        }
+        assert_eq!(ESeries::E48.values().nth(47), Some(9.53));
+        assert_eq!(ESeries::E96.values().nth(1), Some(1.02));
+    }
+
+    #[test]
+    fn nearest() {
+        assert_almost_eq(ESeries::E12.nearest(4600.0), 4700.0);
+        assert_almost_eq(ESeries::E24.nearest(0.0052), 0.0051);
+        assert_almost_eq(ESeries::E96.nearest(10_050.0), 10_000.0);
+        assert_almost_eq(ESeries::E6.nearest(9.0), 10.0);
+        assert_almost_eq(ESeries::E192.nearest(1000.0), 1000.0);
+        assert_almost_eq(ESeries::E12.nearest(-1.25), -1.2);
+        assert_eq!(ESeries::E12.nearest(0.0), 0.0);
+    }
+}
@@ -0,0 +1,270 @@
+//! Types and constants for handling electric charge, such as the capacity of
+//! a battery.
+
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::*;
+
+/// Number of coulombs in an ampere-hour
+pub const COULOMBS_AMPERE_HOURS_FACTOR: f64 = 3600.0;
+/// Number of coulombs in a milliampere-hour
+pub const COULOMBS_MILLIAMPERE_HOURS_FACTOR: f64 = 3.6;
+
+/// The `ElectricCharge` struct can be used to deal with electric charge in a
+/// common way. It is also the unit of a battery's capacity, which is usually
+/// given in ampere-hours or milliampere-hours.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Current, ElectricCharge};
+///
+/// let battery = ElectricCharge::from_milliampere_hours(2500.0);
+/// let runtime = battery.runtime(Current::from_milliamperes(500.0));
+/// println!("The battery lasts {} seconds at 500 mA", runtime.as_secs());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ElectricCharge {
+    coulombs: f64,
+}
+
+impl ElectricCharge {
+    /// Create a new ElectricCharge from a floating point value in coulombs
+    pub fn from_coulombs(coulombs: f64) -> Self {
+        ElectricCharge { coulombs }
+    }
+
+    /// Create a new ElectricCharge from a floating point value in
+    /// millicoulombs
+    pub fn from_millicoulombs(millicoulombs: f64) -> Self {
+        Self::from_coulombs(millicoulombs / 1000.0)
+    }
+
+    /// Create a new ElectricCharge from a floating point value in
+    /// microcoulombs
+    pub fn from_microcoulombs(microcoulombs: f64) -> Self {
+        Self::from_coulombs(microcoulombs / 1000.0 / 1000.0)
+    }
+
+    /// Create a new ElectricCharge from a floating point value in
+    /// ampere-hours
+    pub fn from_ampere_hours(ampere_hours: f64) -> Self {
+        Self::from_coulombs(ampere_hours * COULOMBS_AMPERE_HOURS_FACTOR)
+    }
+
+    /// Create a new ElectricCharge from a floating point value in
+    /// milliampere-hours
+    pub fn from_milliampere_hours(milliampere_hours: f64) -> Self {
+        Self::from_coulombs(milliampere_hours * COULOMBS_MILLIAMPERE_HOURS_FACTOR)
+    }
+
+    /// Convert this ElectricCharge into a floating point value in coulombs
+    pub fn as_coulombs(&self) -> f64 {
+        self.coulombs
+    }
+
+    /// Convert this ElectricCharge into a floating point value in
+    /// millicoulombs
+    pub fn as_millicoulombs(&self) -> f64 {
+        self.coulombs * 1000.0
+    }
+
+    /// Convert this ElectricCharge into a floating point value in
+    /// microcoulombs
+    pub fn as_microcoulombs(&self) -> f64 {
+        self.coulombs * 1000.0 * 1000.0
+    }
+
+    /// Convert this ElectricCharge into a floating point value in
+    /// ampere-hours
+    pub fn as_ampere_hours(&self) -> f64 {
+        self.coulombs / COULOMBS_AMPERE_HOURS_FACTOR
+    }
+
+    /// Convert this ElectricCharge into a floating point value in
+    /// milliampere-hours
+    pub fn as_milliampere_hours(&self) -> f64 {
+        self.coulombs / COULOMBS_MILLIAMPERE_HOURS_FACTOR
+    }
+
+    /// The C-rate at which a battery of this capacity is charged or
+    /// discharged by the given current. A 2 Ah battery discharged at 1 A is
+    /// running at 0.5C.
+    pub fn c_rate(&self, current: Current) -> f64 {
+        current.as_amperes() / self.as_ampere_hours()
+    }
+
+    /// The current which charges or discharges a battery of this capacity at
+    /// the given C-rate.
+    pub fn current_at_c_rate(&self, c_rate: f64) -> Current {
+        Current::from_amperes(self.as_ampere_hours() * c_rate)
+    }
+
+    /// How long a battery of this capacity lasts when discharged at a
+    /// constant current. This ignores the Peukert effect, so real batteries
+    /// will fall short of it at high currents.
+    pub fn runtime(&self, current: Current) -> time::Duration {
+        *self / current
+    }
+
+    /// How long a battery of this capacity and nominal voltage lasts when it
+    /// supplies a constant power.
+    pub fn runtime_at_power(&self, voltage: Voltage, power: Power) -> time::Duration {
+        (*self * voltage) / power
+    }
+}
+
+impl Measurement for ElectricCharge {
+    fn as_base_units(&self) -> f64 {
+        self.coulombs
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_coulombs(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "C"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("pC", 1e-12),
+            ("nC", 1e-9),
+            ("\u{00B5}C", 1e-6),
+            ("mC", 1e-3),
+            ("C", 1e0),
+            ("kC", 1e3),
+            ("MC", 1e6),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { ElectricCharge }
+
+implement_units! {
+    ElectricCharge, ElectricChargeUnit {
+        Microcoulomb => ("\u{00B5}C", "microcoulomb", "microcoulombs", 1e-6) in Si,
+        Millicoulomb => ("mC", "millicoulomb", "millicoulombs", 1e-3) in Si,
+        Coulomb => ("C", "coulomb", "coulombs", 1.0) in Si,
+        MilliampereHour => ("mAh", "milliampere-hour", "milliampere-hours", COULOMBS_MILLIAMPERE_HOURS_FACTOR),
+        AmpereHour => ("Ah", "ampere-hour", "ampere-hours", COULOMBS_AMPERE_HOURS_FACTOR),
+    }
+}
+
+/// The unit names understood when parsing an `ElectricCharge`.
+const UNIT_NAMES: &[UnitConstructor<'static, ElectricCharge>] = &[
+    ("pC", |v| ElectricCharge::from_coulombs(v * 1e-12)),
+    ("nC", |v| ElectricCharge::from_coulombs(v * 1e-9)),
+    ("\u{00B5}C", ElectricCharge::from_microcoulombs),
+    ("mC", ElectricCharge::from_millicoulombs),
+    ("C", ElectricCharge::from_coulombs),
+    ("kC", |v| ElectricCharge::from_coulombs(v * 1e3)),
+    ("MC", |v| ElectricCharge::from_coulombs(v * 1e6)),
+    ("mAh", ElectricCharge::from_milliampere_hours),
+    ("Ah", ElectricCharge::from_ampere_hours),
+    ("coulomb", ElectricCharge::from_coulombs),
+    ("coulombs", ElectricCharge::from_coulombs),
+    ("millicoulomb", ElectricCharge::from_millicoulombs),
+    ("millicoulombs", ElectricCharge::from_millicoulombs),
+    ("microcoulomb", ElectricCharge::from_microcoulombs),
+    ("microcoulombs", ElectricCharge::from_microcoulombs),
+    ("ampere-hour", ElectricCharge::from_ampere_hours),
+    ("ampere-hours", ElectricCharge::from_ampere_hours),
+    ("milliampere-hour", ElectricCharge::from_milliampere_hours),
+    ("milliampere-hours", ElectricCharge::from_milliampere_hours),
+];
+
+impl UnitNames for ElectricCharge {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for ElectricCharge {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use electric_charge::*;
+    use test_utils::assert_almost_eq;
+    use time::Duration;
+
+    #[test]
+    fn as_coulombs() {
+        let q = ElectricCharge::from_ampere_hours(2.0);
+        assert_almost_eq(q.as_coulombs(), 7200.0);
+        assert_almost_eq(q.as_milliampere_hours(), 2000.0);
+    }
+
+    #[test]
+    fn as_microcoulombs() {
+        let q = ElectricCharge::from_millicoulombs(3.0);
+        assert_almost_eq(q.as_microcoulombs(), 3000.0);
+        assert_almost_eq(q.as_coulombs(), 0.003);
+    }
+
+    #[test]
+    fn current_and_duration() {
+        let q: ElectricCharge = Current::from_amperes(2.0) * Duration::new(1800, 0);
+        assert_almost_eq(q.as_ampere_hours(), 1.0);
+        let i: Current = q / Duration::new(3600, 0);
+        assert_almost_eq(i.as_amperes(), 1.0);
+        let t: Duration = q / Current::from_amperes(0.5);
+        assert_eq!(t, Duration::new(7200, 0));
+    }
+
+    #[test]
+    fn energy() {
+        let e: Energy = ElectricCharge::from_ampere_hours(2.0) * Voltage::from_volts(3.7);
+        assert_almost_eq(e.as_watt_hours(), 7.4);
+        let v: Voltage = e / ElectricCharge::from_ampere_hours(2.0);
+        assert_almost_eq(v.as_volts(), 3.7);
+    }
+
+    #[test]
+    fn c_rate() {
+        let q = ElectricCharge::from_milliampere_hours(2000.0);
+        assert_almost_eq(q.c_rate(Current::from_amperes(1.0)), 0.5);
+        assert_almost_eq(q.current_at_c_rate(2.0).as_amperes(), 4.0);
+    }
+
+    #[test]
+    fn runtime() {
+        let q = ElectricCharge::from_milliampere_hours(2500.0);
+        assert_eq!(
+            q.runtime(Current::from_milliamperes(500.0)),
+            Duration::new(5 * 3600, 0)
+        );
+        let t = q.runtime_at_power(Voltage::from_volts(3.6), Power::from_watts(1.8));
+        assert_eq!(t, Duration::new(5 * 3600, 0));
+    }
+
+    #[test]
+    fn display() {
+        let q = ElectricCharge::from_millicoulombs(250.0);
+        assert_eq!(format!("{}", q), "250\u{00A0}mC");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: ElectricCharge = "2500 mAh".parse().unwrap();
+        let b: ElectricCharge = "3 uC".parse().unwrap();
+        assert_almost_eq(a.as_ampere_hours(), 2.5);
+        assert_almost_eq(b.as_microcoulombs(), 3.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = ElectricCharge::from_unit(1.5, ElectricChargeUnit::AmpereHour);
+        assert_almost_eq(a.as_coulombs(), 5400.0);
+        assert_almost_eq(a.as_unit(ElectricChargeUnit::MilliampereHour), 1500.0);
+    }
+}
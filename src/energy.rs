@@ -1,6 +1,7 @@
 //! Types and constants for handling energy.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 
 /// The `Energy` struct can be used to deal with energies in a common way.
 /// Common metric and imperial units are supported.
@@ -115,6 +116,64 @@ impl Measurement for Energy {
 
 implement_measurement! { Energy }
 
+implement_units! {
+    Energy, EnergyUnit {
+        Joule => ("J", "joule", "joules", 1.0) in Si,
+        Kilocalorie => ("kcal", "kilocalorie", "kilocalories", 4186.8),
+        Btu => ("BTU", "British thermal unit", "British thermal units", 1055.056) in UsCustomary | Imperial,
+        ElectronVolt => ("eV", "electronvolt", "electronvolts", 1.0 / 6.241509479607718e+18),
+        WattHour => ("Wh", "watt-hour", "watt-hours", 3600.0) in Si,
+        KilowattHour => ("kWh", "kilowatt-hour", "kilowatt-hours", 3600.0 * 1000.0) in Si,
+    }
+}
+
+/// The unit names understood when parsing an `Energy`.
+const UNIT_NAMES: &[UnitConstructor<'static, Energy>] = &[
+    ("fJ", |v| Energy::from_joules(v * 1e-15)),
+    ("pJ", |v| Energy::from_joules(v * 1e-12)),
+    ("nJ", |v| Energy::from_joules(v * 1e-9)),
+    ("\u{00B5}J", |v| Energy::from_joules(v * 1e-6)),
+    ("mJ", |v| Energy::from_joules(v * 1e-3)),
+    ("J", Energy::from_joules),
+    ("kJ", |v| Energy::from_joules(v * 1e3)),
+    ("MJ", |v| Energy::from_joules(v * 1e6)),
+    ("GJ", |v| Energy::from_joules(v * 1e9)),
+    ("TJ", |v| Energy::from_joules(v * 1e12)),
+    ("PJ", |v| Energy::from_joules(v * 1e15)),
+    ("EJ", |v| Energy::from_joules(v * 1e18)),
+    ("joule", Energy::from_joules),
+    ("joules", Energy::from_joules),
+    ("kcal", Energy::from_kcalories),
+    ("kcalorie", Energy::from_kcalories),
+    ("kcalories", Energy::from_kcalories),
+    ("kilocalorie", Energy::from_kcalories),
+    ("kilocalories", Energy::from_kcalories),
+    ("BTU", Energy::from_btu),
+    ("eV", Energy::from_e_v),
+    ("Wh", Energy::from_watt_hours),
+    ("kWh", Energy::from_kilowatt_hours),
+    ("watt hour", Energy::from_watt_hours),
+    ("watt hours", Energy::from_watt_hours),
+    ("kilowatt hour", Energy::from_kilowatt_hours),
+    ("kilowatt hours", Energy::from_kilowatt_hours),
+    ("electronvolt", Energy::from_e_v),
+    ("electronvolts", Energy::from_e_v),
+];
+
+impl UnitNames for Energy {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Energy {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use energy::*;
@@ -242,4 +301,18 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Energy = "2.5 kWh".parse().unwrap();
+        let b: Energy = "3 MJ".parse().unwrap();
+        assert_almost_eq(a.as_kilowatt_hours(), 2.5);
+        assert_almost_eq(b.as_joules(), 3e6);
+    }
+
+    #[test]
+    fn units() {
+        let a = Energy::from_unit(1.0, EnergyUnit::KilowattHour);
+        assert_almost_eq(a.as_joules(), 3.6e6);
+        assert_almost_eq(a.as_unit(EnergyUnit::WattHour), 1000.0);
+    }
 }
@@ -0,0 +1,349 @@
+//! Engineering notation with SI prefixes.
+//!
+//! `Engineering` formats any `Measurement` with an SI prefix, from quecto
+//! (10^-30) to quetta (10^30), attached to the unit named by
+//! `get_base_units_name`. The prefix is a power of a thousand chosen so that
+//! the value is at least one and below a thousand, as in "4.70 µF" or
+//! "12.5 MHz".
+//!
+//! A live readout whose value hovers near a boundary would switch between,
+//! say, "999 mV" and "1.00 V" on every update. Setting a hysteresis keeps
+//! the previous prefix until the value moves that fraction beyond its range.
+//!
+//! The prefixes and unit symbols are the same in every language, but a
+//! `Locale` can supply the decimal separator.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::{Engineering, Voltage};
+//!
+//! let mut readout = Engineering::new().sig_figs(3).hysteresis(0.05).ascii_space();
+//! assert_eq!(readout.format(&Voltage::from_millivolts(999.0)).to_string(), "999 mV");
+//! assert_eq!(readout.format(&Voltage::from_volts(1.02)).to_string(), "1020 mV");
+//! assert_eq!(readout.format(&Voltage::from_volts(1.2)).to_string(), "1.20 V");
+//! ```
+
+use super::display::{
+    absolute, decimal_exponent, decimals_for_sig_figs, power_of_ten, write_measurement, MAX_DIGITS,
+};
+use super::locale::Locale;
+use super::measurement::Measurement;
+use std::fmt;
+
+/// The SI prefixes used in engineering notation, smallest first.
+const PREFIXES: [&str; 21] = [
+    "q", "r", "y", "z", "a", "f", "p", "n", "\u{00B5}", "m", "", "k", "M", "G", "T", "P", "E", "Z",
+    "Y", "R", "Q",
+];
+
+/// The power of ten of the first prefix in `PREFIXES`.
+const SMALLEST_EXPONENT: i32 = -30;
+
+/// The power of ten of the last prefix in `PREFIXES`.
+const LARGEST_EXPONENT: i32 = 30;
+
+/// Formats measurements in engineering notation. Each call to `format`
+/// remembers the prefix it chose, for use by the hysteresis.
+#[derive(Copy, Clone, Debug)]
+pub struct Engineering {
+    sig_figs: usize,
+    hysteresis: f64,
+    ascii_space: bool,
+    decimal_separator: char,
+    exponent: Option<i32>,
+}
+
+impl Engineering {
+    /// Format to three significant figures, without hysteresis.
+    pub fn new() -> Self {
+        Engineering {
+            sig_figs: 3,
+            hysteresis: 0.0,
+            ascii_space: false,
+            decimal_separator: '.',
+            exponent: None,
+        }
+    }
+
+    /// Format to this many significant figures, up to 65535. Digits before
+    /// the decimal point are never dropped.
+    pub fn sig_figs(mut self, sig_figs: usize) -> Self {
+        self.sig_figs = sig_figs.min(MAX_DIGITS);
+        self
+    }
+
+    /// Keep the previous prefix while the value is within this fraction of
+    /// its range, so with 0.05 "mV" is kept from 0.95 mV to 1050 mV.
+    pub fn hysteresis(mut self, fraction: f64) -> Self {
+        self.hysteresis = fraction;
+        self
+    }
+
+    /// Separate the value and unit with an ASCII space rather than a
+    /// non-breaking space.
+    pub fn ascii_space(mut self) -> Self {
+        self.ascii_space = true;
+        self
+    }
+
+    /// Use the decimal separator of a locale.
+    pub fn locale(mut self, locale: &Locale) -> Self {
+        self.decimal_separator = locale.decimal_separator;
+        self
+    }
+
+    /// Forget the previous prefix, so the next value gets the prefix that
+    /// suits it best.
+    pub fn reset(&mut self) {
+        self.exponent = None;
+    }
+
+    /// Format a measurement.
+    pub fn format<T: Measurement>(&mut self, quantity: &T) -> EngineeringDisplay {
+        let (unit, scale, power) = prefixable_unit(quantity.get_base_units_name());
+        let value = quantity.as_base_units() * scale;
+        let exponent = self.choose_exponent(value, power);
+        self.exponent = Some(exponent);
+        let value = value / power_of_ten(exponent * power);
+        EngineeringDisplay {
+            value,
+            decimals: decimals_for_sig_figs(value, self.sig_figs),
+            prefix: PREFIXES[((exponent - SMALLEST_EXPONENT) / 3) as usize],
+            unit,
+            decimal_separator: self.decimal_separator,
+            separator: if self.ascii_space { ' ' } else { '\u{00A0}' },
+        }
+    }
+
+    /// The power of ten of the prefix for a value, where the prefixed unit
+    /// is raised to `power`.
+    fn choose_exponent(&self, value: f64, power: i32) -> i32 {
+        let magnitude = absolute(value);
+        let range = power_of_ten(3 * power);
+        if magnitude == 0.0 || !magnitude.is_finite() {
+            return match self.exponent {
+                Some(previous) if self.hysteresis > 0.0 => previous,
+                _ => 0,
+            };
+        }
+        if let Some(previous) = self.exponent {
+            let scaled = magnitude / power_of_ten(previous * power);
+            if self.hysteresis > 0.0
+                && scaled >= 1.0 - self.hysteresis
+                && scaled < range * (1.0 + self.hysteresis)
+            {
+                return previous;
+            }
+        }
+        let mut exponent = decimal_exponent(magnitude).div_euclid(3 * power) * 3;
+        // Rounding may carry the value up to the next prefix, as 999.7 mV
+        // does at three figures.
+        let scaled = magnitude / power_of_ten(exponent * power);
+        let decimals = decimals_for_sig_figs(scaled, self.sig_figs) as i32;
+        if scaled + 0.5 * power_of_ten(-decimals) >= range {
+            exponent += 3;
+        }
+        exponent.clamp(SMALLEST_EXPONENT, LARGEST_EXPONENT)
+    }
+}
+
+impl Default for Engineering {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A measurement formatted in engineering notation. Created by
+/// `Engineering::format`.
+#[derive(Copy, Clone, Debug)]
+pub struct EngineeringDisplay {
+    value: f64,
+    decimals: usize,
+    prefix: &'static str,
+    unit: &'static str,
+    decimal_separator: char,
+    separator: char,
+}
+
+impl EngineeringDisplay {
+    /// The value in the prefixed unit.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The SI prefix, for example "k". This is empty for the unprefixed
+    /// unit.
+    pub fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+
+    /// The unit the prefix is attached to, for example "g" for a `Mass`.
+    pub fn unit(&self) -> &'static str {
+        self.unit
+    }
+}
+
+impl fmt::Display for EngineeringDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_measurement(
+            f,
+            self.value,
+            Some(self.decimals),
+            self.decimal_separator,
+            self.separator,
+            &format_args!("{}{}", self.prefix, self.unit),
+        )
+    }
+}
+
+/// Split a base unit name into the unit a prefix attaches to, the number of
+/// those units in one base unit, and the power the prefixed unit is raised
+/// to. Kilograms are prefixed as grams, octets as bytes, and the prefix of
+/// square metres is squared along with the metre.
+fn prefixable_unit(name: &'static str) -> (&'static str, f64, i32) {
+    let (unit, scale) = if name.starts_with("kg") {
+        (&name[1..], 1e3)
+    } else if name == "octets" {
+        ("B", 1.0)
+    } else {
+        (name, 1.0)
+    };
+    let numerator = unit.split('/').next().unwrap_or(unit);
+    let power = if numerator.ends_with('\u{00B2}') {
+        2
+    } else if numerator.ends_with('\u{00B3}') {
+        3
+    } else {
+        1
+    };
+    (unit, scale, power)
+}
+
+#[cfg(test)]
+mod test {
+    use area::Area;
+    use capacitance::Capacitance;
+    use data::Data;
+    use density::Density;
+    use engineering::*;
+    use frequency::Frequency;
+    use length::Length;
+    use mass::Mass;
+    use resistance::Resistance;
+    use voltage::Voltage;
+
+    fn format<T: Measurement>(quantity: &T) -> String {
+        Engineering::new()
+            .ascii_space()
+            .format(quantity)
+            .to_string()
+    }
+
+    #[test]
+    fn prefixes() {
+        assert_eq!(
+            format(&Capacitance::from_microfarads(4.7)),
+            "4.70 \u{00B5}F"
+        );
+        assert_eq!(format(&Frequency::from_hertz(12.5e6)), "12.5 MHz");
+        assert_eq!(format(&Resistance::from_ohms(220.0)), "220 \u{2126}");
+        assert_eq!(format(&Voltage::from_volts(-0.0123)), "-12.3 mV");
+        assert_eq!(format(&Length::from_meters(0.0)), "0.00 m");
+        assert_eq!(format(&Data::from_octets(1500.0)), "1.50 kB");
+        assert_eq!(format(&Data::from_gigaoctets(2.0)), "2.00 GB");
+    }
+
+    #[test]
+    fn full_range() {
+        assert_eq!(format(&Length::from_meters(1.5e-30)), "1.50 qm");
+        assert_eq!(format(&Length::from_meters(2.5e-28)), "250 qm");
+        assert_eq!(format(&Length::from_meters(3e30)), "3.00 Qm");
+        assert_eq!(format(&Length::from_meters(4e33)), "4000 Qm");
+        assert_eq!(format(&Length::from_meters(5e-33)), "0.00500 qm");
+    }
+
+    #[test]
+    fn kilograms() {
+        assert_eq!(format(&Mass::from_kilograms(70.0)), "70.0 kg");
+        assert_eq!(format(&Mass::from_grams(0.25)), "250 mg");
+        assert_eq!(format(&Density::from_kilograms_per_liter(1.0)), "1.00 kg/L");
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!(format(&Area::from_square_meters(5000.0)), "5000 m\u{00B2}");
+        assert_eq!(format(&Area::from_square_meters(2e6)), "2.00 km\u{00B2}");
+        assert_eq!(format(&Area::from_square_meters(3e-6)), "3.00 mm\u{00B2}");
+    }
+
+    #[test]
+    fn sig_figs() {
+        let voltage = Voltage::from_volts(1.23456);
+        assert_eq!(
+            Engineering::new().sig_figs(5).format(&voltage).to_string(),
+            "1.2346\u{00A0}V"
+        );
+        assert_eq!(
+            Engineering::new().sig_figs(1).format(&voltage).to_string(),
+            "1\u{00A0}V"
+        );
+        let long = Engineering::new().sig_figs(usize::MAX).format(&voltage);
+        assert_eq!(long.to_string().len(), MAX_DIGITS + ".\u{00A0}V".len());
+    }
+
+    #[test]
+    fn rounding_carries_to_next_prefix() {
+        assert_eq!(format(&Voltage::from_millivolts(999.7)), "1.00 V");
+        assert_eq!(format(&Voltage::from_millivolts(999.4)), "999 mV");
+    }
+
+    #[test]
+    fn hysteresis() {
+        let mut readout = Engineering::new().hysteresis(0.05).ascii_space();
+        let mut show = |volts: f64| readout.format(&Voltage::from_volts(volts)).to_string();
+        assert_eq!(show(0.999), "999 mV");
+        assert_eq!(show(1.001), "1001 mV");
+        assert_eq!(show(0.998), "998 mV");
+        assert_eq!(show(1.1), "1.10 V");
+        assert_eq!(show(0.97), "0.970 V");
+        assert_eq!(show(0.9), "900 mV");
+        assert_eq!(show(0.0), "0.00 mV");
+    }
+
+    #[test]
+    fn reset() {
+        let mut readout = Engineering::new().hysteresis(0.05).ascii_space();
+        readout.format(&Voltage::from_volts(0.999));
+        readout.reset();
+        assert_eq!(
+            readout.format(&Voltage::from_volts(1.001)).to_string(),
+            "1.00 V"
+        );
+    }
+
+    #[test]
+    fn parts() {
+        let display = Engineering::new().format(&Mass::from_kilograms(1500.0));
+        assert_eq!(display.prefix(), "M");
+        assert_eq!(display.unit(), "g");
+        assert_eq!(display.value(), 1.5);
+    }
+
+    #[test]
+    fn locale() {
+        let display = Engineering::new()
+            .locale(&::locale::FR)
+            .ascii_space()
+            .format(&Capacitance::from_microfarads(4.7));
+        assert_eq!(display.to_string(), "4,70 \u{00B5}F");
+    }
+
+    #[test]
+    fn padding() {
+        let display = Engineering::new()
+            .ascii_space()
+            .format(&Frequency::from_hertz(50.0));
+        assert_eq!(format!("[{:>9}]", display), "[  50.0 Hz]");
+    }
+}
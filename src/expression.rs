@@ -0,0 +1,1295 @@
+//! Evaluates expressions such as "3 kg * 9.81 m/s^2" or "(2 ft + 7 in) * 3".
+//!
+//! An expression is made of quantities ("9.81 m/s^2", "3'6\"" or a bare unit
+//! such as "s"), plain numbers, parentheses and the operators `+`, `-`, `*`,
+//! `/` and `^` (with an integer exponent). An exponent straight after a unit
+//! applies to the unit alone, so "2 m^2" is two square metres, and a minus
+//! sign is applied after `^`, so "-2^2" is -4. Units are looked up in the same
+//! tables used by each quantity's `FromStr` implementation, and the result of
+//! multiplying or dividing two quantities follows the same relations as the
+//! operators implemented on the quantity types, so a Mass times an
+//! Acceleration is a Force.
+//!
+//! Products and quotients which no relation covers, such as the kg·m of
+//! "3 kg * 2 m / (1 s)^2" on the way to a Force, are worked out from their SI
+//! dimensions instead, as a `DynQuantity` would be. Angles, angular
+//! velocities, absolute temperatures and data are left out of this, for the
+//! same reasons as in `quantity`.
+//!
+//! The result is a `Value`, whose kind is only known at runtime. Use
+//! `Value::downcast` to turn it into one of the quantity types.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::Force;
+//! use measurements::expression::evaluate;
+//!
+//! let force = evaluate("3 kg * 9.81 m/s^2").unwrap();
+//! let force = force.downcast::<Force>().unwrap();
+//! println!("That's {} newtons", force.as_newtons());
+//! ```
+//!
+//! Where a unit name belongs to more than one quantity, the first of Length,
+//! Area, Volume, Mass, Temperature, Duration, Pressure, Speed, Acceleration,
+//! Energy, Power, Voltage, Current, Resistance, Force, Angle, Frequency,
+//! AngularVelocity, Torque, Data, DataRate, Density, VolumetricFlowRate,
+//! MassFlowRate, ElectricCharge, Capacitance, Inductance, Conductance,
+//! ApparentPower and ReactivePower wins. So "5 pounds" is a Mass and "20 °C"
+//! is an absolute Temperature; subtract two Temperatures to get a
+//! TemperatureDelta.
+
+use super::*;
+use display::{powi, write_measurement};
+use parse::{
+    is_word, micro_eq, scan_number, skip_whitespace, starts_number, ParseErrorKind, UnitNames,
+};
+use std::fmt;
+
+/// Implemented by every quantity type a `Value` can hold.
+pub trait ValueKind: Measurement {
+    /// The kind of `Value` which holds this quantity.
+    const KIND: Kind;
+}
+
+/// The result of evaluating an expression: a quantity whose kind is only
+/// known at runtime.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Value {
+    kind: Kind,
+    base_units: f64,
+}
+
+macro_rules! implement_kinds {
+    ($($kind:ident => $t:ty,)*) => {
+        /// The kinds of quantity a `Value` can hold.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum Kind {
+            /// A plain number, with no unit
+            Number,
+            /// A quantity of this dimension with no named type, held in
+            /// coherent SI units
+            Derived(DynDimension),
+            $(
+                #[doc = concat!("A `", stringify!($kind), "`")]
+                $kind,
+            )*
+        }
+
+        impl fmt::Display for Kind {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match *self {
+                    Kind::Number => f.write_str("Number"),
+                    Kind::Derived(dimension) => fmt::Display::fmt(&dimension, f),
+                    $(Kind::$kind => f.write_str(stringify!($kind)),)*
+                }
+            }
+        }
+
+        $(
+            impl ValueKind for $t {
+                const KIND: Kind = Kind::$kind;
+            }
+
+            impl From<$t> for Value {
+                fn from(measurement: $t) -> Value {
+                    Value::new(Kind::$kind, measurement.as_base_units())
+                }
+            }
+        )*
+
+        impl fmt::Display for Value {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match self.kind {
+                    Kind::Number => fmt::Display::fmt(&self.base_units, f),
+                    Kind::Derived(dimension) => {
+                        let precision = f.precision();
+                        write_measurement(
+                            f,
+                            self.base_units,
+                            precision,
+                            '.',
+                            '\u{00A0}',
+                            &dimension,
+                        )
+                    }
+                    $(Kind::$kind => display(&<$t>::from_base_units(self.base_units), f),)*
+                }
+            }
+        }
+    };
+}
+
+implement_kinds! {
+    Length => Length,
+    Area => Area,
+    Volume => Volume,
+    Mass => Mass,
+    Temperature => Temperature,
+    TemperatureDelta => TemperatureDelta,
+    Duration => time::Duration,
+    Pressure => Pressure,
+    Speed => Speed,
+    Acceleration => Acceleration,
+    Energy => Energy,
+    Power => Power,
+    Voltage => Voltage,
+    Current => Current,
+    Resistance => Resistance,
+    Force => Force,
+    Angle => Angle,
+    Frequency => Frequency,
+    AngularVelocity => AngularVelocity,
+    Torque => Torque,
+    TorqueEnergy => TorqueEnergy,
+    Data => Data,
+    DataRate => DataRate,
+    Density => Density,
+    VolumetricFlowRate => VolumetricFlowRate,
+    MassFlowRate => MassFlowRate,
+    ElectricCharge => ElectricCharge,
+    Capacitance => Capacitance,
+    Inductance => Inductance,
+    Conductance => Conductance,
+    ApparentPower => ApparentPower,
+    ReactivePower => ReactivePower,
+}
+
+/// Display a measurement the same way `implement_display!` does.
+fn display<T: Measurement>(measurement: &T, f: &mut fmt::Formatter) -> fmt::Result {
+    let (unit, value) = measurement.get_appropriate_units();
+    let precision = f.precision();
+    write_measurement(f, value, precision, '.', '\u{00A0}', &unit)
+}
+
+/// The relations between quantities, as (A, B, C, k) where A = k * B * C in
+/// base units. These are the relations implemented in lib.rs; k is only
+/// needed where the base units do not line up, as litres do not with cubic
+/// metres.
+const RELATIONS: &[(Kind, Kind, Kind, f64)] = &[
+    (Kind::Area, Kind::Length, Kind::Length, 1.0),
+    (Kind::Energy, Kind::Duration, Kind::Power, 1.0),
+    (Kind::Force, Kind::Mass, Kind::Acceleration, 1.0),
+    (Kind::Force, Kind::Pressure, Kind::Area, 1.0),
+    (Kind::Length, Kind::Duration, Kind::Speed, 1.0),
+    (Kind::Power, Kind::Force, Kind::Speed, 1.0),
+    (Kind::Speed, Kind::Duration, Kind::Acceleration, 1.0),
+    (Kind::Volume, Kind::Length, Kind::Area, 1e3),
+    (Kind::Power, Kind::AngularVelocity, Kind::Torque, 1.0),
+    (Kind::Power, Kind::Voltage, Kind::Current, 1.0),
+    (Kind::Voltage, Kind::Resistance, Kind::Current, 1.0),
+    (Kind::TorqueEnergy, Kind::Force, Kind::Length, 1.0),
+    (Kind::Mass, Kind::Volume, Kind::Density, 1.0),
+    (Kind::Volume, Kind::Duration, Kind::VolumetricFlowRate, 1.0),
+    (Kind::Mass, Kind::Duration, Kind::MassFlowRate, 1.0),
+    (
+        Kind::MassFlowRate,
+        Kind::VolumetricFlowRate,
+        Kind::Density,
+        1.0,
+    ),
+    (Kind::VolumetricFlowRate, Kind::Area, Kind::Speed, 1e3),
+    (Kind::ElectricCharge, Kind::Duration, Kind::Current, 1.0),
+    (Kind::Energy, Kind::ElectricCharge, Kind::Voltage, 1.0),
+    (Kind::Duration, Kind::Resistance, Kind::Capacitance, 1.0),
+    (Kind::Inductance, Kind::Duration, Kind::Resistance, 1.0),
+    (Kind::Current, Kind::Voltage, Kind::Conductance, 1.0),
+    (Kind::ElectricCharge, Kind::Capacitance, Kind::Voltage, 1.0),
+    (Kind::Data, Kind::Duration, Kind::DataRate, 1.0),
+];
+
+impl Value {
+    /// Create a new Value of the given kind from a floating point value in
+    /// that kind's base units.
+    pub fn new(kind: Kind, base_units: f64) -> Value {
+        Value { kind, base_units }
+    }
+
+    /// Create a new Value holding a plain number.
+    pub fn from_number(number: f64) -> Value {
+        Value::new(Kind::Number, number)
+    }
+
+    /// The kind of quantity this Value holds.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// This Value in its kind's base units.
+    pub fn as_base_units(&self) -> f64 {
+        self.base_units
+    }
+
+    /// This Value as a plain number, or `None` if it has a unit.
+    pub fn as_number(&self) -> Option<f64> {
+        if self.kind == Kind::Number {
+            Some(self.base_units)
+        } else {
+            None
+        }
+    }
+
+    /// Convert this Value into a quantity type, or `None` if it holds a
+    /// different kind of quantity. A `TorqueEnergy` can be converted into
+    /// either a `Torque` or an `Energy`, and a derived quantity into any
+    /// type of the same dimension.
+    pub fn downcast<T: ValueKind>(&self) -> Option<T> {
+        self.base_units_as(T::KIND).map(T::from_base_units)
+    }
+
+    /// This Value in the base units of `kind`, or `None` if it is a
+    /// different kind of quantity.
+    fn base_units_as(&self, kind: Kind) -> Option<f64> {
+        if same_kind(self.kind, kind) == Some(kind) {
+            return Some(self.base_units);
+        }
+        match (self.kind, si_dimension(kind)) {
+            (Kind::Derived(derived), Some((dimension, factor))) if derived == dimension => {
+                Some(self.base_units / factor)
+            }
+            _ => None,
+        }
+    }
+
+    /// This Value in coherent SI units, or `None` for the kinds which are
+    /// not combined by dimension.
+    fn to_quantity(self) -> Option<DynQuantity> {
+        let (dimension, factor) = si_dimension(self.kind)?;
+        Some(DynQuantity::new(self.base_units * factor, dimension))
+    }
+
+    /// A derived Value, or a number if the dimensions have cancelled out.
+    fn from_quantity(quantity: DynQuantity) -> Value {
+        if quantity.dimension().is_dimensionless() {
+            Value::from_number(quantity.value())
+        } else {
+            Value::new(Kind::Derived(quantity.dimension()), quantity.value())
+        }
+    }
+
+    /// Combine two Values by their SI dimensions, for the operations which
+    /// no named relation covers.
+    fn combine(
+        self,
+        rhs: Value,
+        operation: fn(DynQuantity, DynQuantity) -> Result<DynQuantity, DimensionError>,
+    ) -> Option<Value> {
+        let quantity = operation(self.to_quantity()?, rhs.to_quantity()?).ok()?;
+        Some(Value::from_quantity(quantity))
+    }
+
+    /// Convert this Value into a floating point value in the named unit.
+    ///
+    /// An error is returned if the unit is not known, or is not a unit of
+    /// this Value's kind. A `TorqueEnergy` can be converted into units of
+    /// either Torque or Energy, and a derived quantity into units of any
+    /// kind of the same dimension.
+    pub fn as_unit(&self, unit: &str) -> Result<f64, ExpressionError> {
+        let name = unit.trim();
+        let position = unit.len() - unit.trim_start().len();
+        if name.is_empty() {
+            return Err(ExpressionError::syntax(
+                ParseErrorKind::MissingUnit,
+                position,
+            ));
+        }
+        let whole = |u: &UnitMatch| u.length == name.len();
+        let compatible = |k: Kind| self.base_units_as(k).is_some();
+        let found = match_unit_of(name, &compatible)
+            .filter(whole)
+            .and_then(|u| Some((u, self.base_units_as(u.table.kind)?)));
+        let (found, base_units) = match found {
+            Some(found) => found,
+            None => {
+                return Err(match match_unit(name).filter(whole) {
+                    Some(other) => ExpressionError::new(
+                        ExpressionErrorKind::IncompatibleUnit {
+                            kind: self.kind,
+                            unit: other.table.kind,
+                        },
+                        position,
+                    ),
+                    None => ExpressionError::syntax(ParseErrorKind::UnknownUnit, position),
+                })
+            }
+        };
+        // Every unit is an affine function of base units, so two points are
+        // enough to invert it.
+        let zero = found.value(0.0).base_units;
+        let one = found.value(1.0).base_units;
+        Ok((base_units - zero) / (one - zero))
+    }
+
+    /// Add two Values, or return `None` if they cannot be added.
+    pub fn checked_add(self, rhs: Value) -> Option<Value> {
+        let kind = match (self.kind, rhs.kind) {
+            (Kind::Temperature, Kind::TemperatureDelta) => Kind::Temperature,
+            (Kind::TemperatureDelta, Kind::Temperature) => Kind::Temperature,
+            (Kind::Temperature, _) | (_, Kind::Temperature) => return None,
+            (l, r) => match same_kind(l, r) {
+                Some(kind) => kind,
+                None => return self.combine(rhs, DynQuantity::try_add),
+            },
+        };
+        Some(Value::new(kind, self.base_units + rhs.base_units))
+    }
+
+    /// Subtract one Value from another, or return `None` if they cannot be
+    /// subtracted.
+    pub fn checked_sub(self, rhs: Value) -> Option<Value> {
+        let kind = match (self.kind, rhs.kind) {
+            (Kind::Temperature, Kind::Temperature) => Kind::TemperatureDelta,
+            (Kind::Temperature, Kind::TemperatureDelta) => Kind::Temperature,
+            (Kind::Temperature, _) | (_, Kind::Temperature) => return None,
+            (l, r) => match same_kind(l, r) {
+                Some(kind) => kind,
+                None => return self.combine(rhs, DynQuantity::try_sub),
+            },
+        };
+        Some(Value::new(kind, self.base_units - rhs.base_units))
+    }
+
+    /// Multiply two Values, or return `None` if they cannot be multiplied.
+    /// The product is a named kind where a relation gives one, and a
+    /// derived quantity otherwise.
+    pub fn checked_mul(self, rhs: Value) -> Option<Value> {
+        let (kind, factor) = match (self.kind, rhs.kind) {
+            (Kind::Temperature, _) | (_, Kind::Temperature) => return None,
+            (Kind::Number, k) | (k, Kind::Number) => (k, 1.0),
+            (l, r) => match RELATIONS
+                .iter()
+                .find(|&&(_, b, c, _)| (b, c) == (l, r) || (c, b) == (l, r))
+            {
+                Some(&(a, _, _, factor)) => (a, factor),
+                None => return self.combine(rhs, DynQuantity::try_mul),
+            },
+        };
+        Some(Value::new(kind, factor * self.base_units * rhs.base_units))
+    }
+
+    /// Divide one Value by another, or return `None` if they cannot be
+    /// divided. As with `checked_mul`, the quotient is a derived quantity
+    /// where no relation names it.
+    pub fn checked_div(self, rhs: Value) -> Option<Value> {
+        let (kind, factor) = match (self.kind, rhs.kind) {
+            (Kind::Temperature, _) | (_, Kind::Temperature) => return None,
+            (k, Kind::Number) => (k, 1.0),
+            (l, r) if same_kind(l, r).is_some() => (Kind::Number, 1.0),
+            (l, r) => {
+                let quotient = RELATIONS
+                    .iter()
+                    .filter(|&&(a, _, _, _)| same_kind(a, l).is_some())
+                    .find_map(|&(_, b, c, factor)| {
+                        if r == c {
+                            Some((b, factor))
+                        } else if r == b {
+                            Some((c, factor))
+                        } else {
+                            None
+                        }
+                    });
+                match quotient {
+                    Some(quotient) => quotient,
+                    None => return self.combine(rhs, DynQuantity::try_div),
+                }
+            }
+        };
+        Some(Value::new(kind, self.base_units / rhs.base_units / factor))
+    }
+
+    /// Negate this Value, or return `None` for an absolute Temperature.
+    pub fn checked_neg(self) -> Option<Value> {
+        if self.kind == Kind::Temperature {
+            None
+        } else {
+            Some(Value::new(self.kind, -self.base_units))
+        }
+    }
+
+    /// Raise this Value to an integer power, or return `None` if there is
+    /// no quantity with the resulting dimensions. Only numbers may be raised
+    /// beyond `MAX_EXPONENT`, since no quantity has dimensions that large.
+    pub fn checked_powi(self, exponent: i32) -> Option<Value> {
+        if self.kind == Kind::Number {
+            return Some(Value::from_number(powi(self.base_units, exponent)));
+        }
+        if exponent.unsigned_abs() > MAX_EXPONENT {
+            return None;
+        }
+        let mut result = Value::from_number(1.0);
+        for _ in 0..exponent.unsigned_abs() {
+            result = if exponent > 0 {
+                result.checked_mul(self)?
+            } else {
+                result.checked_div(self)?
+            };
+        }
+        Some(result)
+    }
+}
+
+impl ::std::str::FromStr for Value {
+    type Err = ExpressionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        evaluate(s)
+    }
+}
+
+/// The largest power to which `Value::checked_powi` raises a quantity.
+const MAX_EXPONENT: u32 = 8;
+
+fn is_torque_or_energy(kind: Kind) -> bool {
+    kind == Kind::Torque || kind == Kind::Energy
+}
+
+/// The SI dimension of a kind of quantity, and the number of coherent SI
+/// units in one of its base units, or `None` for the kinds which are not
+/// combined by dimension. An Angle or an AngularVelocity would lose its
+/// radians, an absolute Temperature cannot be multiplied, and Data has no
+/// SI dimension.
+fn si_dimension(kind: Kind) -> Option<(DynDimension, f64)> {
+    fn of<T: Dimensioned>() -> Option<(DynDimension, f64)> {
+        Some((DynDimension::of::<T::Dimension>(), T::SI_FACTOR))
+    }
+    match kind {
+        Kind::Number => Some((DynDimension::DIMENSIONLESS, 1.0)),
+        Kind::Derived(dimension) => Some((dimension, 1.0)),
+        Kind::Length => of::<Length>(),
+        Kind::Area => of::<Area>(),
+        Kind::Volume => of::<Volume>(),
+        Kind::Mass => of::<Mass>(),
+        Kind::TemperatureDelta => of::<TemperatureDelta>(),
+        Kind::Duration => of::<time::Duration>(),
+        Kind::Pressure => of::<Pressure>(),
+        Kind::Speed => of::<Speed>(),
+        Kind::Acceleration => of::<Acceleration>(),
+        Kind::Energy => of::<Energy>(),
+        Kind::Power => of::<Power>(),
+        Kind::Voltage => of::<Voltage>(),
+        Kind::Current => of::<Current>(),
+        Kind::Resistance => of::<Resistance>(),
+        Kind::Force => of::<Force>(),
+        Kind::Frequency => of::<Frequency>(),
+        Kind::Torque => of::<Torque>(),
+        Kind::TorqueEnergy => of::<TorqueEnergy>(),
+        Kind::Density => of::<Density>(),
+        Kind::VolumetricFlowRate => of::<VolumetricFlowRate>(),
+        Kind::MassFlowRate => of::<MassFlowRate>(),
+        Kind::ElectricCharge => of::<ElectricCharge>(),
+        Kind::Capacitance => of::<Capacitance>(),
+        Kind::Inductance => of::<Inductance>(),
+        Kind::Conductance => of::<Conductance>(),
+        Kind::ApparentPower => of::<ApparentPower>(),
+        Kind::ReactivePower => of::<ReactivePower>(),
+        Kind::Temperature | Kind::Angle | Kind::AngularVelocity | Kind::Data | Kind::DataRate => {
+            None
+        }
+    }
+}
+
+/// The kind of the sum of two kinds, treating a `TorqueEnergy` as the same
+/// kind as a `Torque` or an `Energy`.
+fn same_kind(l: Kind, r: Kind) -> Option<Kind> {
+    if l == r {
+        Some(l)
+    } else if l == Kind::TorqueEnergy && is_torque_or_energy(r) {
+        Some(r)
+    } else if r == Kind::TorqueEnergy && is_torque_or_energy(l) {
+        Some(l)
+    } else {
+        None
+    }
+}
+
+/// Describes why an expression could not be evaluated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExpressionErrorKind {
+    /// Part of the expression could not be read.
+    Syntax(ParseErrorKind),
+    /// An opening parenthesis has no matching closing parenthesis.
+    UnclosedParenthesis,
+    /// An operator was applied to quantities which have no physical
+    /// relation, for example a Length was added to a Mass.
+    DimensionMismatch {
+        /// The kind of the left hand operand
+        left: Kind,
+        /// The operator
+        operator: char,
+        /// The kind of the right hand operand
+        right: Kind,
+    },
+    /// A Value was converted into a unit of a different kind of quantity.
+    IncompatibleUnit {
+        /// The kind of the Value
+        kind: Kind,
+        /// The kind of quantity the unit measures
+        unit: Kind,
+    },
+}
+
+/// The error returned when an expression cannot be evaluated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExpressionError {
+    kind: ExpressionErrorKind,
+    position: usize,
+}
+
+impl ExpressionError {
+    /// Create a new error of the given kind, at the given byte offset into
+    /// the input.
+    pub fn new(kind: ExpressionErrorKind, position: usize) -> Self {
+        ExpressionError { kind, position }
+    }
+
+    /// Why the expression could not be evaluated.
+    pub fn kind(&self) -> ExpressionErrorKind {
+        self.kind
+    }
+
+    /// The byte offset into the input at which the problem was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    fn syntax(kind: ParseErrorKind, position: usize) -> Self {
+        ExpressionError::new(ExpressionErrorKind::Syntax(kind), position)
+    }
+
+    fn mismatch(left: Kind, operator: char, right: Kind, position: usize) -> Self {
+        ExpressionError::new(
+            ExpressionErrorKind::DimensionMismatch {
+                left,
+                operator,
+                right,
+            },
+            position,
+        )
+    }
+}
+
+impl From<ParseMeasurementError> for ExpressionError {
+    fn from(e: ParseMeasurementError) -> Self {
+        ExpressionError::syntax(e.kind(), e.position())
+    }
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ExpressionErrorKind::Syntax(kind) => {
+                ParseMeasurementError::new(kind, self.position).fmt(f)
+            }
+            ExpressionErrorKind::UnclosedParenthesis => {
+                write!(f, "unclosed parenthesis at byte {}", self.position)
+            }
+            ExpressionErrorKind::DimensionMismatch {
+                left,
+                operator,
+                right,
+            } => write!(
+                f,
+                "cannot apply '{}' to {} and {} at byte {}",
+                operator, left, right, self.position
+            ),
+            ExpressionErrorKind::IncompatibleUnit { kind, unit } => write!(
+                f,
+                "cannot convert {} into a unit of {} at byte {}",
+                kind, unit, self.position
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl ::std::error::Error for ExpressionError {}
+
+/// Evaluate an expression, returning a `Value` of whichever kind of quantity
+/// it produces.
+pub fn evaluate(input: &str) -> Result<Value, ExpressionError> {
+    let mut parser = Parser { input, position: 0 };
+    parser.skip_whitespace();
+    if parser.at_end() {
+        return Err(ExpressionError::syntax(ParseErrorKind::Empty, 0));
+    }
+    let value = parser.expression()?;
+    parser.skip_whitespace();
+    if !parser.at_end() {
+        return Err(ExpressionError::syntax(
+            ParseErrorKind::UnexpectedTerm,
+            parser.position,
+        ));
+    }
+    Ok(value)
+}
+
+/// The units of every kind of quantity, in the order in which they are
+/// searched.
+const UNIT_TABLES: &[UnitTable] = &[
+    UnitTable::new::<Length>(Kind::Length),
+    UnitTable::new::<Area>(Kind::Area),
+    UnitTable::new::<Volume>(Kind::Volume),
+    UnitTable::new::<Mass>(Kind::Mass),
+    UnitTable::new::<Temperature>(Kind::Temperature),
+    UnitTable::new::<TemperatureDelta>(Kind::TemperatureDelta),
+    UnitTable::new::<time::Duration>(Kind::Duration),
+    UnitTable::new::<Pressure>(Kind::Pressure),
+    UnitTable::new::<Speed>(Kind::Speed),
+    UnitTable::new::<Acceleration>(Kind::Acceleration),
+    UnitTable::new::<Energy>(Kind::Energy),
+    UnitTable::new::<Power>(Kind::Power),
+    UnitTable::new::<Voltage>(Kind::Voltage),
+    UnitTable::new::<Current>(Kind::Current),
+    UnitTable::new::<Resistance>(Kind::Resistance),
+    UnitTable::new::<Force>(Kind::Force),
+    UnitTable::new::<Angle>(Kind::Angle),
+    UnitTable::new::<Frequency>(Kind::Frequency),
+    UnitTable::new::<AngularVelocity>(Kind::AngularVelocity),
+    UnitTable::new::<Torque>(Kind::Torque),
+    UnitTable::new::<Data>(Kind::Data),
+    UnitTable::new::<DataRate>(Kind::DataRate),
+    UnitTable::new::<Density>(Kind::Density),
+    UnitTable::new::<VolumetricFlowRate>(Kind::VolumetricFlowRate),
+    UnitTable::new::<MassFlowRate>(Kind::MassFlowRate),
+    UnitTable::new::<ElectricCharge>(Kind::ElectricCharge),
+    UnitTable::new::<Capacitance>(Kind::Capacitance),
+    UnitTable::new::<Inductance>(Kind::Inductance),
+    UnitTable::new::<Conductance>(Kind::Conductance),
+    UnitTable::new::<ApparentPower>(Kind::ApparentPower),
+    UnitTable::new::<ReactivePower>(Kind::ReactivePower),
+];
+
+/// The unit names of one kind of quantity.
+struct UnitTable {
+    kind: Kind,
+    /// Returns the name of the unit at an index, or `None` past the end
+    name: fn(usize) -> Option<&'static str>,
+    /// Converts a value in the unit at an index into base units
+    to_base_units: fn(usize, f64) -> f64,
+}
+
+impl UnitTable {
+    const fn new<T: Measurement + UnitNames>(kind: Kind) -> UnitTable {
+        UnitTable {
+            kind,
+            name: unit_name::<T>,
+            to_base_units: unit_to_base_units::<T>,
+        }
+    }
+}
+
+fn unit_name<T: UnitNames>(index: usize) -> Option<&'static str> {
+    T::unit_names().get(index).map(|&(name, _)| name)
+}
+
+fn unit_to_base_units<T: Measurement + UnitNames>(index: usize, value: f64) -> f64 {
+    (T::unit_names()[index].1)(value).as_base_units()
+}
+
+/// A unit found at the start of some input.
+#[derive(Copy, Clone)]
+struct UnitMatch {
+    table: &'static UnitTable,
+    index: usize,
+    /// The length of the unit name in the input
+    length: usize,
+}
+
+impl UnitMatch {
+    fn value(&self, value: f64) -> Value {
+        Value::new(
+            self.table.kind,
+            (self.table.to_base_units)(self.index, value),
+        )
+    }
+}
+
+/// Find the longest unit name at the start of `input`. As with
+/// `parse::lookup_unit`, an exact match is preferred, then a "u" or Greek mu
+/// in place of the micro sign, then a case-insensitive match of a name which
+/// is a word, as long as only one unit matches. The name must not be followed
+/// by a letter.
+fn match_unit(input: &str) -> Option<UnitMatch> {
+    match_unit_of(input, &|_| true)
+}
+
+/// As `match_unit`, but only the units of kinds for which `kinds` returns
+/// true are considered.
+fn match_unit_of(input: &str, kinds: &dyn Fn(Kind) -> bool) -> Option<UnitMatch> {
+    let exact = |name: &str| {
+        if input.starts_with(name) {
+            Some(name.len())
+        } else {
+            None
+        }
+    };
+    let micro = |name: &str| {
+        if !name.starts_with('\u{00B5}') {
+            return None;
+        }
+        let prefix = input.chars().next()?.len_utf8();
+        let length = prefix + name.len() - '\u{00B5}'.len_utf8();
+        let candidate = input.get(..length)?;
+        if micro_eq(name, candidate) {
+            Some(length)
+        } else {
+            None
+        }
+    };
+    let case_insensitive = |name: &str| match input.get(..name.len()) {
+        Some(candidate) if candidate.eq_ignore_ascii_case(name) && is_word(name, &listed) => {
+            Some(name.len())
+        }
+        _ => None,
+    };
+    longest_unit(input, kinds, &exact, false)
+        .or_else(|| longest_unit(input, kinds, &micro, false))
+        .or_else(|| longest_unit(input, kinds, &case_insensitive, true))
+}
+
+/// Whether any table lists a unit with this name.
+fn listed(name: &str) -> bool {
+    UNIT_TABLES.iter().any(|table| {
+        let mut index = 0;
+        while let Some(other) = (table.name)(index) {
+            if other == name {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    })
+}
+
+fn longest_unit(
+    input: &str,
+    kinds: &dyn Fn(Kind) -> bool,
+    matches: &dyn Fn(&str) -> Option<usize>,
+    unique: bool,
+) -> Option<UnitMatch> {
+    let mut best: Option<UnitMatch> = None;
+    let mut ambiguous = false;
+    for table in UNIT_TABLES.iter().filter(|t| kinds(t.kind)) {
+        let mut index = 0;
+        while let Some(name) = (table.name)(index) {
+            if let Some(length) = matches(name).filter(|&l| !followed_by_letter(input, l)) {
+                match best {
+                    Some(b) if b.length > length => {}
+                    Some(b) if b.length == length => ambiguous = true,
+                    _ => {
+                        best = Some(UnitMatch {
+                            table,
+                            index,
+                            length,
+                        });
+                        ambiguous = false;
+                    }
+                }
+            }
+            index += 1;
+        }
+    }
+    if unique && ambiguous {
+        None
+    } else {
+        best
+    }
+}
+
+fn followed_by_letter(input: &str, position: usize) -> bool {
+    input[position..]
+        .chars()
+        .next()
+        .is_some_and(char::is_alphabetic)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        self.position = skip_whitespace(self.input, self.position);
+    }
+
+    fn at_end(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    /// Skip whitespace, and if the next character is one of `operators`
+    /// consume and return it.
+    fn operator(&mut self, operators: &[char]) -> Option<char> {
+        self.skip_whitespace();
+        let c = self.input[self.position..].chars().next()?;
+        if operators.contains(&c) {
+            self.position += c.len_utf8();
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    /// expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Result<Value, ExpressionError> {
+        let mut value = self.term()?;
+        loop {
+            self.skip_whitespace();
+            let position = self.position;
+            let operator = match self.operator(&['+', '-']) {
+                Some(operator) => operator,
+                None => return Ok(value),
+            };
+            let rhs = self.term()?;
+            value = match operator {
+                '+' => value.checked_add(rhs),
+                _ => value.checked_sub(rhs),
+            }
+            .ok_or_else(|| ExpressionError::mismatch(value.kind, operator, rhs.kind, position))?;
+        }
+    }
+
+    /// term := unary (('*' | '/') unary)*
+    fn term(&mut self) -> Result<Value, ExpressionError> {
+        let mut value = self.unary()?;
+        loop {
+            self.skip_whitespace();
+            let position = self.position;
+            let operator = match self.operator(&['*', '\u{00D7}', '\u{00B7}', '/', '\u{00F7}']) {
+                Some(operator) => operator,
+                None => return Ok(value),
+            };
+            let rhs = self.unary()?;
+            value = match operator {
+                '/' | '\u{00F7}' => value.checked_div(rhs),
+                _ => value.checked_mul(rhs),
+            }
+            .ok_or_else(|| ExpressionError::mismatch(value.kind, operator, rhs.kind, position))?;
+        }
+    }
+
+    /// unary := ('-' | '+') unary | power
+    ///
+    /// A minus sign directly before a number belongs to the number, so
+    /// "-40 °C" is minus forty degrees Celsius rather than the negation of
+    /// forty degrees Celsius.
+    fn unary(&mut self) -> Result<Value, ExpressionError> {
+        self.skip_whitespace();
+        let position = self.position;
+        let operator = match self.operator(&['-', '+']) {
+            Some(operator) => operator,
+            None => return self.power(false),
+        };
+        let negative = operator == '-';
+        if starts_number(self.input, skip_whitespace(self.input, self.position)) {
+            return self.power(negative);
+        }
+        let value = self.unary()?;
+        if !negative {
+            return Ok(value);
+        }
+        value
+            .checked_neg()
+            .ok_or_else(|| ExpressionError::mismatch(Kind::Number, '-', value.kind, position))
+    }
+
+    /// power := primary ('^' integer)?
+    ///
+    /// A minus sign before a plain number is applied after the power, so
+    /// "-2^2" is -4.
+    fn power(&mut self, negative: bool) -> Result<Value, ExpressionError> {
+        let value = self.primary(negative)?;
+        let (position, exponent) = match self.exponent()? {
+            Some(exponent) => exponent,
+            None => return Ok(value),
+        };
+        let negate = negative && value.kind == Kind::Number;
+        let base = if negate {
+            Value::from_number(-value.base_units)
+        } else {
+            value
+        };
+        let result = base
+            .checked_powi(exponent)
+            .ok_or_else(|| ExpressionError::mismatch(value.kind, '^', Kind::Number, position))?;
+        if negate {
+            Ok(Value::from_number(-result.base_units))
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Read '^' and an integer exponent if they come next, returning the
+    /// position of the '^' and the exponent.
+    fn exponent(&mut self) -> Result<Option<(usize, i32)>, ExpressionError> {
+        let position = skip_whitespace(self.input, self.position);
+        if !self.input[position..].starts_with('^') {
+            return Ok(None);
+        }
+        let start = skip_whitespace(self.input, position + 1);
+        let end = scan_number(self.input, start);
+        let exponent: i32 = self.input[start..end]
+            .parse()
+            .map_err(|_| ExpressionError::syntax(ParseErrorKind::InvalidNumber, start))?;
+        self.position = end;
+        Ok(Some((position, exponent)))
+    }
+
+    /// primary := '(' expression ')' | quantity | unit
+    fn primary(&mut self, negative: bool) -> Result<Value, ExpressionError> {
+        self.skip_whitespace();
+        let start = self.position;
+        if self.operator(&['(']).is_some() {
+            let value = self.expression()?;
+            if self.operator(&[')']).is_none() {
+                return Err(ExpressionError::new(
+                    ExpressionErrorKind::UnclosedParenthesis,
+                    start,
+                ));
+            }
+            return Ok(value);
+        }
+        if starts_number(self.input, start) {
+            return self.quantity(negative);
+        }
+        match match_unit(&self.input[start..]) {
+            Some(unit) => {
+                self.position += unit.length;
+                Ok(unit.value(1.0))
+            }
+            None if self.at_end() || !followed_by_letter(self.input, start) => Err(
+                ExpressionError::syntax(ParseErrorKind::InvalidNumber, start),
+            ),
+            None => Err(ExpressionError::syntax(ParseErrorKind::UnknownUnit, start)),
+        }
+    }
+
+    /// quantity := number (unit ('^' integer)?)? (number unit ('^' integer)?)*
+    ///
+    /// Several number and unit pairs in a row are added together, as with
+    /// `FromStr`, so "5 ft 11 in" and "3'6\"" are single quantities. An
+    /// exponent applies to the unit alone, so "2 m^2" is two square metres.
+    fn quantity(&mut self, negative: bool) -> Result<Value, ExpressionError> {
+        let (value, unit) = self.number_and_unit(negative)?;
+        let unit = match unit {
+            Some(unit) => unit,
+            None => return Ok(Value::from_number(value)),
+        };
+        let mut total = self.unit_power(value, unit)?;
+        loop {
+            let start = skip_whitespace(self.input, self.position);
+            if !starts_number(self.input, start) {
+                return Ok(total);
+            }
+            self.position = start;
+            let next = match self.number_and_unit(negative)? {
+                (_, None) => {
+                    return Err(ExpressionError::syntax(
+                        ParseErrorKind::MissingUnit,
+                        self.position,
+                    ))
+                }
+                (_, Some(_)) if total.kind == Kind::Temperature => {
+                    return Err(ExpressionError::syntax(
+                        ParseErrorKind::UnexpectedTerm,
+                        start,
+                    ))
+                }
+                (value, Some(next)) => self.unit_power(value, next)?,
+            };
+            if next.kind != total.kind {
+                return Err(ExpressionError::mismatch(total.kind, '+', next.kind, start));
+            }
+            total = Value::new(total.kind, total.base_units + next.base_units);
+        }
+    }
+
+    /// A number of a unit, which is raised to the exponent that follows it if
+    /// there is one.
+    fn unit_power(&mut self, value: f64, unit: UnitMatch) -> Result<Value, ExpressionError> {
+        let (position, exponent) = match self.exponent()? {
+            Some(exponent) => exponent,
+            None => return Ok(unit.value(value)),
+        };
+        let one = unit.value(1.0);
+        one.checked_powi(exponent)
+            .map(|power| Value::new(power.kind, power.base_units * value))
+            .ok_or_else(|| ExpressionError::mismatch(one.kind, '^', Kind::Number, position))
+    }
+
+    /// Read a number, negated if `negative` is set, and the unit that
+    /// follows it if there is one.
+    fn number_and_unit(
+        &mut self,
+        negative: bool,
+    ) -> Result<(f64, Option<UnitMatch>), ExpressionError> {
+        let start = self.position;
+        let end = scan_number(self.input, start);
+        let value: f64 = self.input[start..end]
+            .parse()
+            .map_err(|_| ExpressionError::syntax(ParseErrorKind::InvalidNumber, start))?;
+        let value = if negative { -value } else { value };
+        self.position = end;
+        let unit_start = skip_whitespace(self.input, end);
+        match match_unit(&self.input[unit_start..]) {
+            Some(unit) => {
+                self.position = unit_start + unit.length;
+                Ok((value, Some(unit)))
+            }
+            None if followed_by_letter(self.input, unit_start) => Err(ExpressionError::syntax(
+                ParseErrorKind::UnknownUnit,
+                unit_start,
+            )),
+            None => Ok((value, None)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use expression::*;
+    use test_utils::assert_almost_eq;
+
+    fn eval(input: &str) -> Value {
+        evaluate(input).unwrap()
+    }
+
+    fn error(input: &str) -> ExpressionError {
+        evaluate(input).unwrap_err()
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(eval("1 + 2 * 3").as_number(), Some(7.0));
+        assert_eq!(eval("(1 + 2) * 3").as_number(), Some(9.0));
+        assert_eq!(eval("2^10 / -4").as_number(), Some(-256.0));
+        assert_eq!(eval("-2^2").as_number(), Some(-4.0));
+        assert_eq!(eval("(-2)^2").as_number(), Some(4.0));
+        assert_eq!(eval("2^-2").as_number(), Some(0.25));
+        assert_eq!(eval("1^2000000000").as_number(), Some(1.0));
+        assert_eq!(eval("2^-2147483648").as_number(), Some(0.0));
+        assert_eq!(eval("-(1.5e1)").as_number(), Some(-15.0));
+    }
+
+    #[test]
+    fn force() {
+        let f: Force = eval("3 kg * 9.81 m/s^2").downcast().unwrap();
+        assert_almost_eq(f.as_newtons(), 29.43);
+        assert!(eval("3 kg * 9.81 m/s^2").downcast::<Mass>().is_none());
+    }
+
+    #[test]
+    fn parentheses() {
+        let l: Length = eval("(2 ft + 7 in) * 3").downcast().unwrap();
+        assert_almost_eq(l.as_inches(), 93.0);
+    }
+
+    #[test]
+    fn compound() {
+        let l: Length = eval("5 ft 11 in - 3'6\"").downcast().unwrap();
+        assert_almost_eq(l.as_inches(), 29.0);
+        let l: Length = eval("-3'6\"").downcast().unwrap();
+        assert_almost_eq(l.as_feet(), -3.5);
+    }
+
+    #[test]
+    fn relations() {
+        let p: Power = eval("12 V * 2 A").downcast().unwrap();
+        assert_almost_eq(p.as_watts(), 24.0);
+        let i: Current = eval("12 V / 4 ohm").downcast().unwrap();
+        assert_almost_eq(i.as_amperes(), 3.0);
+        let a: Area = eval("(2 m)^2").downcast().unwrap();
+        assert_almost_eq(a.as_square_meters(), 4.0);
+        let a: Area = eval("2 m^2").downcast().unwrap();
+        assert_almost_eq(a.as_square_meters(), 2.0);
+        let f: Force = eval("100 kPa * 2 m^2").downcast().unwrap();
+        assert_almost_eq(f.as_newtons(), 200e3);
+        let v: Volume = eval("2 m * 3 m * 4 m").downcast().unwrap();
+        assert_almost_eq(v.as_cubic_meters(), 24.0);
+        let v: Volume = eval("(2 m)^3").downcast().unwrap();
+        assert_almost_eq(v.as_cubic_meters(), 8.0);
+        let s: Speed = eval("100 m / 10 s").downcast().unwrap();
+        assert_almost_eq(s.as_meters_per_second(), 10.0);
+        let e: Energy = eval("2 kW * 3 h").downcast().unwrap();
+        assert_almost_eq(e.as_kilowatt_hours(), 6.0);
+        let p: Power = eval("100 J / s").downcast().unwrap();
+        assert_almost_eq(p.as_watts(), 100.0);
+        let q: VolumetricFlowRate = eval("1 m^2 * 0.02 m/s").downcast().unwrap();
+        assert_almost_eq(q.as_liters_per_second(), 20.0);
+        let v: Speed = eval("20 L/s / 1 m^2").downcast().unwrap();
+        assert_almost_eq(v.as_meters_per_second(), 0.02);
+        let q: ElectricCharge = eval("2 A * 3 h").downcast().unwrap();
+        assert_almost_eq(q.as_ampere_hours(), 6.0);
+        let e: Energy = eval("2500 mAh * 3.7 V").downcast().unwrap();
+        assert_almost_eq(e.as_watt_hours(), 9.25);
+        let t = eval("10 k\u{2126} * 100 uF").downcast::<time::Duration>();
+        assert_almost_eq(t.unwrap().as_secs_f64(), 1.0);
+        let g: Conductance = eval("10 mA / 5 V").downcast().unwrap();
+        assert_almost_eq(g.as_millisiemens(), 2.0);
+        let s: ApparentPower = eval("2 kVA + 500 VA").downcast().unwrap();
+        assert_almost_eq(s.as_kilovolt_amperes(), 2.5);
+        assert_eq!(eval("5 kvar * 2").kind(), Kind::ReactivePower);
+        let d: Data = eval("100 Mbit/s * 8 s").downcast().unwrap();
+        assert_almost_eq(d.as_megaoctets(), 100.0);
+        let r: DataRate = eval("1 GB / 80 s").downcast().unwrap();
+        assert_almost_eq(r.as_megabits_per_second(), 100.0);
+        assert_eq!(eval("6 m / 2 m").as_number(), Some(3.0));
+    }
+
+    #[test]
+    fn derived() {
+        let f: Force = eval("3 kg * 2 m / (1 s)^2").downcast().unwrap();
+        assert_almost_eq(f.as_newtons(), 6.0);
+        let p: Power = eval("2 N * 3 m / 4 s").downcast().unwrap();
+        assert_almost_eq(p.as_watts(), 1.5);
+        let f: Frequency = eval("1 / 2 s").downcast().unwrap();
+        assert_almost_eq(f.as_hertz(), 0.5);
+        let v = eval("2 m * 3 m * 4 m * 5 m / 10 m");
+        assert_almost_eq(v.as_unit("L").unwrap(), 12000.0);
+        let v = eval("3 kg * 2 m / (1 s)^2 + 4 N - 1 kg * 1 m / s^2");
+        assert_almost_eq(v.as_unit("N").unwrap(), 9.0);
+        assert_eq!(eval("3 kg * 2 m / (1 s)^2 / 2 N").as_number(), Some(3.0));
+        assert_eq!(
+            eval("3 kg * 2 m").kind(),
+            Kind::Derived(DynDimension::MASS * DynDimension::LENGTH)
+        );
+        assert_eq!(format!("{}", eval("1 / 2 s")), "0.5\u{00A0}Hz");
+        assert!(eval("3 kg * 2 m").downcast::<Torque>().is_none());
+        assert!(evaluate("3 kg * 2 m + 1 N").is_err());
+        assert!(evaluate("2 rad / 1 s * 3 kg").is_err());
+    }
+
+    #[test]
+    fn torque_energy() {
+        let v = eval("10 N * 2 m");
+        assert_eq!(v.kind(), Kind::TorqueEnergy);
+        let t: Torque = v.downcast().unwrap();
+        let e: Energy = v.downcast().unwrap();
+        assert_almost_eq(t.as_newton_metres(), 20.0);
+        assert_almost_eq(e.as_joules(), 20.0);
+        assert_eq!(eval("10 N * 2 m + 5 J").kind(), Kind::Energy);
+        assert_eq!(eval("20 Nm / 2 m").kind(), Kind::Force);
+    }
+
+    #[test]
+    fn temperature() {
+        let t: Temperature = eval("20 \u{00B0}C + (10 K - 5 K)").downcast().unwrap();
+        assert_eq!(
+            eval("20 \u{00B0}C - 10 \u{00B0}C").kind(),
+            Kind::TemperatureDelta
+        );
+        assert_almost_eq(t.as_celsius(), 25.0);
+        let t: Temperature = eval("-40 \u{00B0}C").downcast().unwrap();
+        assert_almost_eq(t.as_fahrenheit(), -40.0);
+    }
+
+    #[test]
+    fn mismatch() {
+        let e = error("2 m + 3 kg");
+        assert_eq!(
+            e.kind(),
+            ExpressionErrorKind::DimensionMismatch {
+                left: Kind::Length,
+                operator: '+',
+                right: Kind::Mass,
+            }
+        );
+        assert_eq!(e.position(), 4);
+        assert_eq!(
+            format!("{}", e),
+            "cannot apply '+' to Length and Mass at byte 4"
+        );
+        assert!(evaluate("(2 m)^9").is_err());
+        assert!(evaluate("2 m^-2147483648").is_err());
+        assert!(evaluate("(2 m)^2147483647").is_err());
+        assert!(evaluate("2 * 20 \u{00B0}C").is_err());
+    }
+
+    #[test]
+    fn syntax() {
+        assert_eq!(
+            error("  ").kind(),
+            ExpressionErrorKind::Syntax(ParseErrorKind::Empty)
+        );
+        let e = error("2 furlongz");
+        assert_eq!(
+            e.kind(),
+            ExpressionErrorKind::Syntax(ParseErrorKind::UnknownUnit)
+        );
+        assert_eq!(e.position(), 2);
+        let e = error("(2 m + 3 m");
+        assert_eq!(e.kind(), ExpressionErrorKind::UnclosedParenthesis);
+        assert_eq!(e.position(), 0);
+        let e = error("2 m +");
+        assert_eq!(
+            e.kind(),
+            ExpressionErrorKind::Syntax(ParseErrorKind::InvalidNumber)
+        );
+        let e = error("2 m)");
+        assert_eq!(
+            e.kind(),
+            ExpressionErrorKind::Syntax(ParseErrorKind::UnexpectedTerm)
+        );
+        assert_eq!(e.position(), 3);
+    }
+
+    #[test]
+    fn units() {
+        assert_almost_eq(
+            eval("7 um").downcast::<Length>().unwrap().as_micrometers(),
+            7.0,
+        );
+        assert_almost_eq(
+            eval("2 KILOMETERS")
+                .downcast::<Length>()
+                .unwrap()
+                .as_meters(),
+            2000.0,
+        );
+        assert!(evaluate("1 Mm").is_err());
+        assert_almost_eq(eval("90 min").as_base_units(), 5400.0);
+        assert_eq!(eval("5 pounds").kind(), Kind::Mass);
+        assert_eq!(eval("5 miles per hour").kind(), Kind::Speed);
+    }
+
+    #[test]
+    fn as_unit() {
+        assert_almost_eq(eval("3'6\"").as_unit("in").unwrap(), 42.0);
+        assert_almost_eq(eval("100 \u{00B0}C").as_unit("\u{00B0}F").unwrap(), 212.0);
+        assert_almost_eq(eval("10 N * 2 m").as_unit("kJ").unwrap(), 0.02);
+        assert_almost_eq(eval("60 rpm").as_unit("Hz").unwrap(), 1.0);
+        assert_eq!(
+            eval("2 m").as_unit("kg").unwrap_err().kind(),
+            ExpressionErrorKind::IncompatibleUnit {
+                kind: Kind::Length,
+                unit: Kind::Mass,
+            }
+        );
+        let e = eval("2 m").as_unit(" furlongz").unwrap_err();
+        assert_eq!(
+            e.kind(),
+            ExpressionErrorKind::Syntax(ParseErrorKind::UnknownUnit)
+        );
+        assert_eq!(e.position(), 1);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", eval("2 * 3")), "6");
+        assert_eq!(format!("{}", eval("2 m * 3")), "6\u{00A0}m");
+        assert_eq!(format!("{:>6}", eval("2 * 3")), "     6");
+        assert_eq!(format!("{:>6}", eval("2 m * 3")), "   6\u{00A0}m");
+        assert_eq!(format!("{:-<8.1}", eval("2 m * 3")), "6.0\u{00A0}m---");
+        assert_eq!(
+            format!("{:^10}", eval("3 kg * 2 m")),
+            "  6\u{00A0}kg\u{00B7}m  "
+        );
+    }
+
+    #[test]
+    fn from() {
+        let v = Value::from(Length::from_meters(2.0));
+        assert_eq!(v.kind(), Kind::Length);
+        assert_eq!("2 m".parse::<Value>(), Ok(v));
+    }
+}
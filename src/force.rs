@@ -1,6 +1,7 @@
 //! Types and constants for handling force.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 
 /// Number of POUNDS force in a Newton
 pub const POUNDS_PER_NEWTON: f64 = 0.224809;
@@ -136,6 +137,64 @@ impl Measurement for Force {
 
 implement_measurement! { Force }
 
+implement_units! {
+    Force, ForceUnit {
+        Micronewton => ("\u{00B5}N", "micronewton", "micronewtons", 1e-6) in Si,
+        Millinewton => ("mN", "millinewton", "millinewtons", 1e-3) in Si,
+        Newton => ("N", "newton", "newtons", 1.0) in Si,
+        Pound => ("lbf", "pound-force", "pounds-force", 1.0 / POUNDS_PER_NEWTON) in UsCustomary | Imperial,
+        Poundal => ("pdl", "poundal", "poundals", 1.0 / POUNDALS_PER_NEWTON),
+        Kilopond => ("kp", "kilopond", "kiloponds", 1.0 / KILOPONDS_PER_NEWTON),
+        Dyne => ("dyn", "dyne", "dynes", 1.0 / DYNES_PER_NEWTON),
+    }
+}
+
+/// The unit names understood when parsing a `Force`.
+const UNIT_NAMES: &[UnitConstructor<'static, Force>] = &[
+    ("nN", |v| Force::from_newtons(v * 1e-9)),
+    ("\u{00B5}N", |v| Force::from_newtons(v * 1e-6)),
+    ("mN", |v| Force::from_newtons(v * 1e-3)),
+    ("N", Force::from_newtons),
+    ("kN", |v| Force::from_newtons(v * 1e3)),
+    ("MN", |v| Force::from_newtons(v * 1e6)),
+    ("GN", |v| Force::from_newtons(v * 1e9)),
+    ("TN", |v| Force::from_newtons(v * 1e12)),
+    ("newton", Force::from_newtons),
+    ("newtons", Force::from_newtons),
+    ("micronewton", Force::from_micronewtons),
+    ("micronewtons", Force::from_micronewtons),
+    ("millinewton", Force::from_millinewtons),
+    ("millinewtons", Force::from_millinewtons),
+    ("lbf", Force::from_pounds),
+    ("pound", Force::from_pounds),
+    ("pounds", Force::from_pounds),
+    ("pound-force", Force::from_pounds),
+    ("pounds-force", Force::from_pounds),
+    ("pdl", Force::from_poundals),
+    ("poundal", Force::from_poundals),
+    ("poundals", Force::from_poundals),
+    ("kp", Force::from_kiloponds),
+    ("kilopond", Force::from_kiloponds),
+    ("kiloponds", Force::from_kiloponds),
+    ("dyn", Force::from_dynes),
+    ("dyne", Force::from_dynes),
+    ("dynes", Force::from_dynes),
+];
+
+impl UnitNames for Force {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Force {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use force::*;
@@ -282,4 +341,18 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Force = "10 kN".parse().unwrap();
+        let b: Force = "5 lbf".parse().unwrap();
+        assert_almost_eq(a.as_newtons(), 10000.0);
+        assert_almost_eq(b.as_pounds(), 5.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Force::from_unit(1.0, ForceUnit::Kilopond);
+        assert_almost_eq(a.as_newtons(), 9.80665);
+        assert_almost_eq(a.as_unit(ForceUnit::Dyne), 980665.0);
+    }
 }
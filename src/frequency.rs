@@ -1,7 +1,9 @@
 //! Types and constants for handling frequencies.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 use ::time;
+use super::{Capacitance, Inductance, Resistance};
 
 /// Number of nanohertz in a Hz
 pub const HERTZ_NANOHERTZ_FACTOR: f64 = 1e9;
@@ -83,6 +85,26 @@ impl Frequency {
         Self::from_hertz(1.0 / period.as_base_units())
     }
 
+    /// Create a new Frequency from the cutoff (-3 dB) frequency of a
+    /// first-order RC filter, 1 / (2πRC).
+    pub fn from_rc_cutoff(resistance: Resistance, capacitance: Capacitance) -> Self {
+        Self::from_hertz(1.0 / (2.0 * ::PI * resistance.as_ohms() * capacitance.as_farads()))
+    }
+
+    /// Create a new Frequency from the cutoff (-3 dB) frequency of a
+    /// first-order RL filter, R / (2πL).
+    pub fn from_rl_cutoff(resistance: Resistance, inductance: Inductance) -> Self {
+        Self::from_hertz(resistance.as_ohms() / (2.0 * ::PI * inductance.as_henries()))
+    }
+
+    /// Create a new Frequency from the resonant frequency of an LC circuit,
+    /// 1 / (2π√(LC)).
+    #[cfg(not(feature = "no_std"))]
+    pub fn from_lc_resonance(inductance: Inductance, capacitance: Capacitance) -> Self {
+        let lc = inductance.as_henries() * capacitance.as_farads();
+        Self::from_hertz(1.0 / (2.0 * ::PI * lc.sqrt()))
+    }
+
     /// Convert this Frequency to a floating point value in Nanohertz
     pub fn as_nanohertz(&self) -> f64 {
         self.hertz * HERTZ_NANOHERTZ_FACTOR
@@ -160,6 +182,53 @@ impl Measurement for Frequency {
 
 implement_measurement! { Frequency }
 
+implement_units! {
+    Frequency, FrequencyUnit {
+        Nanohertz => ("nHz", "nanohertz", "nanohertz", 1.0 / HERTZ_NANOHERTZ_FACTOR) in Si,
+        Microhertz => ("\u{00B5}Hz", "microhertz", "microhertz", 1.0 / HERTZ_MICROHERTZ_FACTOR) in Si,
+        Millihertz => ("mHz", "millihertz", "millihertz", 1.0 / HERTZ_MILLIHERTZ_FACTOR) in Si,
+        Hertz => ("Hz", "hertz", "hertz", 1.0) in Si,
+        Kilohertz => ("kHz", "kilohertz", "kilohertz", 1.0 / HERTZ_KILOHERTZ_FACTOR) in Si,
+        Megahertz => ("MHz", "megahertz", "megahertz", 1.0 / HERTZ_MEGAHERTZ_FACTOR) in Si,
+        Gigahertz => ("GHz", "gigahertz", "gigahertz", 1.0 / HERTZ_GIGAHERTZ_FACTOR) in Si,
+        Terahertz => ("THz", "terahertz", "terahertz", 1.0 / HERTZ_TERAHERTZ_FACTOR) in Si,
+    }
+}
+
+/// The unit names understood when parsing a `Frequency`.
+const UNIT_NAMES: &[UnitConstructor<'static, Frequency>] = &[
+    ("nHz", |v| Frequency::from_hertz(v * 1e-9)),
+    ("\u{00B5}Hz", |v| Frequency::from_hertz(v * 1e-6)),
+    ("mHz", |v| Frequency::from_hertz(v * 1e-3)),
+    ("Hz", Frequency::from_hertz),
+    ("kHz", |v| Frequency::from_hertz(v * 1e3)),
+    ("MHz", |v| Frequency::from_hertz(v * 1e6)),
+    ("GHz", |v| Frequency::from_hertz(v * 1e9)),
+    ("THz", |v| Frequency::from_hertz(v * 1e12)),
+    ("nanohertz", Frequency::from_nanohertz),
+    ("microhertz", Frequency::from_microhertz),
+    ("millihertz", Frequency::from_millihertz),
+    ("hertz", Frequency::from_hertz),
+    ("kilohertz", Frequency::from_kilohertz),
+    ("megahertz", Frequency::from_megahertz),
+    ("gigahertz", Frequency::from_gigahertz),
+    ("terahertz", Frequency::from_terahertz),
+];
+
+impl UnitNames for Frequency {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Frequency {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -253,6 +322,19 @@ mod test {
         assert_almost_eq(r2, 1e-2);
     }
 
+    #[test]
+    pub fn cutoff() {
+        let r = Resistance::from_kiloohms(1.0);
+        let c = Capacitance::from_microfarads(1.0);
+        let l = Inductance::from_millihenries(10.0);
+        let f1 = Frequency::from_rc_cutoff(r, c);
+        let f2 = Frequency::from_rl_cutoff(r, l);
+        let f3 = Frequency::from_lc_resonance(l, c);
+        assert_almost_eq(f1.as_hertz(), 159.15494);
+        assert_almost_eq(f2.as_kilohertz(), 15.915494);
+        assert_almost_eq(f3.as_hertz(), 1591.5494);
+    }
+
     // Traits
     #[test]
     fn add() {
@@ -315,4 +397,18 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Frequency = "100.3 MHz".parse().unwrap();
+        let b: Frequency = "50 hertz".parse().unwrap();
+        assert_almost_eq(a.as_megahertz(), 100.3);
+        assert_almost_eq(b.as_hertz(), 50.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Frequency::from_unit(100.3, FrequencyUnit::Megahertz);
+        assert_almost_eq(a.as_hertz(), 100.3e6);
+        assert_almost_eq(a.as_unit(FrequencyUnit::Kilohertz), 100_300.0);
+    }
 }
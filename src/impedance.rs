@@ -0,0 +1,474 @@
+//! Complex impedances and phasors, for analysing AC circuits.
+//!
+//! An `Impedance` is a resistance together with a reactance, which is
+//! positive for an inductor and negative for a capacitor. A `Phasor` is a
+//! sinusoidal voltage or current, with an RMS magnitude and a phase. Both are
+//! held as rectangular components, and Ohm's law relates them:
+//!
+//! ```
+//! use measurements::impedance::{Impedance, Phasor};
+//! use measurements::{Capacitance, Frequency, Resistance, Voltage};
+//!
+//! let f = Frequency::from_kilohertz(1.0);
+//! let z = Impedance::from(Resistance::from_ohms(100.0))
+//!     + Impedance::from_capacitance(Capacitance::from_microfarads(1.0), f);
+//! let i = Phasor::from(Voltage::from_volts(10.0)) / z;
+//! println!("{} flows through {}", i, z);
+//! ```
+
+use super::measurement::*;
+#[cfg(not(feature = "no_std"))]
+use super::Angle;
+use super::{Capacitance, Current, Frequency, Inductance, Resistance, Voltage};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The impedance of a component or network at a single frequency.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Impedance {
+    resistance: f64,
+    reactance: f64,
+}
+
+impl Impedance {
+    /// Create a new Impedance from its resistance and reactance. The
+    /// reactance is measured in ohms, so it is given as a `Resistance`.
+    pub fn new(resistance: Resistance, reactance: Resistance) -> Self {
+        Self::from_ohms(resistance.as_ohms(), reactance.as_ohms())
+    }
+
+    /// Create a new Impedance from its resistance and reactance as floating
+    /// point values in ohms
+    pub fn from_ohms(resistance: f64, reactance: f64) -> Self {
+        Impedance {
+            resistance,
+            reactance,
+        }
+    }
+
+    /// Create a new Impedance from its magnitude and phase angle
+    #[cfg(not(feature = "no_std"))]
+    pub fn from_polar(magnitude: Resistance, phase: Angle) -> Self {
+        let (sin, cos) = phase.sin_cos();
+        Self::from_ohms(magnitude.as_ohms() * cos, magnitude.as_ohms() * sin)
+    }
+
+    /// Create a new Impedance from an ideal capacitor at a frequency,
+    /// -1 / (2πfC)
+    pub fn from_capacitance(capacitance: Capacitance, frequency: Frequency) -> Self {
+        Self::from_ohms(
+            0.0,
+            -1.0 / (2.0 * ::PI * frequency.as_hertz() * capacitance.as_farads()),
+        )
+    }
+
+    /// Create a new Impedance from an ideal inductor at a frequency, 2πfL
+    pub fn from_inductance(inductance: Inductance, frequency: Frequency) -> Self {
+        Self::from_ohms(
+            0.0,
+            2.0 * ::PI * frequency.as_hertz() * inductance.as_henries(),
+        )
+    }
+
+    /// Create a new Impedance from impedances connected in series
+    pub fn series<I: IntoIterator<Item = Impedance>>(impedances: I) -> Self {
+        impedances
+            .into_iter()
+            .fold(Self::from_ohms(0.0, 0.0), |sum, z| sum + z)
+    }
+
+    /// Create a new Impedance from impedances connected in parallel. A zero
+    /// impedance shorts the others out, so the result is zero, and no
+    /// impedances at all are an open circuit, with infinite resistance.
+    pub fn parallel<I: IntoIterator<Item = Impedance>>(impedances: I) -> Self {
+        let mut admittance = Self::from_ohms(0.0, 0.0);
+        for z in impedances {
+            if z.resistance == 0.0 && z.reactance == 0.0 {
+                return Self::from_ohms(0.0, 0.0);
+            }
+            admittance = admittance + z.reciprocal();
+        }
+        if admittance.resistance == 0.0 && admittance.reactance == 0.0 {
+            Self::from_ohms(f64::INFINITY, 0.0)
+        } else {
+            admittance.reciprocal()
+        }
+    }
+
+    /// The resistance, or real part, of this Impedance
+    pub fn resistance(&self) -> Resistance {
+        Resistance::from_ohms(self.resistance)
+    }
+
+    /// The reactance, or imaginary part, of this Impedance
+    pub fn reactance(&self) -> Resistance {
+        Resistance::from_ohms(self.reactance)
+    }
+
+    /// The magnitude of this Impedance
+    #[cfg(not(feature = "no_std"))]
+    pub fn magnitude(&self) -> Resistance {
+        Resistance::from_ohms(self.resistance.hypot(self.reactance))
+    }
+
+    /// The phase angle of this Impedance, by which the voltage across it
+    /// leads the current through it
+    #[cfg(not(feature = "no_std"))]
+    pub fn phase(&self) -> Angle {
+        Angle::from_radians(self.reactance.atan2(self.resistance))
+    }
+
+    /// The reciprocal of this Impedance, in siemens rather than ohms.
+    fn reciprocal(&self) -> Self {
+        let norm = self.resistance * self.resistance + self.reactance * self.reactance;
+        Self::from_ohms(self.resistance / norm, -self.reactance / norm)
+    }
+}
+
+impl From<Resistance> for Impedance {
+    fn from(resistance: Resistance) -> Self {
+        Self::from_ohms(resistance.as_ohms(), 0.0)
+    }
+}
+
+impl Add for Impedance {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_ohms(
+            self.resistance + rhs.resistance,
+            self.reactance + rhs.reactance,
+        )
+    }
+}
+
+impl Sub for Impedance {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_ohms(
+            self.resistance - rhs.resistance,
+            self.reactance - rhs.reactance,
+        )
+    }
+}
+
+impl Mul<f64> for Impedance {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::from_ohms(self.resistance * rhs, self.reactance * rhs)
+    }
+}
+
+impl Div<f64> for Impedance {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self::from_ohms(self.resistance / rhs, self.reactance / rhs)
+    }
+}
+
+/// Formats as "100 - j159.2 Ω", in the units `Display` would pick for the
+/// larger of the two parts.
+impl fmt::Display for Impedance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_rectangular::<Resistance>(f, self.resistance, self.reactance)
+    }
+}
+
+/// A sinusoidal quantity, such as a `Voltage` or `Current`, with an RMS
+/// magnitude and a phase.
+#[derive(Copy, Clone, Debug)]
+pub struct Phasor<T> {
+    real: f64,
+    imaginary: f64,
+    unit: PhantomData<T>,
+}
+
+impl<T: Measurement> Phasor<T> {
+    /// Create a new Phasor from its real (in phase) and imaginary
+    /// (quadrature) parts.
+    pub fn new(real: T, imaginary: T) -> Self {
+        Phasor::from_base_units(real.as_base_units(), imaginary.as_base_units())
+    }
+
+    /// Create a new Phasor from its real and imaginary parts in base units.
+    pub fn from_base_units(real: f64, imaginary: f64) -> Self {
+        Phasor {
+            real,
+            imaginary,
+            unit: PhantomData,
+        }
+    }
+
+    /// Create a new Phasor from its RMS magnitude and phase angle
+    #[cfg(not(feature = "no_std"))]
+    pub fn from_polar(magnitude: T, phase: Angle) -> Self {
+        let (sin, cos) = phase.sin_cos();
+        let magnitude = magnitude.as_base_units();
+        Phasor::from_base_units(magnitude * cos, magnitude * sin)
+    }
+
+    /// The real, or in phase, part of this Phasor
+    pub fn real(&self) -> T {
+        T::from_base_units(self.real)
+    }
+
+    /// The imaginary, or quadrature, part of this Phasor
+    pub fn imaginary(&self) -> T {
+        T::from_base_units(self.imaginary)
+    }
+
+    /// The RMS magnitude of this Phasor
+    #[cfg(not(feature = "no_std"))]
+    pub fn magnitude(&self) -> T {
+        T::from_base_units(self.real.hypot(self.imaginary))
+    }
+
+    /// The phase angle of this Phasor
+    #[cfg(not(feature = "no_std"))]
+    pub fn phase(&self) -> Angle {
+        Angle::from_radians(self.imaginary.atan2(self.real))
+    }
+}
+
+impl<T: Measurement> From<T> for Phasor<T> {
+    fn from(value: T) -> Self {
+        Phasor::from_base_units(value.as_base_units(), 0.0)
+    }
+}
+
+impl<T: Measurement> Add for Phasor<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Phasor::from_base_units(self.real + rhs.real, self.imaginary + rhs.imaginary)
+    }
+}
+
+impl<T: Measurement> Sub for Phasor<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Phasor::from_base_units(self.real - rhs.real, self.imaginary - rhs.imaginary)
+    }
+}
+
+impl<T: Measurement> Neg for Phasor<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Phasor::from_base_units(-self.real, -self.imaginary)
+    }
+}
+
+impl<T: Measurement> Mul<f64> for Phasor<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Phasor::from_base_units(self.real * rhs, self.imaginary * rhs)
+    }
+}
+
+impl<T: Measurement> Div<f64> for Phasor<T> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Phasor::from_base_units(self.real / rhs, self.imaginary / rhs)
+    }
+}
+
+impl<T> PartialEq for Phasor<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.real == other.real && self.imaginary == other.imaginary
+    }
+}
+
+/// Formats as "3 + j4 V", in the units `Display` would pick for the larger of
+/// the two parts.
+impl<T: Measurement> fmt::Display for Phasor<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_rectangular::<T>(f, self.real, self.imaginary)
+    }
+}
+
+/// V = I × Z
+impl Mul<Impedance> for Phasor<Current> {
+    type Output = Phasor<Voltage>;
+
+    fn mul(self, rhs: Impedance) -> Phasor<Voltage> {
+        let (real, imaginary) =
+            multiply((self.real, self.imaginary), (rhs.resistance, rhs.reactance));
+        Phasor::from_base_units(real, imaginary)
+    }
+}
+
+/// V = Z × I
+impl Mul<Phasor<Current>> for Impedance {
+    type Output = Phasor<Voltage>;
+
+    fn mul(self, rhs: Phasor<Current>) -> Phasor<Voltage> {
+        rhs * self
+    }
+}
+
+/// I = V / Z
+impl Div<Impedance> for Phasor<Voltage> {
+    type Output = Phasor<Current>;
+
+    fn div(self, rhs: Impedance) -> Phasor<Current> {
+        let (real, imaginary) = multiply(
+            (self.real, self.imaginary),
+            (rhs.reciprocal().resistance, rhs.reciprocal().reactance),
+        );
+        Phasor::from_base_units(real, imaginary)
+    }
+}
+
+/// Z = V / I
+impl Div<Phasor<Current>> for Phasor<Voltage> {
+    type Output = Impedance;
+
+    fn div(self, rhs: Phasor<Current>) -> Impedance {
+        let current = Impedance::from_ohms(rhs.real, rhs.imaginary).reciprocal();
+        let (resistance, reactance) = multiply(
+            (self.real, self.imaginary),
+            (current.resistance, current.reactance),
+        );
+        Impedance::from_ohms(resistance, reactance)
+    }
+}
+
+/// The product of two complex numbers, as (real, imaginary) pairs.
+fn multiply(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+/// Write a complex number in rectangular form, scaled to the units of `T`
+/// which suit the larger of its parts.
+fn write_rectangular<T: Measurement>(
+    f: &mut fmt::Formatter,
+    real: f64,
+    imaginary: f64,
+) -> fmt::Result {
+    let reference = if imaginary.abs() > real.abs() {
+        imaginary
+    } else {
+        real
+    };
+    let (unit, scaled) = T::from_base_units(reference).get_appropriate_units();
+    let scale = if reference != 0.0 {
+        scaled / reference
+    } else {
+        1.0
+    };
+    let (real, imaginary) = (real * scale, imaginary * scale);
+    let sign = if imaginary.is_sign_negative() {
+        '-'
+    } else {
+        '+'
+    };
+    match f.precision() {
+        Some(precision) => write!(
+            f,
+            "{:.*} {} j{:.*}\u{00A0}{}",
+            precision,
+            real,
+            sign,
+            precision,
+            imaginary.abs(),
+            unit
+        ),
+        None => write!(f, "{} {} j{}\u{00A0}{}", real, sign, imaginary.abs(), unit),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use impedance::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn components() {
+        let z = Impedance::new(Resistance::from_ohms(30.0), Resistance::from_ohms(-40.0));
+        assert_almost_eq(z.resistance().as_ohms(), 30.0);
+        assert_almost_eq(z.reactance().as_ohms(), -40.0);
+        assert_almost_eq(z.magnitude().as_ohms(), 50.0);
+        assert_almost_eq(z.phase().as_degrees(), -53.130_102_354_155_98);
+        let polar = Impedance::from_polar(z.magnitude(), z.phase());
+        assert_almost_eq(polar.resistance().as_ohms(), 30.0);
+        assert_almost_eq(polar.reactance().as_ohms(), -40.0);
+    }
+
+    #[test]
+    fn reactance() {
+        let f = Frequency::from_kilohertz(1.0);
+        let c = Impedance::from_capacitance(Capacitance::from_microfarads(1.0), f);
+        assert_eq!(c.resistance().as_ohms(), 0.0);
+        assert_almost_eq(c.reactance().as_ohms(), -159.154_943_091_895_35);
+        let l = Impedance::from_inductance(Inductance::from_millihenries(10.0), f);
+        assert_almost_eq(l.reactance().as_ohms(), 62.831_853_071_795_87);
+    }
+
+    #[test]
+    fn series_parallel() {
+        let r = Impedance::from(Resistance::from_ohms(100.0));
+        let x = Impedance::from_ohms(0.0, 100.0);
+        let series = Impedance::series(vec![r, x]);
+        assert_eq!(series, r + x);
+        assert_almost_eq(series.reactance().as_ohms(), 100.0);
+        let parallel = Impedance::parallel(vec![r, x]);
+        assert_almost_eq(parallel.resistance().as_ohms(), 50.0);
+        assert_almost_eq(parallel.reactance().as_ohms(), 50.0);
+        let resonant = Impedance::parallel(vec![
+            Impedance::from_ohms(0.0, 50.0),
+            Impedance::from_ohms(0.0, -50.0),
+        ]);
+        assert!(resonant.resistance().as_ohms().is_infinite());
+        assert!(Impedance::parallel(vec![])
+            .resistance()
+            .as_ohms()
+            .is_infinite());
+        let zero = Impedance::from_ohms(0.0, 0.0);
+        assert_eq!(Impedance::parallel(vec![zero, r]), zero);
+        assert_eq!(Impedance::parallel(vec![x, zero, r]), zero);
+    }
+
+    #[test]
+    fn ohms_law() {
+        let z = Impedance::from_ohms(3.0, 4.0);
+        let v = Phasor::from(Voltage::from_volts(10.0));
+        let i: Phasor<Current> = v / z;
+        assert_almost_eq(i.real().as_amperes(), 1.2);
+        assert_almost_eq(i.imaginary().as_amperes(), -1.6);
+        assert_almost_eq(i.magnitude().as_amperes(), 2.0);
+        assert_almost_eq(i.phase().as_degrees(), -53.130_102_354_155_98);
+        let v2: Phasor<Voltage> = z * i;
+        assert_almost_eq(v2.real().as_volts(), 10.0);
+        assert_almost_eq(v2.imaginary().as_volts() + 1.0, 1.0);
+        let z2 = v / i;
+        assert_almost_eq(z2.resistance().as_ohms(), 3.0);
+        assert_almost_eq(z2.reactance().as_ohms(), 4.0);
+    }
+
+    #[test]
+    fn phasor_arithmetic() {
+        let a = Phasor::new(Voltage::from_volts(3.0), Voltage::from_volts(0.0));
+        let b = Phasor::from_polar(Voltage::from_volts(4.0), Angle::from_degrees(90.0));
+        let sum = a + b;
+        assert_almost_eq(sum.magnitude().as_volts(), 5.0);
+        assert_almost_eq((a - b).imaginary().as_volts(), -4.0);
+        assert_almost_eq((-a * 2.0).real().as_volts(), -6.0);
+        assert_almost_eq((a / 2.0).real().as_volts(), 1.5);
+    }
+
+    #[test]
+    fn display() {
+        let z = Impedance::from_ohms(1500.0, -2200.0);
+        assert_eq!(format!("{}", z), "1.5 - j2.2\u{00A0}k\u{2126}");
+        let v = Phasor::new(Voltage::from_volts(3.0), Voltage::from_volts(4.0));
+        assert_eq!(format!("{:.1}", v), "3.0 + j4.0\u{00A0}V");
+    }
+}
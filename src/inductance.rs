@@ -0,0 +1,222 @@
+//! Types and constants for handling electrical inductance.
+
+use super::e_series::ESeries;
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+
+/// The `Inductance` struct can be used to deal with electrical inductance in
+/// a common way.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Inductance, Resistance};
+/// use std::time::Duration;
+///
+/// let l = Inductance::from_millihenries(50.0);
+/// let tau: Duration = l / Resistance::from_ohms(100.0);
+/// println!("The L/R time constant is {:?}", tau);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Inductance {
+    henries: f64,
+}
+
+impl Inductance {
+    /// Create a new Inductance from a floating point value in henries
+    pub fn from_henries(henries: f64) -> Self {
+        Inductance { henries }
+    }
+
+    /// Create a new Inductance from a floating point value in millihenries
+    pub fn from_millihenries(millihenries: f64) -> Self {
+        Self::from_henries(millihenries * 1e-3)
+    }
+
+    /// Create a new Inductance from a floating point value in microhenries
+    pub fn from_microhenries(microhenries: f64) -> Self {
+        Self::from_henries(microhenries * 1e-6)
+    }
+
+    /// Create a new Inductance from a floating point value in nanohenries
+    pub fn from_nanohenries(nanohenries: f64) -> Self {
+        Self::from_henries(nanohenries * 1e-9)
+    }
+
+    /// Convert this Inductance into a floating point value in henries
+    pub fn as_henries(&self) -> f64 {
+        self.henries
+    }
+
+    /// Convert this Inductance into a floating point value in millihenries
+    pub fn as_millihenries(&self) -> f64 {
+        self.henries / 1e-3
+    }
+
+    /// Convert this Inductance into a floating point value in microhenries
+    pub fn as_microhenries(&self) -> f64 {
+        self.henries / 1e-6
+    }
+
+    /// Convert this Inductance into a floating point value in nanohenries
+    pub fn as_nanohenries(&self) -> f64 {
+        self.henries / 1e-9
+    }
+
+    /// Create a new Inductance from uncoupled inductors connected in series
+    pub fn series<I: IntoIterator<Item = Inductance>>(inductances: I) -> Self {
+        Self::from_henries(inductances.into_iter().fold(0.0, |sum, l| sum + l.henries))
+    }
+
+    /// Create a new Inductance from uncoupled inductors connected in
+    /// parallel. No inductors at all are an open circuit, with infinite
+    /// inductance.
+    pub fn parallel<I: IntoIterator<Item = Inductance>>(inductances: I) -> Self {
+        Self::from_henries(
+            1.0 / inductances
+                .into_iter()
+                .fold(0.0, |sum, l| sum + 1.0 / l.henries),
+        )
+    }
+
+    /// Round this Inductance to the nearest preferred value in an E-series,
+    /// also returning the relative error of that value.
+    pub fn to_e_series(&self, series: ESeries) -> (Inductance, f64) {
+        let preferred = series.nearest(self.henries);
+        (
+            Inductance::from_henries(preferred),
+            (preferred - self.henries) / self.henries,
+        )
+    }
+}
+
+impl Measurement for Inductance {
+    fn as_base_units(&self) -> f64 {
+        self.henries
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_henries(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "H"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("pH", 1e-12),
+            ("nH", 1e-9),
+            ("\u{00B5}H", 1e-6),
+            ("mH", 1e-3),
+            ("H", 1e0),
+            ("kH", 1e3),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { Inductance }
+
+implement_units! {
+    Inductance, InductanceUnit {
+        Nanohenry => ("nH", "nanohenry", "nanohenries", 1e-9) in Si,
+        Microhenry => ("\u{00B5}H", "microhenry", "microhenries", 1e-6) in Si,
+        Millihenry => ("mH", "millihenry", "millihenries", 1e-3) in Si,
+        Henry => ("H", "henry", "henries", 1.0) in Si,
+    }
+}
+
+/// The unit names understood when parsing an `Inductance`.
+const UNIT_NAMES: &[UnitConstructor<'static, Inductance>] = &[
+    ("pH", |v| Inductance::from_henries(v * 1e-12)),
+    ("nH", Inductance::from_nanohenries),
+    ("\u{00B5}H", Inductance::from_microhenries),
+    ("mH", Inductance::from_millihenries),
+    ("H", Inductance::from_henries),
+    ("kH", |v| Inductance::from_henries(v * 1e3)),
+    ("henry", Inductance::from_henries),
+    ("henries", Inductance::from_henries),
+    ("henrys", Inductance::from_henries),
+    ("millihenry", Inductance::from_millihenries),
+    ("millihenries", Inductance::from_millihenries),
+    ("microhenry", Inductance::from_microhenries),
+    ("microhenries", Inductance::from_microhenries),
+    ("nanohenry", Inductance::from_nanohenries),
+    ("nanohenries", Inductance::from_nanohenries),
+];
+
+impl UnitNames for Inductance {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Inductance {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use inductance::*;
+    use resistance::Resistance;
+    use test_utils::assert_almost_eq;
+    use time::Duration;
+
+    #[test]
+    fn as_henries() {
+        let l = Inductance::from_microhenries(330.0);
+        assert_almost_eq(l.as_henries(), 330e-6);
+        assert_almost_eq(l.as_millihenries(), 0.33);
+        assert_almost_eq(l.as_nanohenries(), 330_000.0);
+    }
+
+    #[test]
+    fn lr_time_constant() {
+        let l = Inductance::from_millihenries(50.0);
+        let tau: Duration = l / Resistance::from_ohms(100.0);
+        assert_almost_eq(tau.as_secs_f64(), 0.0005);
+        let l2: Inductance = Resistance::from_ohms(100.0) * tau;
+        assert_almost_eq(l2.as_millihenries(), 50.0);
+    }
+
+    #[test]
+    fn series_parallel() {
+        let l = [
+            Inductance::from_millihenries(2.0),
+            Inductance::from_millihenries(2.0),
+        ];
+        assert_almost_eq(Inductance::series(l.iter().cloned()).as_millihenries(), 4.0);
+        assert_almost_eq(
+            Inductance::parallel(l.iter().cloned()).as_millihenries(),
+            1.0,
+        );
+        let (l, _) = Inductance::from_microhenries(3.2).to_e_series(ESeries::E12);
+        assert_almost_eq(l.as_microhenries(), 3.3);
+    }
+
+    #[test]
+    fn display() {
+        let l = Inductance::from_microhenries(10.0);
+        assert_eq!(format!("{}", l), "10\u{00A0}\u{00B5}H");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: Inductance = "330 uH".parse().unwrap();
+        let b: Inductance = "2 henries".parse().unwrap();
+        assert_almost_eq(a.as_microhenries(), 330.0);
+        assert_almost_eq(b.as_henries(), 2.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Inductance::from_unit(4.7, InductanceUnit::Millihenry);
+        assert_almost_eq(a.as_unit(InductanceUnit::Microhenry), 4700.0);
+    }
+}
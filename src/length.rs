@@ -1,6 +1,8 @@
 //! Types and constants for handling lengths (or distances).
 
+use super::display::split_whole;
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 
 // Constants, metric
 
@@ -143,6 +145,11 @@ impl Length {
         Self::from_meters(feet / METER_FEET_FACTOR)
     }
 
+    /// Create a new Length from feet and inches, as in 5 ft 11 in.
+    pub fn from_feet_inches(feet: f64, inches: f64) -> Self {
+        Self::from_inches(feet * 12.0 + inches)
+    }
+
     /// Create a new Length from a floating point value in yards.
     pub fn from_yards(yards: f64) -> Self {
         Self::from_meters(yards / METER_YARD_FACTOR)
@@ -262,6 +269,12 @@ impl Length {
     pub fn as_miles(&self) -> f64 {
         self.meters * METER_MILE_FACTOR
     }
+
+    /// Convert this Length into whole feet and the inches left over. Both
+    /// have the sign of the length.
+    pub fn as_feet_inches(&self) -> (f64, f64) {
+        split_whole(self.as_inches(), 12.0)
+    }
 }
 
 impl Measurement for Length {
@@ -296,6 +309,104 @@ impl Measurement for Length {
 
 implement_measurement! { Length }
 
+implement_units! {
+    Length, LengthUnit {
+        Nanometer => ("nm", "nanometer", "nanometers", 1.0 / METER_NANOMETER_FACTOR) in Si,
+        Micrometer => ("\u{00B5}m", "micrometer", "micrometers", 1.0 / METER_MICROMETER_FACTOR) in Si,
+        Millimeter => ("mm", "millimeter", "millimeters", 1.0 / METER_MILLIMETER_FACTOR) in Si,
+        Centimeter => ("cm", "centimeter", "centimeters", 1.0 / METER_CENTIMETER_FACTOR) in Si,
+        Decimeter => ("dm", "decimeter", "decimeters", 1.0 / METER_DECIMETER_FACTOR),
+        Meter => ("m", "meter", "meters", 1.0) in Si,
+        Hectometer => ("hm", "hectometer", "hectometers", 1.0 / METER_HECTOMETER_FACTOR),
+        Kilometer => ("km", "kilometer", "kilometers", 1.0 / METER_KILOMETER_FACTOR) in Si,
+        Inch => ("in", "inch", "inches", 1.0 / METER_INCH_FACTOR) in UsCustomary | Imperial,
+        Foot => ("ft", "foot", "feet", 1.0 / METER_FEET_FACTOR) in UsCustomary | Imperial,
+        Yard => ("yd", "yard", "yards", 1.0 / METER_YARD_FACTOR) in UsCustomary | Imperial,
+        Furlong => ("fur", "furlong", "furlongs", 1.0 / METER_FURLONG_FACTOR),
+        Mile => ("mi", "mile", "miles", 1.0 / METER_MILE_FACTOR) in UsCustomary | Imperial,
+    }
+}
+
+/// The unit names understood when parsing a `Length`.
+const UNIT_NAMES: &[UnitConstructor<'static, Length>] = &[
+    ("pm", |v| Length::from_meters(v * 1e-12)),
+    ("nm", Length::from_nanometers),
+    ("\u{00B5}m", Length::from_micrometers),
+    ("mm", Length::from_millimeters),
+    ("cm", Length::from_centimeters),
+    ("dm", Length::from_decimeters),
+    ("m", Length::from_meters),
+    ("hm", Length::from_hectometers),
+    ("km", Length::from_kilometers),
+    ("thousand km", |v| Length::from_kilometers(v * 1e3)),
+    ("million km", |v| Length::from_kilometers(v * 1e6)),
+    ("in", Length::from_inches),
+    ("\"", Length::from_inches),
+    ("\u{2033}", Length::from_inches),
+    ("ft", Length::from_feet),
+    ("'", Length::from_feet),
+    ("\u{2032}", Length::from_feet),
+    ("yd", Length::from_yards),
+    ("fur", Length::from_furlongs),
+    ("mi", Length::from_miles),
+    ("nanometer", Length::from_nanometers),
+    ("nanometers", Length::from_nanometers),
+    ("nanometre", Length::from_nanometers),
+    ("nanometres", Length::from_nanometers),
+    ("micrometer", Length::from_micrometers),
+    ("micrometers", Length::from_micrometers),
+    ("micrometre", Length::from_micrometers),
+    ("micrometres", Length::from_micrometers),
+    ("millimeter", Length::from_millimeters),
+    ("millimeters", Length::from_millimeters),
+    ("millimetre", Length::from_millimeters),
+    ("millimetres", Length::from_millimeters),
+    ("centimeter", Length::from_centimeters),
+    ("centimeters", Length::from_centimeters),
+    ("centimetre", Length::from_centimeters),
+    ("centimetres", Length::from_centimeters),
+    ("decimeter", Length::from_decimeters),
+    ("decimeters", Length::from_decimeters),
+    ("decimetre", Length::from_decimeters),
+    ("decimetres", Length::from_decimeters),
+    ("meter", Length::from_meters),
+    ("meters", Length::from_meters),
+    ("metre", Length::from_meters),
+    ("metres", Length::from_meters),
+    ("hectometer", Length::from_hectometers),
+    ("hectometers", Length::from_hectometers),
+    ("hectometre", Length::from_hectometers),
+    ("hectometres", Length::from_hectometers),
+    ("kilometer", Length::from_kilometers),
+    ("kilometers", Length::from_kilometers),
+    ("kilometre", Length::from_kilometers),
+    ("kilometres", Length::from_kilometers),
+    ("inch", Length::from_inches),
+    ("inches", Length::from_inches),
+    ("foot", Length::from_feet),
+    ("feet", Length::from_feet),
+    ("yard", Length::from_yards),
+    ("yards", Length::from_yards),
+    ("furlong", Length::from_furlongs),
+    ("furlongs", Length::from_furlongs),
+    ("mile", Length::from_miles),
+    ("miles", Length::from_miles),
+];
+
+impl UnitNames for Length {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Length {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use length::*;
@@ -509,4 +620,72 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Length = "12.5 km".parse().unwrap();
+        let b: Length = "3'6\"".parse().unwrap();
+        let c: Length = "2 metres".parse().unwrap();
+        assert_almost_eq(a.as_meters(), 12500.0);
+        assert_almost_eq(b.as_feet(), 3.5);
+        assert_almost_eq(c.as_meters(), 2.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Length::from_unit(3.0, LengthUnit::Foot);
+        assert_almost_eq(a.as_feet(), 3.0);
+        assert_almost_eq(a.as_unit(LengthUnit::Inch), 36.0);
+    }
+
+    #[test]
+    fn feet_inches() {
+        let a = Length::from_feet_inches(5.0, 11.0);
+        assert_almost_eq(a.as_inches(), 71.0);
+        let (feet, inches) = a.as_feet_inches();
+        assert_eq!(feet, 5.0);
+        assert_almost_eq(inches, 11.0);
+
+        let (feet, inches) = Length::from_feet(6.0).as_feet_inches();
+        assert_eq!((feet, inches), (6.0, 0.0));
+
+        let (feet, inches) = Length::from_inches(-40.0).as_feet_inches();
+        assert_eq!(feet, -3.0);
+        assert_almost_eq(inches, -4.0);
+    }
+
+    #[test]
+    fn compound_display() {
+        const FEET_INCHES: &[LengthUnit] = &[LengthUnit::Foot, LengthUnit::Inch];
+        let a = Length::from_feet_inches(5.0, 11.0);
+        assert_eq!(a.display().compound(FEET_INCHES).ascii_space().to_string(), "5 ft 11 in");
+        assert_eq!(
+            a.display().compound(FEET_INCHES).long_names().ascii_space().to_string(),
+            "5 feet 11 inches"
+        );
+
+        let b = Length::from_inches(70.97);
+        let b = b.display().compound(FEET_INCHES).fraction(16).ascii_space();
+        assert_eq!(b.to_string(), "5 ft 11 in");
+        let c = Length::from_millimeters(1800.0).display().compound(FEET_INCHES).fraction(16);
+        assert_eq!(c.ascii_space().to_string(), "5 ft 10 7/8 in");
+        let d = Length::from_inches(71.999).display().compound(FEET_INCHES).precision(1);
+        assert_eq!(d.ascii_space().to_string(), "6 ft");
+        let e = Length::from_inches(-0.5).display().compound(FEET_INCHES).fraction(4);
+        assert_eq!(e.ascii_space().to_string(), "-1/2 in");
+        let f = Length::from_inches(0.0).display().compound(FEET_INCHES);
+        assert_eq!(f.ascii_space().to_string(), "0 in");
+        let g = Length::from_inches(1.0).display().unit(LengthUnit::Inch).fraction(8);
+        assert_eq!(g.long_names().ascii_space().to_string(), "1 inch");
+    }
+
+    #[test]
+    fn compound_round_trip() {
+        const FEET_INCHES: &[LengthUnit] = &[LengthUnit::Foot, LengthUnit::Inch];
+        let a = Length::from_feet_inches(5.0, 10.0 + 7.0 / 8.0);
+        let text = a.display().compound(FEET_INCHES).fraction(16).to_string();
+        let b: Length = text.parse().unwrap();
+        assert_almost_eq(b.as_inches(), a.as_inches());
+        let c: Length = "5' 10 7/8\"".parse().unwrap();
+        assert_almost_eq(c.as_inches(), 70.875);
+    }
 }
@@ -20,72 +20,165 @@ use std::time as time;
 
 use std::f64::consts::PI as PI;
 
+/// The ratio between line and phase quantities in a balanced three-phase
+/// system.
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+#[cfg(feature = "serde")]
+extern crate serde;
+
 #[macro_use]
 mod measurement;
 pub use measurement::Measurement;
 
+pub mod parse;
+pub use parse::ParseMeasurementError;
+
+#[macro_use]
+pub mod unit;
+pub use unit::{Unit, UnitSystem};
+
+pub mod display;
+pub use display::QuantityDisplay;
+
+pub mod engineering;
+pub use engineering::{Engineering, EngineeringDisplay};
+
+pub mod locale;
+pub use locale::Locale;
+
+pub mod duration;
+pub use duration::DurationUnit;
+
 pub mod length;
-pub use length::{Distance, Length};
+pub use length::{Distance, Length, LengthUnit};
 
 pub mod temperature;
-pub use temperature::{Temperature, TemperatureDelta};
+pub use temperature::{Temperature, TemperatureDelta, TemperatureUnit};
 
 pub mod mass;
-pub use mass::Mass;
+pub use mass::{Mass, MassUnit};
 
 pub mod volume;
-pub use volume::Volume;
+pub use volume::{Volume, VolumeUnit};
 
 pub mod pressure;
-pub use pressure::Pressure;
+pub use pressure::{Pressure, PressureUnit};
 
 pub mod speed;
-pub use speed::Speed;
+pub use speed::{Speed, SpeedUnit};
 
 pub mod acceleration;
-pub use acceleration::Acceleration;
+pub use acceleration::{Acceleration, AccelerationUnit};
 
 pub mod energy;
-pub use energy::Energy;
+pub use energy::{Energy, EnergyUnit};
 
 pub mod power;
-pub use power::Power;
+pub use power::{Power, PowerUnit};
 
 pub mod voltage;
-pub use voltage::Voltage;
+pub use voltage::{Voltage, VoltageUnit};
 
 pub mod current;
-pub use current::Current;
+pub use current::{Current, CurrentUnit};
 
 pub mod resistance;
-pub use resistance::Resistance;
+pub use resistance::{Resistance, ResistanceUnit};
 
 pub mod force;
-pub use force::Force;
+pub use force::{Force, ForceUnit};
 
 pub mod area;
-pub use area::Area;
+pub use area::{Area, AreaUnit};
 
 pub mod angle;
-pub use angle::Angle;
+pub use angle::{Angle, AngleUnit};
 
 pub mod frequency;
-pub use frequency::Frequency;
+pub use frequency::{Frequency, FrequencyUnit};
 
 pub mod angular_velocity;
-pub use angular_velocity::AngularVelocity;
+pub use angular_velocity::{AngularVelocity, AngularVelocityUnit};
 
 pub mod torque;
-pub use torque::Torque;
+pub use torque::{Torque, TorqueUnit};
 
 pub mod data;
-pub use data::Data;
+pub use data::{Data, DataDisplayMode, DataUnit};
+
+pub mod data_size;
+pub use data_size::{DataSize, DataSizeError};
+
+pub mod data_rate;
+pub use data_rate::{DataRate, DataRateUnit};
+
+pub mod density;
+pub use density::{Density, DensityUnit};
+
+pub mod volumetric_flow_rate;
+pub use volumetric_flow_rate::{VolumetricFlowRate, VolumetricFlowRateUnit};
+
+pub mod mass_flow_rate;
+pub use mass_flow_rate::{MassFlowRate, MassFlowRateUnit};
+
+pub mod electric_charge;
+pub use electric_charge::{ElectricCharge, ElectricChargeUnit};
+
+pub mod capacitance;
+pub use capacitance::{Capacitance, CapacitanceUnit};
+
+pub mod inductance;
+pub use inductance::{Inductance, InductanceUnit};
+
+pub mod conductance;
+pub use conductance::{Conductance, ConductanceUnit};
+
+pub mod apparent_power;
+pub use apparent_power::{ApparentPower, ApparentPowerUnit};
+
+pub mod reactive_power;
+pub use reactive_power::{ReactivePower, ReactivePowerUnit};
+
+pub mod e_series;
+pub use e_series::ESeries;
+
+pub mod marking;
+pub use marking::ResistorMarking;
+
+pub mod impedance;
+pub use impedance::{Impedance, Phasor};
+
+#[cfg(not(feature = "no_std"))]
+pub mod decibel;
+#[cfg(not(feature = "no_std"))]
+pub use decibel::{Decibel, DecibelReference};
+
+pub mod expression;
+
+pub mod dimension;
+pub mod quantity;
+pub use quantity::{Dimensioned, Quantity};
+
+pub mod dyn_quantity;
+pub use dyn_quantity::{DimensionError, DynDimension, DynQuantity};
+
+#[cfg(not(feature = "no_std"))]
+pub mod uncertain;
+#[cfg(not(feature = "no_std"))]
+pub use uncertain::Uncertain;
+
+pub mod tolerance;
+pub use tolerance::TolerancedLength;
 
 mod torque_energy;
 pub use torque_energy::TorqueEnergy;
 
 pub mod prelude;
 
+#[cfg(feature = "serde")]
+mod serde_impls;
+
 pub mod test_utils;
 
 /// For given types A, B and C, implement, using base units:
@@ -174,6 +267,17 @@ impl_maths!(Volume, Length, Area);
 impl_maths!(Power, AngularVelocity, Torque);
 impl_maths!(Power, Voltage, Current);
 impl_maths!(Voltage, Resistance, Current);
+impl_maths!(Mass, Volume, Density);
+impl_maths!(Volume, time::Duration, VolumetricFlowRate);
+impl_maths!(Mass, time::Duration, MassFlowRate);
+impl_maths!(MassFlowRate, VolumetricFlowRate, Density);
+impl_maths!(ElectricCharge, time::Duration, Current);
+impl_maths!(Energy, ElectricCharge, Voltage);
+impl_maths!(time::Duration, Resistance, Capacitance);
+impl_maths!(Inductance, time::Duration, Resistance);
+impl_maths!(Current, Voltage, Conductance);
+impl_maths!(ElectricCharge, Capacitance, Voltage);
+impl_maths!(Data, time::Duration, DataRate);
 
 // Force * Distance is ambiguous. Create an ambiguous struct the user can then
 // cast into either Torque or Energy.
@@ -215,3 +319,38 @@ impl std::ops::Div<Force> for Energy {
     }
 }
 
+// A VolumetricFlowRate is stored in litres per second, like Volume, but Area
+// and Speed give cubic metres per second, so these relations are scaled by
+// hand.
+
+impl std::ops::Mul<Speed> for Area {
+    type Output = VolumetricFlowRate;
+
+    fn mul(self, rhs: Speed) -> Self::Output {
+        Self::Output::from_cubic_meters_per_second(self.as_base_units() * rhs.as_base_units())
+    }
+}
+
+impl std::ops::Mul<Area> for Speed {
+    type Output = VolumetricFlowRate;
+
+    fn mul(self, rhs: Area) -> Self::Output {
+        Self::Output::from_cubic_meters_per_second(self.as_base_units() * rhs.as_base_units())
+    }
+}
+
+impl std::ops::Div<Area> for VolumetricFlowRate {
+    type Output = Speed;
+
+    fn div(self, rhs: Area) -> Self::Output {
+        Self::Output::from_base_units(self.as_cubic_meters_per_second() / rhs.as_base_units())
+    }
+}
+
+impl std::ops::Div<Speed> for VolumetricFlowRate {
+    type Output = Area;
+
+    fn div(self, rhs: Speed) -> Self::Output {
+        Self::Output::from_base_units(self.as_cubic_meters_per_second() / rhs.as_base_units())
+    }
+}
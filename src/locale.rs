@@ -0,0 +1,1516 @@
+//! Unit names, symbols and decimal separators for other languages.
+//!
+//! A `Locale` translates the names of units and chooses the decimal
+//! separator used by `QuantityDisplay::locale` and `Engineering::locale`.
+//! English is built in with American and British spellings ("meter" and
+//! "metre"), along with German, French and Japanese. Units a locale does not
+//! translate are looked up in its fallback locale, and then given their
+//! English names.
+//!
+//! Translations are keyed by the English singular name of each unit, as
+//! given by `Unit::singular`, so one table covers every kind of quantity.
+//! Applications can describe further locales as `static`s and `register`
+//! them, so that `find` returns them by language tag.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::{locale, Length, LengthUnit};
+//!
+//! let length = Length::from_meters(2.5);
+//! let text = length.display().locale(&locale::DE).long_names().to_string();
+//! assert_eq!(text, "2,5\u{00A0}Meter");
+//! assert_eq!(locale::EN_GB.plural(LengthUnit::Meter).to_string(), "metres");
+//! assert_eq!(locale::find("fr-CA").unwrap().tag, "fr");
+//! ```
+
+use super::display::absolute;
+use super::unit::Unit;
+use std::fmt;
+#[cfg(not(feature = "no_std"))]
+use std::sync::RwLock;
+
+/// How English unit names are spelled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Spelling {
+    /// "meter" and "liter"
+    American,
+    /// "metre" and "litre"
+    British,
+}
+
+/// Which values take the singular name of a unit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PluralRule {
+    /// Exactly one, as in English and German.
+    One,
+    /// Any value below two, as in French "1,5 mètre".
+    BelowTwo,
+    /// Every value, for languages such as Japanese whose nouns have no
+    /// plural.
+    Invariant,
+}
+
+/// The names of one unit in a locale.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnitTranslation {
+    /// The English singular name of the unit, as given by `Unit::singular`
+    pub unit: &'static str,
+    /// The name of one of this unit
+    pub singular: &'static str,
+    /// The name of several of this unit
+    pub plural: &'static str,
+    /// The symbol, where the locale does not use the usual one
+    pub symbol: Option<&'static str>,
+}
+
+impl UnitTranslation {
+    /// Translate the names of a unit, keeping its usual symbol.
+    pub const fn new(unit: &'static str, singular: &'static str, plural: &'static str) -> Self {
+        UnitTranslation {
+            unit,
+            singular,
+            plural,
+            symbol: None,
+        }
+    }
+
+    /// Use this symbol in place of the unit's usual one.
+    pub const fn with_symbol(self, symbol: &'static str) -> Self {
+        UnitTranslation {
+            symbol: Some(symbol),
+            ..self
+        }
+    }
+}
+
+/// Unit names, symbols and number formatting for a language.
+#[derive(Copy, Clone, Debug)]
+pub struct Locale {
+    /// The language tag, for example "de" or "en-GB"
+    pub tag: &'static str,
+    /// The character between the whole and fractional parts of a number
+    pub decimal_separator: char,
+    /// Which values take the singular name of a unit
+    pub plural_rule: PluralRule,
+    /// How the English names of units without a translation are spelled
+    pub spelling: Spelling,
+    /// The units this locale translates
+    pub units: &'static [UnitTranslation],
+    /// A locale to look in for units not translated here
+    pub fallback: Option<&'static Locale>,
+}
+
+impl Locale {
+    /// The translation of a unit, from this locale or its fallbacks.
+    pub fn translation<U: Unit>(&self, unit: U) -> Option<&'static UnitTranslation> {
+        let key = unit.singular();
+        let mut locale = Some(self);
+        while let Some(current) = locale {
+            if let Some(translation) = current.units.iter().find(|t| t.unit == key) {
+                return Some(translation);
+            }
+            locale = current.fallback;
+        }
+        None
+    }
+
+    /// The symbol for a unit, for example "ko" for a kilooctet in French.
+    pub fn symbol<U: Unit>(&self, unit: U) -> &'static str {
+        self.translation(unit)
+            .and_then(|t| t.symbol)
+            .unwrap_or_else(|| unit.symbol())
+    }
+
+    /// The name of one of a unit.
+    pub fn singular<U: Unit>(&self, unit: U) -> UnitName {
+        match self.translation(unit) {
+            Some(translation) => UnitName::as_written(translation.singular),
+            None => UnitName::english(unit.singular(), self.spelling),
+        }
+    }
+
+    /// The name of several of a unit.
+    pub fn plural<U: Unit>(&self, unit: U) -> UnitName {
+        match self.translation(unit) {
+            Some(translation) => UnitName::as_written(translation.plural),
+            None => UnitName::english(unit.plural(), self.spelling),
+        }
+    }
+
+    /// The name of a unit to follow `value`, singular or plural according
+    /// to the locale's plural rule.
+    pub fn name<U: Unit>(&self, unit: U, value: f64) -> UnitName {
+        if self.is_singular(value) {
+            self.singular(unit)
+        } else {
+            self.plural(unit)
+        }
+    }
+
+    /// Whether `value` takes the singular name of a unit.
+    pub fn is_singular(&self, value: f64) -> bool {
+        match self.plural_rule {
+            PluralRule::One => value == 1.0 || value == -1.0,
+            PluralRule::BelowTwo => absolute(value) < 2.0,
+            PluralRule::Invariant => true,
+        }
+    }
+}
+
+/// The name of a unit in a locale. English names are printed in the
+/// locale's spelling.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnitName {
+    text: &'static str,
+    spelling: Option<Spelling>,
+}
+
+impl UnitName {
+    /// A name printed exactly as given.
+    pub(crate) fn as_written(text: &'static str) -> Self {
+        UnitName {
+            text,
+            spelling: None,
+        }
+    }
+
+    /// An English name, respelled as it is printed.
+    fn english(text: &'static str, spelling: Spelling) -> Self {
+        UnitName {
+            text,
+            spelling: Some(spelling),
+        }
+    }
+}
+
+impl fmt::Display for UnitName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (from, to) = match self.spelling {
+            None => return f.write_str(self.text),
+            Some(Spelling::American) => (["metre", "litre"], ["meter", "liter"]),
+            Some(Spelling::British) => (["meter", "liter"], ["metre", "litre"]),
+        };
+        let mut rest = self.text;
+        loop {
+            let next = (0..from.len())
+                .filter_map(|i| rest.find(from[i]).map(|start| (start, i)))
+                .min();
+            let (start, i) = match next {
+                Some(next) => next,
+                None => return f.write_str(rest),
+            };
+            f.write_str(&rest[..start])?;
+            f.write_str(to[i])?;
+            rest = &rest[start + from[i].len()..];
+        }
+    }
+}
+
+/// English as spelled in the United States. This is how unit names are
+/// written throughout the crate.
+pub const EN_US: Locale = Locale {
+    tag: "en-US",
+    decimal_separator: '.',
+    plural_rule: PluralRule::One,
+    spelling: Spelling::American,
+    units: &[],
+    fallback: None,
+};
+
+/// English as spelled in the United Kingdom.
+pub const EN_GB: Locale = Locale {
+    tag: "en-GB",
+    decimal_separator: '.',
+    plural_rule: PluralRule::One,
+    spelling: Spelling::British,
+    units: &[],
+    fallback: None,
+};
+
+/// German.
+pub const DE: Locale = Locale {
+    tag: "de",
+    decimal_separator: ',',
+    plural_rule: PluralRule::One,
+    spelling: Spelling::British,
+    units: GERMAN,
+    fallback: None,
+};
+
+/// French.
+pub const FR: Locale = Locale {
+    tag: "fr",
+    decimal_separator: ',',
+    plural_rule: PluralRule::BelowTwo,
+    spelling: Spelling::British,
+    units: FRENCH,
+    fallback: None,
+};
+
+/// Japanese.
+pub const JA: Locale = Locale {
+    tag: "ja",
+    decimal_separator: '.',
+    plural_rule: PluralRule::Invariant,
+    spelling: Spelling::British,
+    units: JAPANESE,
+    fallback: None,
+};
+
+/// The locales `find` knows without any being registered.
+const BUILT_IN: &[&Locale] = &[&EN_US, &EN_GB, &DE, &FR, &JA];
+
+/// Locales added by `register`, most recent last.
+#[cfg(not(feature = "no_std"))]
+static REGISTERED: RwLock<Vec<&'static Locale>> = RwLock::new(Vec::new());
+
+/// Make a locale available to `find`, replacing any registered earlier with
+/// the same tag. Registered locales are found before the built-in ones, so
+/// this can also replace those.
+#[cfg(not(feature = "no_std"))]
+pub fn register(locale: &'static Locale) {
+    let mut registered = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+    registered.retain(|l| !same_tag(l.tag, locale.tag));
+    registered.push(locale);
+}
+
+/// Find a locale by its language tag, such as "de" or "en_GB", ignoring
+/// case. A tag with no locale of its own falls back to another locale for
+/// the same language, so "de-AT" finds German.
+pub fn find(tag: &str) -> Option<&'static Locale> {
+    let language = language(tag);
+    search(|l| same_tag(l.tag, tag))
+        .or_else(|| search(|l| same_tag(l.tag, language)))
+        .or_else(|| search(|l| same_tag(self::language(l.tag), language)))
+}
+
+/// The first registered, then built-in, locale to match.
+fn search<F: Fn(&Locale) -> bool>(matches: F) -> Option<&'static Locale> {
+    #[cfg(not(feature = "no_std"))]
+    {
+        let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+        if let Some(&locale) = registered.iter().rev().find(|l| matches(l)) {
+            return Some(locale);
+        }
+    }
+    BUILT_IN.iter().cloned().find(|l| matches(l))
+}
+
+/// The language part of a tag, for example "en" for "en-GB".
+fn language(tag: &str) -> &str {
+    tag.split(['-', '_']).next().unwrap_or(tag)
+}
+
+/// Whether two tags are the same, ignoring case and treating "_" as "-".
+fn same_tag(a: &str, b: &str) -> bool {
+    let normalize = |c: u8| match c {
+        b'_' => b'-',
+        c => c.to_ascii_lowercase(),
+    };
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .all(|(a, b)| normalize(a) == normalize(b))
+}
+
+const GERMAN: &[UnitTranslation] = &[
+    UnitTranslation::new("nanometer", "Nanometer", "Nanometer"),
+    UnitTranslation::new("micrometer", "Mikrometer", "Mikrometer"),
+    UnitTranslation::new("millimeter", "Millimeter", "Millimeter"),
+    UnitTranslation::new("centimeter", "Zentimeter", "Zentimeter"),
+    UnitTranslation::new("decimeter", "Dezimeter", "Dezimeter"),
+    UnitTranslation::new("meter", "Meter", "Meter"),
+    UnitTranslation::new("hectometer", "Hektometer", "Hektometer"),
+    UnitTranslation::new("kilometer", "Kilometer", "Kilometer"),
+    UnitTranslation::new("inch", "Zoll", "Zoll"),
+    UnitTranslation::new("foot", "Fuß", "Fuß"),
+    UnitTranslation::new("yard", "Yard", "Yards"),
+    UnitTranslation::new("furlong", "Furlong", "Furlongs"),
+    UnitTranslation::new("mile", "Meile", "Meilen"),
+    UnitTranslation::new("square nanometer", "Quadratnanometer", "Quadratnanometer"),
+    UnitTranslation::new(
+        "square micrometer",
+        "Quadratmikrometer",
+        "Quadratmikrometer",
+    ),
+    UnitTranslation::new(
+        "square millimeter",
+        "Quadratmillimeter",
+        "Quadratmillimeter",
+    ),
+    UnitTranslation::new(
+        "square centimeter",
+        "Quadratzentimeter",
+        "Quadratzentimeter",
+    ),
+    UnitTranslation::new("square decimeter", "Quadratdezimeter", "Quadratdezimeter"),
+    UnitTranslation::new("square meter", "Quadratmeter", "Quadratmeter"),
+    UnitTranslation::new(
+        "square hectometer",
+        "Quadrathektometer",
+        "Quadrathektometer",
+    ),
+    UnitTranslation::new("hectare", "Hektar", "Hektar"),
+    UnitTranslation::new("square kilometer", "Quadratkilometer", "Quadratkilometer"),
+    UnitTranslation::new("square inch", "Quadratzoll", "Quadratzoll"),
+    UnitTranslation::new("square foot", "Quadratfuß", "Quadratfuß"),
+    UnitTranslation::new("square yard", "Quadratyard", "Quadratyards"),
+    UnitTranslation::new("acre", "Acre", "Acres"),
+    UnitTranslation::new("square mile", "Quadratmeile", "Quadratmeilen"),
+    UnitTranslation::new("milliliter", "Milliliter", "Milliliter"),
+    UnitTranslation::new("cubic centimeter", "Kubikzentimeter", "Kubikzentimeter"),
+    UnitTranslation::new("liter", "Liter", "Liter"),
+    UnitTranslation::new("cubic meter", "Kubikmeter", "Kubikmeter"),
+    UnitTranslation::new("drop", "Tropfen", "Tropfen"),
+    UnitTranslation::new("dram", "Fluiddrachme", "Fluiddrachmen"),
+    UnitTranslation::new("teaspoon", "Teelöffel", "Teelöffel"),
+    UnitTranslation::new("tablespoon", "Esslöffel", "Esslöffel"),
+    UnitTranslation::new("cubic inch", "Kubikzoll", "Kubikzoll"),
+    UnitTranslation::new(
+        "UK fluid ounce",
+        "britische Flüssigunze",
+        "britische Flüssigunzen",
+    ),
+    UnitTranslation::new("fluid ounce", "Flüssigunze", "Flüssigunzen"),
+    UnitTranslation::new("cup", "Tasse", "Tassen"),
+    UnitTranslation::new("pint", "Pint", "Pints"),
+    UnitTranslation::new("UK pint", "britisches Pint", "britische Pints"),
+    UnitTranslation::new("quart", "Quart", "Quarts"),
+    UnitTranslation::new("gallon", "Gallone", "Gallonen"),
+    UnitTranslation::new("UK gallon", "britische Gallone", "britische Gallonen"),
+    UnitTranslation::new("cubic foot", "Kubikfuß", "Kubikfuß"),
+    UnitTranslation::new("cubic yard", "Kubikyard", "Kubikyards"),
+    UnitTranslation::new("nanogram", "Nanogramm", "Nanogramm"),
+    UnitTranslation::new("microgram", "Mikrogramm", "Mikrogramm"),
+    UnitTranslation::new("milligram", "Milligramm", "Milligramm"),
+    UnitTranslation::new("gram", "Gramm", "Gramm"),
+    UnitTranslation::new("kilogram", "Kilogramm", "Kilogramm"),
+    UnitTranslation::new("tonne", "Tonne", "Tonnen"),
+    UnitTranslation::new("carat", "Karat", "Karat"),
+    UnitTranslation::new("grain", "Grain", "Grain"),
+    UnitTranslation::new("pennyweight", "Pennyweight", "Pennyweight"),
+    UnitTranslation::new("ounce", "Unze", "Unzen"),
+    UnitTranslation::new("troy ounce", "Feinunze", "Feinunzen"),
+    UnitTranslation::new("pound", "Pfund", "Pfund"),
+    UnitTranslation::new("troy pound", "Troy-Pfund", "Troy-Pfund"),
+    UnitTranslation::new("stone", "Stone", "Stone"),
+    UnitTranslation::new("short ton", "amerikanische Tonne", "amerikanische Tonnen"),
+    UnitTranslation::new("long ton", "britische Tonne", "britische Tonnen"),
+    UnitTranslation::new("nanosecond", "Nanosekunde", "Nanosekunden"),
+    UnitTranslation::new("microsecond", "Mikrosekunde", "Mikrosekunden"),
+    UnitTranslation::new("millisecond", "Millisekunde", "Millisekunden"),
+    UnitTranslation::new("second", "Sekunde", "Sekunden"),
+    UnitTranslation::new("minute", "Minute", "Minuten"),
+    UnitTranslation::new("hour", "Stunde", "Stunden"),
+    UnitTranslation::new("day", "Tag", "Tage"),
+    UnitTranslation::new("kelvin", "Kelvin", "Kelvin"),
+    UnitTranslation::new("degree Celsius", "Grad Celsius", "Grad Celsius"),
+    UnitTranslation::new("degree Fahrenheit", "Grad Fahrenheit", "Grad Fahrenheit"),
+    UnitTranslation::new("degree Rankine", "Grad Rankine", "Grad Rankine"),
+    UnitTranslation::new("radian", "Radiant", "Radiant"),
+    UnitTranslation::new("degree", "Grad", "Grad"),
+    UnitTranslation::new(
+        "radian per second",
+        "Radiant pro Sekunde",
+        "Radiant pro Sekunde",
+    ),
+    UnitTranslation::new(
+        "revolution per minute",
+        "Umdrehung pro Minute",
+        "Umdrehungen pro Minute",
+    ),
+    UnitTranslation::new(
+        "revolution per second",
+        "Umdrehung pro Sekunde",
+        "Umdrehungen pro Sekunde",
+    ),
+    UnitTranslation::new("meter per second", "Meter pro Sekunde", "Meter pro Sekunde"),
+    UnitTranslation::new(
+        "kilometer per hour",
+        "Kilometer pro Stunde",
+        "Kilometer pro Stunde",
+    ),
+    UnitTranslation::new("mile per hour", "Meile pro Stunde", "Meilen pro Stunde"),
+    UnitTranslation::new(
+        "meter per second squared",
+        "Meter pro Quadratsekunde",
+        "Meter pro Quadratsekunde",
+    ),
+    UnitTranslation::new(
+        "foot per second squared",
+        "Fuß pro Quadratsekunde",
+        "Fuß pro Quadratsekunde",
+    ),
+    UnitTranslation::new("pascal", "Pascal", "Pascal"),
+    UnitTranslation::new("hectopascal", "Hektopascal", "Hektopascal"),
+    UnitTranslation::new("millibar", "Millibar", "Millibar"),
+    UnitTranslation::new("kilopascal", "Kilopascal", "Kilopascal"),
+    UnitTranslation::new(
+        "pound per square inch",
+        "Pfund pro Quadratzoll",
+        "Pfund pro Quadratzoll",
+    ),
+    UnitTranslation::new("bar", "Bar", "Bar"),
+    UnitTranslation::new("atmosphere", "Atmosphäre", "Atmosphären"),
+    UnitTranslation::new("joule", "Joule", "Joule"),
+    UnitTranslation::new("kilocalorie", "Kilokalorie", "Kilokalorien"),
+    UnitTranslation::new(
+        "British thermal unit",
+        "British Thermal Unit",
+        "British Thermal Units",
+    ),
+    UnitTranslation::new("electronvolt", "Elektronenvolt", "Elektronenvolt"),
+    UnitTranslation::new("watt-hour", "Wattstunde", "Wattstunden"),
+    UnitTranslation::new("kilowatt-hour", "Kilowattstunde", "Kilowattstunden"),
+    UnitTranslation::new("microwatt", "Mikrowatt", "Mikrowatt"),
+    UnitTranslation::new("milliwatt", "Milliwatt", "Milliwatt"),
+    UnitTranslation::new("watt", "Watt", "Watt"),
+    UnitTranslation::new("kilowatt", "Kilowatt", "Kilowatt"),
+    UnitTranslation::new(
+        "horsepower",
+        "britische Pferdestärke",
+        "britische Pferdestärken",
+    ),
+    UnitTranslation::new("metric horsepower", "Pferdestärke", "Pferdestärken"),
+    UnitTranslation::new("BTU per minute", "BTU pro Minute", "BTU pro Minute"),
+    UnitTranslation::new("micronewton", "Mikronewton", "Mikronewton"),
+    UnitTranslation::new("millinewton", "Millinewton", "Millinewton"),
+    UnitTranslation::new("newton", "Newton", "Newton"),
+    UnitTranslation::new("pound-force", "Pfundkraft", "Pfundkraft"),
+    UnitTranslation::new("poundal", "Poundal", "Poundal"),
+    UnitTranslation::new("kilopond", "Kilopond", "Kilopond"),
+    UnitTranslation::new("dyne", "Dyn", "Dyn"),
+    UnitTranslation::new("newton metre", "Newtonmeter", "Newtonmeter"),
+    UnitTranslation::new("pound-foot", "Pfund-Fuß", "Pfund-Fuß"),
+    UnitTranslation::new("microvolt", "Mikrovolt", "Mikrovolt"),
+    UnitTranslation::new("millivolt", "Millivolt", "Millivolt"),
+    UnitTranslation::new("volt", "Volt", "Volt"),
+    UnitTranslation::new("kilovolt", "Kilovolt", "Kilovolt"),
+    UnitTranslation::new("nanoampere", "Nanoampere", "Nanoampere"),
+    UnitTranslation::new("microampere", "Mikroampere", "Mikroampere"),
+    UnitTranslation::new("milliampere", "Milliampere", "Milliampere"),
+    UnitTranslation::new("ampere", "Ampere", "Ampere"),
+    UnitTranslation::new("ohm", "Ohm", "Ohm"),
+    UnitTranslation::new("kiloohm", "Kiloohm", "Kiloohm"),
+    UnitTranslation::new("megaohm", "Megaohm", "Megaohm"),
+    UnitTranslation::new("microcoulomb", "Mikrocoulomb", "Mikrocoulomb"),
+    UnitTranslation::new("millicoulomb", "Millicoulomb", "Millicoulomb"),
+    UnitTranslation::new("coulomb", "Coulomb", "Coulomb"),
+    UnitTranslation::new(
+        "milliampere-hour",
+        "Milliamperestunde",
+        "Milliamperestunden",
+    ),
+    UnitTranslation::new("ampere-hour", "Amperestunde", "Amperestunden"),
+    UnitTranslation::new("nanohertz", "Nanohertz", "Nanohertz"),
+    UnitTranslation::new("microhertz", "Mikrohertz", "Mikrohertz"),
+    UnitTranslation::new("millihertz", "Millihertz", "Millihertz"),
+    UnitTranslation::new("hertz", "Hertz", "Hertz"),
+    UnitTranslation::new("kilohertz", "Kilohertz", "Kilohertz"),
+    UnitTranslation::new("megahertz", "Megahertz", "Megahertz"),
+    UnitTranslation::new("gigahertz", "Gigahertz", "Gigahertz"),
+    UnitTranslation::new("terahertz", "Terahertz", "Terahertz"),
+    UnitTranslation::new("bit", "Bit", "Bit"),
+    UnitTranslation::new("octet", "Byte", "Byte"),
+    UnitTranslation::new("kilooctet", "Kilobyte", "Kilobyte"),
+    UnitTranslation::new("megaoctet", "Megabyte", "Megabyte"),
+    UnitTranslation::new("gigaoctet", "Gigabyte", "Gigabyte"),
+    UnitTranslation::new("teraoctet", "Terabyte", "Terabyte"),
+    UnitTranslation::new("petaoctet", "Petabyte", "Petabyte"),
+    UnitTranslation::new("exaoctet", "Exabyte", "Exabyte"),
+    UnitTranslation::new("kibioctet", "Kibibyte", "Kibibyte"),
+    UnitTranslation::new("mebioctet", "Mebibyte", "Mebibyte"),
+    UnitTranslation::new("gibioctet", "Gibibyte", "Gibibyte"),
+    UnitTranslation::new("tebioctet", "Tebibyte", "Tebibyte"),
+    UnitTranslation::new("pebioctet", "Pebibyte", "Pebibyte"),
+    UnitTranslation::new("exbioctet", "Exbibyte", "Exbibyte"),
+    UnitTranslation::new("bit per second", "Bit pro Sekunde", "Bit pro Sekunde"),
+    UnitTranslation::new(
+        "kilobit per second",
+        "Kilobit pro Sekunde",
+        "Kilobit pro Sekunde",
+    ),
+    UnitTranslation::new(
+        "megabit per second",
+        "Megabit pro Sekunde",
+        "Megabit pro Sekunde",
+    ),
+    UnitTranslation::new(
+        "gigabit per second",
+        "Gigabit pro Sekunde",
+        "Gigabit pro Sekunde",
+    ),
+    UnitTranslation::new("octet per second", "Byte pro Sekunde", "Byte pro Sekunde"),
+    UnitTranslation::new(
+        "kilooctet per second",
+        "Kilobyte pro Sekunde",
+        "Kilobyte pro Sekunde",
+    ),
+    UnitTranslation::new(
+        "megaoctet per second",
+        "Megabyte pro Sekunde",
+        "Megabyte pro Sekunde",
+    ),
+    UnitTranslation::new(
+        "kibioctet per second",
+        "Kibibyte pro Sekunde",
+        "Kibibyte pro Sekunde",
+    ),
+    UnitTranslation::new(
+        "mebioctet per second",
+        "Mebibyte pro Sekunde",
+        "Mebibyte pro Sekunde",
+    ),
+    UnitTranslation::new("picofarad", "Pikofarad", "Pikofarad"),
+    UnitTranslation::new("nanofarad", "Nanofarad", "Nanofarad"),
+    UnitTranslation::new("microfarad", "Mikrofarad", "Mikrofarad"),
+    UnitTranslation::new("millifarad", "Millifarad", "Millifarad"),
+    UnitTranslation::new("farad", "Farad", "Farad"),
+    UnitTranslation::new("nanohenry", "Nanohenry", "Nanohenry"),
+    UnitTranslation::new("microhenry", "Mikrohenry", "Mikrohenry"),
+    UnitTranslation::new("millihenry", "Millihenry", "Millihenry"),
+    UnitTranslation::new("henry", "Henry", "Henry"),
+    UnitTranslation::new("microsiemens", "Mikrosiemens", "Mikrosiemens"),
+    UnitTranslation::new("millisiemens", "Millisiemens", "Millisiemens"),
+    UnitTranslation::new("siemens", "Siemens", "Siemens"),
+    UnitTranslation::new("volt-ampere", "Voltampere", "Voltampere"),
+    UnitTranslation::new("kilovolt-ampere", "Kilovoltampere", "Kilovoltampere"),
+    UnitTranslation::new("megavolt-ampere", "Megavoltampere", "Megavoltampere"),
+    UnitTranslation::new("var", "Var", "Var"),
+    UnitTranslation::new("kilovar", "Kilovar", "Kilovar"),
+    UnitTranslation::new("megavar", "Megavar", "Megavar"),
+    UnitTranslation::new(
+        "kilogram per cubic meter",
+        "Kilogramm pro Kubikmeter",
+        "Kilogramm pro Kubikmeter",
+    ),
+    UnitTranslation::new(
+        "kilogram per liter",
+        "Kilogramm pro Liter",
+        "Kilogramm pro Liter",
+    ),
+    UnitTranslation::new(
+        "gram per cubic centimeter",
+        "Gramm pro Kubikzentimeter",
+        "Gramm pro Kubikzentimeter",
+    ),
+    UnitTranslation::new(
+        "gram per milliliter",
+        "Gramm pro Milliliter",
+        "Gramm pro Milliliter",
+    ),
+    UnitTranslation::new(
+        "pound per cubic foot",
+        "Pfund pro Kubikfuß",
+        "Pfund pro Kubikfuß",
+    ),
+    UnitTranslation::new("pound per gallon", "Pfund pro Gallone", "Pfund pro Gallone"),
+    UnitTranslation::new("specific gravity", "relative Dichte", "relative Dichte"),
+    UnitTranslation::new(
+        "kilogram per second",
+        "Kilogramm pro Sekunde",
+        "Kilogramm pro Sekunde",
+    ),
+    UnitTranslation::new(
+        "kilogram per minute",
+        "Kilogramm pro Minute",
+        "Kilogramm pro Minute",
+    ),
+    UnitTranslation::new(
+        "kilogram per hour",
+        "Kilogramm pro Stunde",
+        "Kilogramm pro Stunde",
+    ),
+    UnitTranslation::new("gram per second", "Gramm pro Sekunde", "Gramm pro Sekunde"),
+    UnitTranslation::new("tonne per hour", "Tonne pro Stunde", "Tonnen pro Stunde"),
+    UnitTranslation::new("pound per hour", "Pfund pro Stunde", "Pfund pro Stunde"),
+    UnitTranslation::new(
+        "cubic meter per second",
+        "Kubikmeter pro Sekunde",
+        "Kubikmeter pro Sekunde",
+    ),
+    UnitTranslation::new(
+        "cubic meter per hour",
+        "Kubikmeter pro Stunde",
+        "Kubikmeter pro Stunde",
+    ),
+    UnitTranslation::new("liter per second", "Liter pro Sekunde", "Liter pro Sekunde"),
+    UnitTranslation::new("liter per minute", "Liter pro Minute", "Liter pro Minute"),
+    UnitTranslation::new(
+        "gallon per minute",
+        "Gallone pro Minute",
+        "Gallonen pro Minute",
+    ),
+    UnitTranslation::new(
+        "cubic foot per minute",
+        "Kubikfuß pro Minute",
+        "Kubikfuß pro Minute",
+    ),
+];
+
+const FRENCH: &[UnitTranslation] = &[
+    UnitTranslation::new("nanometer", "nanomètre", "nanomètres"),
+    UnitTranslation::new("micrometer", "micromètre", "micromètres"),
+    UnitTranslation::new("millimeter", "millimètre", "millimètres"),
+    UnitTranslation::new("centimeter", "centimètre", "centimètres"),
+    UnitTranslation::new("decimeter", "décimètre", "décimètres"),
+    UnitTranslation::new("meter", "mètre", "mètres"),
+    UnitTranslation::new("hectometer", "hectomètre", "hectomètres"),
+    UnitTranslation::new("kilometer", "kilomètre", "kilomètres"),
+    UnitTranslation::new("inch", "pouce", "pouces").with_symbol("po"),
+    UnitTranslation::new("foot", "pied", "pieds").with_symbol("pi"),
+    UnitTranslation::new("yard", "verge", "verges").with_symbol("vg"),
+    UnitTranslation::new("furlong", "furlong", "furlongs"),
+    UnitTranslation::new("mile", "mille", "milles"),
+    UnitTranslation::new("square nanometer", "nanomètre carré", "nanomètres carrés"),
+    UnitTranslation::new(
+        "square micrometer",
+        "micromètre carré",
+        "micromètres carrés",
+    ),
+    UnitTranslation::new(
+        "square millimeter",
+        "millimètre carré",
+        "millimètres carrés",
+    ),
+    UnitTranslation::new(
+        "square centimeter",
+        "centimètre carré",
+        "centimètres carrés",
+    ),
+    UnitTranslation::new("square decimeter", "décimètre carré", "décimètres carrés"),
+    UnitTranslation::new("square meter", "mètre carré", "mètres carrés"),
+    UnitTranslation::new(
+        "square hectometer",
+        "hectomètre carré",
+        "hectomètres carrés",
+    ),
+    UnitTranslation::new("hectare", "hectare", "hectares"),
+    UnitTranslation::new("square kilometer", "kilomètre carré", "kilomètres carrés"),
+    UnitTranslation::new("square inch", "pouce carré", "pouces carrés").with_symbol("po²"),
+    UnitTranslation::new("square foot", "pied carré", "pieds carrés").with_symbol("pi²"),
+    UnitTranslation::new("square yard", "verge carrée", "verges carrées").with_symbol("vg²"),
+    UnitTranslation::new("acre", "acre", "acres"),
+    UnitTranslation::new("square mile", "mille carré", "milles carrés"),
+    UnitTranslation::new("milliliter", "millilitre", "millilitres"),
+    UnitTranslation::new("cubic centimeter", "centimètre cube", "centimètres cubes"),
+    UnitTranslation::new("liter", "litre", "litres"),
+    UnitTranslation::new("cubic meter", "mètre cube", "mètres cubes"),
+    UnitTranslation::new("drop", "goutte", "gouttes"),
+    UnitTranslation::new("dram", "drachme liquide", "drachmes liquides"),
+    UnitTranslation::new("teaspoon", "cuillère à café", "cuillères à café").with_symbol("c. à c."),
+    UnitTranslation::new("tablespoon", "cuillère à soupe", "cuillères à soupe")
+        .with_symbol("c. à s."),
+    UnitTranslation::new("cubic inch", "pouce cube", "pouces cubes").with_symbol("po³"),
+    UnitTranslation::new(
+        "UK fluid ounce",
+        "once liquide impériale",
+        "onces liquides impériales",
+    ),
+    UnitTranslation::new("fluid ounce", "once liquide", "onces liquides"),
+    UnitTranslation::new("cup", "tasse", "tasses"),
+    UnitTranslation::new("pint", "pinte", "pintes"),
+    UnitTranslation::new("UK pint", "pinte impériale", "pintes impériales"),
+    UnitTranslation::new("quart", "quart", "quarts"),
+    UnitTranslation::new("gallon", "gallon", "gallons"),
+    UnitTranslation::new("UK gallon", "gallon impérial", "gallons impériaux"),
+    UnitTranslation::new("cubic foot", "pied cube", "pieds cubes").with_symbol("pi³"),
+    UnitTranslation::new("cubic yard", "verge cube", "verges cubes").with_symbol("vg³"),
+    UnitTranslation::new("nanogram", "nanogramme", "nanogrammes"),
+    UnitTranslation::new("microgram", "microgramme", "microgrammes"),
+    UnitTranslation::new("milligram", "milligramme", "milligrammes"),
+    UnitTranslation::new("gram", "gramme", "grammes"),
+    UnitTranslation::new("kilogram", "kilogramme", "kilogrammes"),
+    UnitTranslation::new("tonne", "tonne", "tonnes"),
+    UnitTranslation::new("carat", "carat", "carats"),
+    UnitTranslation::new("grain", "grain", "grains"),
+    UnitTranslation::new("pennyweight", "pennyweight", "pennyweights"),
+    UnitTranslation::new("ounce", "once", "onces"),
+    UnitTranslation::new("troy ounce", "once troy", "onces troy"),
+    UnitTranslation::new("pound", "livre", "livres"),
+    UnitTranslation::new("troy pound", "livre troy", "livres troy"),
+    UnitTranslation::new("stone", "stone", "stones"),
+    UnitTranslation::new("short ton", "tonne courte", "tonnes courtes"),
+    UnitTranslation::new("long ton", "tonne longue", "tonnes longues"),
+    UnitTranslation::new("nanosecond", "nanoseconde", "nanosecondes"),
+    UnitTranslation::new("microsecond", "microseconde", "microsecondes"),
+    UnitTranslation::new("millisecond", "milliseconde", "millisecondes"),
+    UnitTranslation::new("second", "seconde", "secondes"),
+    UnitTranslation::new("minute", "minute", "minutes"),
+    UnitTranslation::new("hour", "heure", "heures"),
+    UnitTranslation::new("day", "jour", "jours").with_symbol("j"),
+    UnitTranslation::new("kelvin", "kelvin", "kelvins"),
+    UnitTranslation::new("degree Celsius", "degré Celsius", "degrés Celsius"),
+    UnitTranslation::new("degree Fahrenheit", "degré Fahrenheit", "degrés Fahrenheit"),
+    UnitTranslation::new("degree Rankine", "degré Rankine", "degrés Rankine"),
+    UnitTranslation::new("radian", "radian", "radians"),
+    UnitTranslation::new("degree", "degré", "degrés"),
+    UnitTranslation::new(
+        "radian per second",
+        "radian par seconde",
+        "radians par seconde",
+    ),
+    UnitTranslation::new(
+        "revolution per minute",
+        "tour par minute",
+        "tours par minute",
+    )
+    .with_symbol("tr/min"),
+    UnitTranslation::new(
+        "revolution per second",
+        "tour par seconde",
+        "tours par seconde",
+    )
+    .with_symbol("tr/s"),
+    UnitTranslation::new(
+        "meter per second",
+        "mètre par seconde",
+        "mètres par seconde",
+    ),
+    UnitTranslation::new(
+        "kilometer per hour",
+        "kilomètre par heure",
+        "kilomètres par heure",
+    ),
+    UnitTranslation::new("mile per hour", "mille par heure", "milles par heure"),
+    UnitTranslation::new(
+        "meter per second squared",
+        "mètre par seconde carrée",
+        "mètres par seconde carrée",
+    ),
+    UnitTranslation::new(
+        "foot per second squared",
+        "pied par seconde carrée",
+        "pieds par seconde carrée",
+    )
+    .with_symbol("pi/s²"),
+    UnitTranslation::new("pascal", "pascal", "pascals"),
+    UnitTranslation::new("hectopascal", "hectopascal", "hectopascals"),
+    UnitTranslation::new("millibar", "millibar", "millibars"),
+    UnitTranslation::new("kilopascal", "kilopascal", "kilopascals"),
+    UnitTranslation::new(
+        "pound per square inch",
+        "livre par pouce carré",
+        "livres par pouce carré",
+    ),
+    UnitTranslation::new("bar", "bar", "bars"),
+    UnitTranslation::new("atmosphere", "atmosphère", "atmosphères"),
+    UnitTranslation::new("joule", "joule", "joules"),
+    UnitTranslation::new("kilocalorie", "kilocalorie", "kilocalories"),
+    UnitTranslation::new(
+        "British thermal unit",
+        "British thermal unit",
+        "British thermal units",
+    ),
+    UnitTranslation::new("electronvolt", "électronvolt", "électronvolts"),
+    UnitTranslation::new("watt-hour", "wattheure", "wattheures"),
+    UnitTranslation::new("kilowatt-hour", "kilowattheure", "kilowattheures"),
+    UnitTranslation::new("microwatt", "microwatt", "microwatts"),
+    UnitTranslation::new("milliwatt", "milliwatt", "milliwatts"),
+    UnitTranslation::new("watt", "watt", "watts"),
+    UnitTranslation::new("kilowatt", "kilowatt", "kilowatts"),
+    UnitTranslation::new(
+        "horsepower",
+        "cheval-vapeur impérial",
+        "chevaux-vapeur impériaux",
+    ),
+    UnitTranslation::new("metric horsepower", "cheval-vapeur", "chevaux-vapeur").with_symbol("ch"),
+    UnitTranslation::new("BTU per minute", "BTU par minute", "BTU par minute"),
+    UnitTranslation::new("micronewton", "micronewton", "micronewtons"),
+    UnitTranslation::new("millinewton", "millinewton", "millinewtons"),
+    UnitTranslation::new("newton", "newton", "newtons"),
+    UnitTranslation::new("pound-force", "livre-force", "livres-force"),
+    UnitTranslation::new("poundal", "poundal", "poundals"),
+    UnitTranslation::new("kilopond", "kilopond", "kiloponds"),
+    UnitTranslation::new("dyne", "dyne", "dynes"),
+    UnitTranslation::new("newton metre", "newton-mètre", "newtons-mètres"),
+    UnitTranslation::new("pound-foot", "livre-pied", "livres-pieds"),
+    UnitTranslation::new("microvolt", "microvolt", "microvolts"),
+    UnitTranslation::new("millivolt", "millivolt", "millivolts"),
+    UnitTranslation::new("volt", "volt", "volts"),
+    UnitTranslation::new("kilovolt", "kilovolt", "kilovolts"),
+    UnitTranslation::new("nanoampere", "nanoampère", "nanoampères"),
+    UnitTranslation::new("microampere", "microampère", "microampères"),
+    UnitTranslation::new("milliampere", "milliampère", "milliampères"),
+    UnitTranslation::new("ampere", "ampère", "ampères"),
+    UnitTranslation::new("ohm", "ohm", "ohms"),
+    UnitTranslation::new("kiloohm", "kiloohm", "kiloohms"),
+    UnitTranslation::new("megaohm", "mégohm", "mégohms"),
+    UnitTranslation::new("microcoulomb", "microcoulomb", "microcoulombs"),
+    UnitTranslation::new("millicoulomb", "millicoulomb", "millicoulombs"),
+    UnitTranslation::new("coulomb", "coulomb", "coulombs"),
+    UnitTranslation::new(
+        "milliampere-hour",
+        "milliampère-heure",
+        "milliampères-heures",
+    ),
+    UnitTranslation::new("ampere-hour", "ampère-heure", "ampères-heures"),
+    UnitTranslation::new("nanohertz", "nanohertz", "nanohertz"),
+    UnitTranslation::new("microhertz", "microhertz", "microhertz"),
+    UnitTranslation::new("millihertz", "millihertz", "millihertz"),
+    UnitTranslation::new("hertz", "hertz", "hertz"),
+    UnitTranslation::new("kilohertz", "kilohertz", "kilohertz"),
+    UnitTranslation::new("megahertz", "mégahertz", "mégahertz"),
+    UnitTranslation::new("gigahertz", "gigahertz", "gigahertz"),
+    UnitTranslation::new("terahertz", "térahertz", "térahertz"),
+    UnitTranslation::new("bit", "bit", "bits"),
+    UnitTranslation::new("octet", "octet", "octets").with_symbol("o"),
+    UnitTranslation::new("kilooctet", "kilooctet", "kilooctets").with_symbol("ko"),
+    UnitTranslation::new("megaoctet", "mégaoctet", "mégaoctets").with_symbol("Mo"),
+    UnitTranslation::new("gigaoctet", "gigaoctet", "gigaoctets").with_symbol("Go"),
+    UnitTranslation::new("teraoctet", "téraoctet", "téraoctets").with_symbol("To"),
+    UnitTranslation::new("petaoctet", "pétaoctet", "pétaoctets").with_symbol("Po"),
+    UnitTranslation::new("exaoctet", "exaoctet", "exaoctets").with_symbol("Eo"),
+    UnitTranslation::new("kibioctet", "kibioctet", "kibioctets").with_symbol("Kio"),
+    UnitTranslation::new("mebioctet", "mébioctet", "mébioctets").with_symbol("Mio"),
+    UnitTranslation::new("gibioctet", "gibioctet", "gibioctets").with_symbol("Gio"),
+    UnitTranslation::new("tebioctet", "tébioctet", "tébioctets").with_symbol("Tio"),
+    UnitTranslation::new("pebioctet", "pébioctet", "pébioctets").with_symbol("Pio"),
+    UnitTranslation::new("exbioctet", "exbioctet", "exbioctets").with_symbol("Eio"),
+    UnitTranslation::new("bit per second", "bit par seconde", "bits par seconde"),
+    UnitTranslation::new(
+        "kilobit per second",
+        "kilobit par seconde",
+        "kilobits par seconde",
+    ),
+    UnitTranslation::new(
+        "megabit per second",
+        "mégabit par seconde",
+        "mégabits par seconde",
+    ),
+    UnitTranslation::new(
+        "gigabit per second",
+        "gigabit par seconde",
+        "gigabits par seconde",
+    ),
+    UnitTranslation::new(
+        "octet per second",
+        "octet par seconde",
+        "octets par seconde",
+    )
+    .with_symbol("o/s"),
+    UnitTranslation::new(
+        "kilooctet per second",
+        "kilooctet par seconde",
+        "kilooctets par seconde",
+    )
+    .with_symbol("ko/s"),
+    UnitTranslation::new(
+        "megaoctet per second",
+        "mégaoctet par seconde",
+        "mégaoctets par seconde",
+    )
+    .with_symbol("Mo/s"),
+    UnitTranslation::new(
+        "kibioctet per second",
+        "kibioctet par seconde",
+        "kibioctets par seconde",
+    )
+    .with_symbol("Kio/s"),
+    UnitTranslation::new(
+        "mebioctet per second",
+        "mébioctet par seconde",
+        "mébioctets par seconde",
+    )
+    .with_symbol("Mio/s"),
+    UnitTranslation::new("picofarad", "picofarad", "picofarads"),
+    UnitTranslation::new("nanofarad", "nanofarad", "nanofarads"),
+    UnitTranslation::new("microfarad", "microfarad", "microfarads"),
+    UnitTranslation::new("millifarad", "millifarad", "millifarads"),
+    UnitTranslation::new("farad", "farad", "farads"),
+    UnitTranslation::new("nanohenry", "nanohenry", "nanohenrys"),
+    UnitTranslation::new("microhenry", "microhenry", "microhenrys"),
+    UnitTranslation::new("millihenry", "millihenry", "millihenrys"),
+    UnitTranslation::new("henry", "henry", "henrys"),
+    UnitTranslation::new("microsiemens", "microsiemens", "microsiemens"),
+    UnitTranslation::new("millisiemens", "millisiemens", "millisiemens"),
+    UnitTranslation::new("siemens", "siemens", "siemens"),
+    UnitTranslation::new("volt-ampere", "voltampère", "voltampères"),
+    UnitTranslation::new("kilovolt-ampere", "kilovoltampère", "kilovoltampères"),
+    UnitTranslation::new("megavolt-ampere", "mégavoltampère", "mégavoltampères"),
+    UnitTranslation::new("var", "var", "vars"),
+    UnitTranslation::new("kilovar", "kilovar", "kilovars"),
+    UnitTranslation::new("megavar", "mégavar", "mégavars"),
+    UnitTranslation::new(
+        "kilogram per cubic meter",
+        "kilogramme par mètre cube",
+        "kilogrammes par mètre cube",
+    ),
+    UnitTranslation::new(
+        "kilogram per liter",
+        "kilogramme par litre",
+        "kilogrammes par litre",
+    ),
+    UnitTranslation::new(
+        "gram per cubic centimeter",
+        "gramme par centimètre cube",
+        "grammes par centimètre cube",
+    ),
+    UnitTranslation::new(
+        "gram per milliliter",
+        "gramme par millilitre",
+        "grammes par millilitre",
+    ),
+    UnitTranslation::new(
+        "pound per cubic foot",
+        "livre par pied cube",
+        "livres par pied cube",
+    )
+    .with_symbol("lb/pi³"),
+    UnitTranslation::new("pound per gallon", "livre par gallon", "livres par gallon"),
+    UnitTranslation::new("specific gravity", "densité relative", "densité relative"),
+    UnitTranslation::new(
+        "kilogram per second",
+        "kilogramme par seconde",
+        "kilogrammes par seconde",
+    ),
+    UnitTranslation::new(
+        "kilogram per minute",
+        "kilogramme par minute",
+        "kilogrammes par minute",
+    ),
+    UnitTranslation::new(
+        "kilogram per hour",
+        "kilogramme par heure",
+        "kilogrammes par heure",
+    ),
+    UnitTranslation::new(
+        "gram per second",
+        "gramme par seconde",
+        "grammes par seconde",
+    ),
+    UnitTranslation::new("tonne per hour", "tonne par heure", "tonnes par heure"),
+    UnitTranslation::new("pound per hour", "livre par heure", "livres par heure"),
+    UnitTranslation::new(
+        "cubic meter per second",
+        "mètre cube par seconde",
+        "mètres cubes par seconde",
+    ),
+    UnitTranslation::new(
+        "cubic meter per hour",
+        "mètre cube par heure",
+        "mètres cubes par heure",
+    ),
+    UnitTranslation::new(
+        "liter per second",
+        "litre par seconde",
+        "litres par seconde",
+    ),
+    UnitTranslation::new("liter per minute", "litre par minute", "litres par minute"),
+    UnitTranslation::new(
+        "gallon per minute",
+        "gallon par minute",
+        "gallons par minute",
+    ),
+    UnitTranslation::new(
+        "cubic foot per minute",
+        "pied cube par minute",
+        "pieds cubes par minute",
+    )
+    .with_symbol("pi³/min"),
+];
+
+const JAPANESE: &[UnitTranslation] = &[
+    UnitTranslation::new("nanometer", "ナノメートル", "ナノメートル"),
+    UnitTranslation::new("micrometer", "マイクロメートル", "マイクロメートル"),
+    UnitTranslation::new("millimeter", "ミリメートル", "ミリメートル"),
+    UnitTranslation::new("centimeter", "センチメートル", "センチメートル"),
+    UnitTranslation::new("decimeter", "デシメートル", "デシメートル"),
+    UnitTranslation::new("meter", "メートル", "メートル"),
+    UnitTranslation::new("hectometer", "ヘクトメートル", "ヘクトメートル"),
+    UnitTranslation::new("kilometer", "キロメートル", "キロメートル"),
+    UnitTranslation::new("inch", "インチ", "インチ"),
+    UnitTranslation::new("foot", "フィート", "フィート"),
+    UnitTranslation::new("yard", "ヤード", "ヤード"),
+    UnitTranslation::new("furlong", "ハロン", "ハロン"),
+    UnitTranslation::new("mile", "マイル", "マイル"),
+    UnitTranslation::new("square nanometer", "平方ナノメートル", "平方ナノメートル"),
+    UnitTranslation::new(
+        "square micrometer",
+        "平方マイクロメートル",
+        "平方マイクロメートル",
+    ),
+    UnitTranslation::new("square millimeter", "平方ミリメートル", "平方ミリメートル"),
+    UnitTranslation::new(
+        "square centimeter",
+        "平方センチメートル",
+        "平方センチメートル",
+    ),
+    UnitTranslation::new("square decimeter", "平方デシメートル", "平方デシメートル"),
+    UnitTranslation::new("square meter", "平方メートル", "平方メートル"),
+    UnitTranslation::new(
+        "square hectometer",
+        "平方ヘクトメートル",
+        "平方ヘクトメートル",
+    ),
+    UnitTranslation::new("hectare", "ヘクタール", "ヘクタール"),
+    UnitTranslation::new("square kilometer", "平方キロメートル", "平方キロメートル"),
+    UnitTranslation::new("square inch", "平方インチ", "平方インチ"),
+    UnitTranslation::new("square foot", "平方フィート", "平方フィート"),
+    UnitTranslation::new("square yard", "平方ヤード", "平方ヤード"),
+    UnitTranslation::new("acre", "エーカー", "エーカー"),
+    UnitTranslation::new("square mile", "平方マイル", "平方マイル"),
+    UnitTranslation::new("milliliter", "ミリリットル", "ミリリットル"),
+    UnitTranslation::new(
+        "cubic centimeter",
+        "立方センチメートル",
+        "立方センチメートル",
+    ),
+    UnitTranslation::new("liter", "リットル", "リットル"),
+    UnitTranslation::new("cubic meter", "立方メートル", "立方メートル"),
+    UnitTranslation::new("drop", "滴", "滴"),
+    UnitTranslation::new("dram", "液量ドラム", "液量ドラム"),
+    UnitTranslation::new("teaspoon", "小さじ", "小さじ"),
+    UnitTranslation::new("tablespoon", "大さじ", "大さじ"),
+    UnitTranslation::new("cubic inch", "立方インチ", "立方インチ"),
+    UnitTranslation::new("UK fluid ounce", "英液量オンス", "英液量オンス"),
+    UnitTranslation::new("fluid ounce", "米液量オンス", "米液量オンス"),
+    UnitTranslation::new("cup", "カップ", "カップ"),
+    UnitTranslation::new("pint", "パイント", "パイント"),
+    UnitTranslation::new("UK pint", "英パイント", "英パイント"),
+    UnitTranslation::new("quart", "クォート", "クォート"),
+    UnitTranslation::new("gallon", "ガロン", "ガロン"),
+    UnitTranslation::new("UK gallon", "英ガロン", "英ガロン"),
+    UnitTranslation::new("cubic foot", "立方フィート", "立方フィート"),
+    UnitTranslation::new("cubic yard", "立方ヤード", "立方ヤード"),
+    UnitTranslation::new("nanogram", "ナノグラム", "ナノグラム"),
+    UnitTranslation::new("microgram", "マイクログラム", "マイクログラム"),
+    UnitTranslation::new("milligram", "ミリグラム", "ミリグラム"),
+    UnitTranslation::new("gram", "グラム", "グラム"),
+    UnitTranslation::new("kilogram", "キログラム", "キログラム"),
+    UnitTranslation::new("tonne", "トン", "トン"),
+    UnitTranslation::new("carat", "カラット", "カラット"),
+    UnitTranslation::new("grain", "グレーン", "グレーン"),
+    UnitTranslation::new("pennyweight", "ペニーウェイト", "ペニーウェイト"),
+    UnitTranslation::new("ounce", "オンス", "オンス"),
+    UnitTranslation::new("troy ounce", "トロイオンス", "トロイオンス"),
+    UnitTranslation::new("pound", "ポンド", "ポンド"),
+    UnitTranslation::new("troy pound", "トロイポンド", "トロイポンド"),
+    UnitTranslation::new("stone", "ストーン", "ストーン"),
+    UnitTranslation::new("short ton", "米トン", "米トン"),
+    UnitTranslation::new("long ton", "英トン", "英トン"),
+    UnitTranslation::new("nanosecond", "ナノ秒", "ナノ秒"),
+    UnitTranslation::new("microsecond", "マイクロ秒", "マイクロ秒"),
+    UnitTranslation::new("millisecond", "ミリ秒", "ミリ秒"),
+    UnitTranslation::new("second", "秒", "秒"),
+    UnitTranslation::new("minute", "分", "分"),
+    UnitTranslation::new("hour", "時間", "時間"),
+    UnitTranslation::new("day", "日", "日"),
+    UnitTranslation::new("kelvin", "ケルビン", "ケルビン"),
+    UnitTranslation::new("degree Celsius", "セルシウス度", "セルシウス度"),
+    UnitTranslation::new(
+        "degree Fahrenheit",
+        "ファーレンハイト度",
+        "ファーレンハイト度",
+    ),
+    UnitTranslation::new("degree Rankine", "ランキン度", "ランキン度"),
+    UnitTranslation::new("radian", "ラジアン", "ラジアン"),
+    UnitTranslation::new("degree", "度", "度"),
+    UnitTranslation::new("radian per second", "ラジアン毎秒", "ラジアン毎秒"),
+    UnitTranslation::new("revolution per minute", "毎分回転数", "毎分回転数"),
+    UnitTranslation::new("revolution per second", "毎秒回転数", "毎秒回転数"),
+    UnitTranslation::new("meter per second", "メートル毎秒", "メートル毎秒"),
+    UnitTranslation::new("kilometer per hour", "キロメートル毎時", "キロメートル毎時"),
+    UnitTranslation::new("mile per hour", "マイル毎時", "マイル毎時"),
+    UnitTranslation::new(
+        "meter per second squared",
+        "メートル毎秒毎秒",
+        "メートル毎秒毎秒",
+    ),
+    UnitTranslation::new(
+        "foot per second squared",
+        "フィート毎秒毎秒",
+        "フィート毎秒毎秒",
+    ),
+    UnitTranslation::new("pascal", "パスカル", "パスカル"),
+    UnitTranslation::new("hectopascal", "ヘクトパスカル", "ヘクトパスカル"),
+    UnitTranslation::new("millibar", "ミリバール", "ミリバール"),
+    UnitTranslation::new("kilopascal", "キロパスカル", "キロパスカル"),
+    UnitTranslation::new(
+        "pound per square inch",
+        "重量ポンド毎平方インチ",
+        "重量ポンド毎平方インチ",
+    ),
+    UnitTranslation::new("bar", "バール", "バール"),
+    UnitTranslation::new("atmosphere", "気圧", "気圧"),
+    UnitTranslation::new("joule", "ジュール", "ジュール"),
+    UnitTranslation::new("kilocalorie", "キロカロリー", "キロカロリー"),
+    UnitTranslation::new("British thermal unit", "英国熱量単位", "英国熱量単位"),
+    UnitTranslation::new("electronvolt", "電子ボルト", "電子ボルト"),
+    UnitTranslation::new("watt-hour", "ワット時", "ワット時"),
+    UnitTranslation::new("kilowatt-hour", "キロワット時", "キロワット時"),
+    UnitTranslation::new("microwatt", "マイクロワット", "マイクロワット"),
+    UnitTranslation::new("milliwatt", "ミリワット", "ミリワット"),
+    UnitTranslation::new("watt", "ワット", "ワット"),
+    UnitTranslation::new("kilowatt", "キロワット", "キロワット"),
+    UnitTranslation::new("horsepower", "英馬力", "英馬力"),
+    UnitTranslation::new("metric horsepower", "仏馬力", "仏馬力"),
+    UnitTranslation::new("BTU per minute", "BTU毎分", "BTU毎分"),
+    UnitTranslation::new("micronewton", "マイクロニュートン", "マイクロニュートン"),
+    UnitTranslation::new("millinewton", "ミリニュートン", "ミリニュートン"),
+    UnitTranslation::new("newton", "ニュートン", "ニュートン"),
+    UnitTranslation::new("pound-force", "重量ポンド", "重量ポンド"),
+    UnitTranslation::new("poundal", "パウンダル", "パウンダル"),
+    UnitTranslation::new("kilopond", "キロポンド", "キロポンド"),
+    UnitTranslation::new("dyne", "ダイン", "ダイン"),
+    UnitTranslation::new("newton metre", "ニュートンメートル", "ニュートンメートル"),
+    UnitTranslation::new("pound-foot", "ポンドフィート", "ポンドフィート"),
+    UnitTranslation::new("microvolt", "マイクロボルト", "マイクロボルト"),
+    UnitTranslation::new("millivolt", "ミリボルト", "ミリボルト"),
+    UnitTranslation::new("volt", "ボルト", "ボルト"),
+    UnitTranslation::new("kilovolt", "キロボルト", "キロボルト"),
+    UnitTranslation::new("nanoampere", "ナノアンペア", "ナノアンペア"),
+    UnitTranslation::new("microampere", "マイクロアンペア", "マイクロアンペア"),
+    UnitTranslation::new("milliampere", "ミリアンペア", "ミリアンペア"),
+    UnitTranslation::new("ampere", "アンペア", "アンペア"),
+    UnitTranslation::new("ohm", "オーム", "オーム"),
+    UnitTranslation::new("kiloohm", "キロオーム", "キロオーム"),
+    UnitTranslation::new("megaohm", "メガオーム", "メガオーム"),
+    UnitTranslation::new("microcoulomb", "マイクロクーロン", "マイクロクーロン"),
+    UnitTranslation::new("millicoulomb", "ミリクーロン", "ミリクーロン"),
+    UnitTranslation::new("coulomb", "クーロン", "クーロン"),
+    UnitTranslation::new("milliampere-hour", "ミリアンペア時", "ミリアンペア時"),
+    UnitTranslation::new("ampere-hour", "アンペア時", "アンペア時"),
+    UnitTranslation::new("nanohertz", "ナノヘルツ", "ナノヘルツ"),
+    UnitTranslation::new("microhertz", "マイクロヘルツ", "マイクロヘルツ"),
+    UnitTranslation::new("millihertz", "ミリヘルツ", "ミリヘルツ"),
+    UnitTranslation::new("hertz", "ヘルツ", "ヘルツ"),
+    UnitTranslation::new("kilohertz", "キロヘルツ", "キロヘルツ"),
+    UnitTranslation::new("megahertz", "メガヘルツ", "メガヘルツ"),
+    UnitTranslation::new("gigahertz", "ギガヘルツ", "ギガヘルツ"),
+    UnitTranslation::new("terahertz", "テラヘルツ", "テラヘルツ"),
+    UnitTranslation::new("bit", "ビット", "ビット"),
+    UnitTranslation::new("octet", "バイト", "バイト"),
+    UnitTranslation::new("kilooctet", "キロバイト", "キロバイト"),
+    UnitTranslation::new("megaoctet", "メガバイト", "メガバイト"),
+    UnitTranslation::new("gigaoctet", "ギガバイト", "ギガバイト"),
+    UnitTranslation::new("teraoctet", "テラバイト", "テラバイト"),
+    UnitTranslation::new("petaoctet", "ペタバイト", "ペタバイト"),
+    UnitTranslation::new("exaoctet", "エクサバイト", "エクサバイト"),
+    UnitTranslation::new("kibioctet", "キビバイト", "キビバイト"),
+    UnitTranslation::new("mebioctet", "メビバイト", "メビバイト"),
+    UnitTranslation::new("gibioctet", "ギビバイト", "ギビバイト"),
+    UnitTranslation::new("tebioctet", "テビバイト", "テビバイト"),
+    UnitTranslation::new("pebioctet", "ペビバイト", "ペビバイト"),
+    UnitTranslation::new("exbioctet", "エクスビバイト", "エクスビバイト"),
+    UnitTranslation::new("bit per second", "ビット毎秒", "ビット毎秒"),
+    UnitTranslation::new("kilobit per second", "キロビット毎秒", "キロビット毎秒"),
+    UnitTranslation::new("megabit per second", "メガビット毎秒", "メガビット毎秒"),
+    UnitTranslation::new("gigabit per second", "ギガビット毎秒", "ギガビット毎秒"),
+    UnitTranslation::new("octet per second", "バイト毎秒", "バイト毎秒"),
+    UnitTranslation::new("kilooctet per second", "キロバイト毎秒", "キロバイト毎秒"),
+    UnitTranslation::new("megaoctet per second", "メガバイト毎秒", "メガバイト毎秒"),
+    UnitTranslation::new("kibioctet per second", "キビバイト毎秒", "キビバイト毎秒"),
+    UnitTranslation::new("mebioctet per second", "メビバイト毎秒", "メビバイト毎秒"),
+    UnitTranslation::new("picofarad", "ピコファラド", "ピコファラド"),
+    UnitTranslation::new("nanofarad", "ナノファラド", "ナノファラド"),
+    UnitTranslation::new("microfarad", "マイクロファラド", "マイクロファラド"),
+    UnitTranslation::new("millifarad", "ミリファラド", "ミリファラド"),
+    UnitTranslation::new("farad", "ファラド", "ファラド"),
+    UnitTranslation::new("nanohenry", "ナノヘンリー", "ナノヘンリー"),
+    UnitTranslation::new("microhenry", "マイクロヘンリー", "マイクロヘンリー"),
+    UnitTranslation::new("millihenry", "ミリヘンリー", "ミリヘンリー"),
+    UnitTranslation::new("henry", "ヘンリー", "ヘンリー"),
+    UnitTranslation::new("microsiemens", "マイクロジーメンス", "マイクロジーメンス"),
+    UnitTranslation::new("millisiemens", "ミリジーメンス", "ミリジーメンス"),
+    UnitTranslation::new("siemens", "ジーメンス", "ジーメンス"),
+    UnitTranslation::new("volt-ampere", "ボルトアンペア", "ボルトアンペア"),
+    UnitTranslation::new(
+        "kilovolt-ampere",
+        "キロボルトアンペア",
+        "キロボルトアンペア",
+    ),
+    UnitTranslation::new(
+        "megavolt-ampere",
+        "メガボルトアンペア",
+        "メガボルトアンペア",
+    ),
+    UnitTranslation::new("var", "バール", "バール"),
+    UnitTranslation::new("kilovar", "キロバール", "キロバール"),
+    UnitTranslation::new("megavar", "メガバール", "メガバール"),
+    UnitTranslation::new(
+        "kilogram per cubic meter",
+        "キログラム毎立方メートル",
+        "キログラム毎立方メートル",
+    ),
+    UnitTranslation::new(
+        "kilogram per liter",
+        "キログラム毎リットル",
+        "キログラム毎リットル",
+    ),
+    UnitTranslation::new(
+        "gram per cubic centimeter",
+        "グラム毎立方センチメートル",
+        "グラム毎立方センチメートル",
+    ),
+    UnitTranslation::new(
+        "gram per milliliter",
+        "グラム毎ミリリットル",
+        "グラム毎ミリリットル",
+    ),
+    UnitTranslation::new(
+        "pound per cubic foot",
+        "ポンド毎立方フィート",
+        "ポンド毎立方フィート",
+    ),
+    UnitTranslation::new("pound per gallon", "ポンド毎ガロン", "ポンド毎ガロン"),
+    UnitTranslation::new("specific gravity", "比重", "比重"),
+    UnitTranslation::new("kilogram per second", "キログラム毎秒", "キログラム毎秒"),
+    UnitTranslation::new("kilogram per minute", "キログラム毎分", "キログラム毎分"),
+    UnitTranslation::new("kilogram per hour", "キログラム毎時", "キログラム毎時"),
+    UnitTranslation::new("gram per second", "グラム毎秒", "グラム毎秒"),
+    UnitTranslation::new("tonne per hour", "トン毎時", "トン毎時"),
+    UnitTranslation::new("pound per hour", "ポンド毎時", "ポンド毎時"),
+    UnitTranslation::new(
+        "cubic meter per second",
+        "立方メートル毎秒",
+        "立方メートル毎秒",
+    ),
+    UnitTranslation::new(
+        "cubic meter per hour",
+        "立方メートル毎時",
+        "立方メートル毎時",
+    ),
+    UnitTranslation::new("liter per second", "リットル毎秒", "リットル毎秒"),
+    UnitTranslation::new("liter per minute", "リットル毎分", "リットル毎分"),
+    UnitTranslation::new("gallon per minute", "ガロン毎分", "ガロン毎分"),
+    UnitTranslation::new(
+        "cubic foot per minute",
+        "立方フィート毎分",
+        "立方フィート毎分",
+    ),
+];
+
+#[cfg(test)]
+mod test {
+    use data::DataUnit;
+    use duration::{self, DurationUnit};
+    use length::{Length, LengthUnit};
+    use locale::*;
+    use mass::Mass;
+    use temperature::TemperatureUnit;
+    use torque::TorqueUnit;
+    use volume::{Volume, VolumeUnit};
+
+    static SWISS_GERMAN: Locale = Locale {
+        tag: "de-CH",
+        decimal_separator: '.',
+        plural_rule: PluralRule::One,
+        spelling: Spelling::British,
+        units: &[UnitTranslation::new("kilogram", "Kilo", "Kilo")],
+        fallback: Some(&DE),
+    };
+
+    #[test]
+    fn spelling() {
+        assert_eq!(EN_US.singular(LengthUnit::Meter).to_string(), "meter");
+        assert_eq!(EN_GB.singular(LengthUnit::Meter).to_string(), "metre");
+        assert_eq!(
+            EN_GB.plural(LengthUnit::Kilometer).to_string(),
+            "kilometres"
+        );
+        assert_eq!(
+            EN_GB.plural(VolumeUnit::Milliliter).to_string(),
+            "millilitres"
+        );
+        assert_eq!(
+            EN_US.singular(TorqueUnit::NewtonMetre).to_string(),
+            "newton meter"
+        );
+        assert_eq!(EN_GB.singular(LengthUnit::Foot).to_string(), "foot");
+    }
+
+    #[test]
+    fn translations() {
+        assert_eq!(DE.plural(VolumeUnit::Cup).to_string(), "Tassen");
+        assert_eq!(
+            FR.singular(TemperatureUnit::Celsius).to_string(),
+            "degré Celsius"
+        );
+        assert_eq!(JA.singular(DurationUnit::Hour).to_string(), "時間");
+        assert_eq!(FR.symbol(DataUnit::Kilooctet), "ko");
+        assert_eq!(DE.symbol(DataUnit::Kilooctet), "kB");
+        assert_eq!(
+            DE.plural(::CapacitanceUnit::Microfarad).to_string(),
+            "Mikrofarad"
+        );
+        assert_eq!(FR.symbol(VolumeUnit::CubicFoot), "pi\u{00B3}");
+        assert_eq!(
+            FR.plural(::MassUnit::ShortTon).to_string(),
+            "tonnes courtes"
+        );
+        assert_eq!(
+            JA.singular(::PressureUnit::Psi).to_string(),
+            "重量ポンド毎平方インチ"
+        );
+    }
+
+    #[test]
+    fn untranslated_units_keep_english_names() {
+        static UNTRANSLATED: Locale = Locale {
+            tag: "eo",
+            decimal_separator: ',',
+            plural_rule: PluralRule::One,
+            spelling: Spelling::British,
+            units: &[],
+            fallback: None,
+        };
+        assert_eq!(
+            UNTRANSLATED.singular(LengthUnit::Furlong).to_string(),
+            "furlong"
+        );
+        assert_eq!(
+            UNTRANSLATED.plural(LengthUnit::Hectometer).to_string(),
+            "hectometres"
+        );
+        assert_eq!(UNTRANSLATED.symbol(LengthUnit::Hectometer), "hm");
+    }
+
+    /// The English singular names of the units of type `U` which `locale`
+    /// does not translate.
+    fn untranslated<U: Unit>(locale: &Locale) -> Vec<&'static str> {
+        U::iter()
+            .filter(|&unit| locale.translation(unit).is_none())
+            .map(|unit| unit.singular())
+            .collect()
+    }
+
+    #[test]
+    fn built_in_tables_are_complete() {
+        for locale in BUILT_IN.iter().filter(|locale| !locale.units.is_empty()) {
+            let missing = [
+                untranslated::<::AccelerationUnit>(locale),
+                untranslated::<::AngleUnit>(locale),
+                untranslated::<::AngularVelocityUnit>(locale),
+                untranslated::<::ApparentPowerUnit>(locale),
+                untranslated::<::AreaUnit>(locale),
+                untranslated::<::CapacitanceUnit>(locale),
+                untranslated::<::ConductanceUnit>(locale),
+                untranslated::<::CurrentUnit>(locale),
+                untranslated::<DataUnit>(locale),
+                untranslated::<::DataRateUnit>(locale),
+                untranslated::<::DensityUnit>(locale),
+                untranslated::<DurationUnit>(locale),
+                untranslated::<::ElectricChargeUnit>(locale),
+                untranslated::<::EnergyUnit>(locale),
+                untranslated::<::ForceUnit>(locale),
+                untranslated::<::FrequencyUnit>(locale),
+                untranslated::<::InductanceUnit>(locale),
+                untranslated::<LengthUnit>(locale),
+                untranslated::<::MassUnit>(locale),
+                untranslated::<::MassFlowRateUnit>(locale),
+                untranslated::<::PowerUnit>(locale),
+                untranslated::<::PressureUnit>(locale),
+                untranslated::<::ReactivePowerUnit>(locale),
+                untranslated::<::ResistanceUnit>(locale),
+                untranslated::<::SpeedUnit>(locale),
+                untranslated::<TemperatureUnit>(locale),
+                untranslated::<TorqueUnit>(locale),
+                untranslated::<::VoltageUnit>(locale),
+                untranslated::<VolumeUnit>(locale),
+                untranslated::<::VolumetricFlowRateUnit>(locale),
+            ]
+            .concat();
+            assert!(missing.is_empty(), "{} lacks {:?}", locale.tag, missing);
+        }
+    }
+
+    #[test]
+    fn fallback() {
+        assert_eq!(
+            SWISS_GERMAN
+                .singular(::mass::MassUnit::Kilogram)
+                .to_string(),
+            "Kilo"
+        );
+        assert_eq!(
+            SWISS_GERMAN.singular(::mass::MassUnit::Gram).to_string(),
+            "Gramm"
+        );
+    }
+
+    #[test]
+    fn plural_rules() {
+        assert!(EN_US.is_singular(1.0));
+        assert!(!EN_US.is_singular(1.5));
+        assert!(FR.is_singular(1.5));
+        assert!(FR.is_singular(-0.5));
+        assert!(!FR.is_singular(2.0));
+        assert!(JA.is_singular(3.0));
+        assert_eq!(FR.name(LengthUnit::Meter, 1.5).to_string(), "mètre");
+        assert_eq!(DE.name(LengthUnit::Mile, 1.5).to_string(), "Meilen");
+    }
+
+    #[test]
+    fn find_locales() {
+        assert_eq!(find("de").unwrap().tag, "de");
+        assert_eq!(find("EN_gb").unwrap().tag, "en-GB");
+        assert_eq!(find("de-AT").unwrap().tag, "de");
+        assert_eq!(find("en").unwrap().tag, "en-US");
+        assert!(find("xx").is_none());
+    }
+
+    #[test]
+    fn register_locale() {
+        register(&SWISS_GERMAN);
+        assert_eq!(find("de-ch").unwrap().tag, "de-CH");
+        assert_eq!(find("de").unwrap().tag, "de");
+    }
+
+    #[test]
+    fn display() {
+        let length = Length::from_meters(1234.5);
+        assert_eq!(
+            length
+                .display()
+                .unit(LengthUnit::Meter)
+                .locale(&DE)
+                .to_string(),
+            "1234,5\u{00A0}m"
+        );
+        assert_eq!(
+            Volume::from_liters(2.0)
+                .display()
+                .unit(VolumeUnit::Liter)
+                .locale(&EN_GB)
+                .long_names()
+                .to_string(),
+            "2\u{00A0}litres"
+        );
+        assert_eq!(
+            Mass::from_grams(1.5)
+                .display()
+                .locale(&FR)
+                .long_names()
+                .precision(1)
+                .to_string(),
+            "1,5\u{00A0}gramme"
+        );
+        assert_eq!(
+            ::data::Data::from_kilooctets(3.5)
+                .display()
+                .unit(DataUnit::Kilooctet)
+                .locale(&FR)
+                .ascii_space()
+                .to_string(),
+            "3,5 ko"
+        );
+    }
+
+    #[test]
+    fn compound_display() {
+        let d = duration::from_hours_minutes_seconds(1, 23, 30.0).unwrap();
+        let units = &[DurationUnit::Hour, DurationUnit::Minute];
+        assert_eq!(
+            duration::display(&d)
+                .compound(units)
+                .precision(1)
+                .locale(&DE)
+                .long_names()
+                .ascii_space()
+                .to_string(),
+            "1 Stunde 23,5 Minuten"
+        );
+    }
+}
@@ -0,0 +1,992 @@
+//! Marking codes for resistors and capacitors.
+//!
+//! Through-hole resistors are marked with colour bands (IEC 60062): two or
+//! three digits, a multiplier, then a tolerance and sometimes a temperature
+//! coefficient. Surface-mount resistors are marked with three or four digit
+//! codes such as "472" (47 × 10² Ω), with "R" standing in for the decimal
+//! point in "4R7", or with EIA-96 codes such as "01C", which give the index
+//! of an E96 value and a multiplier letter. The BS 1852 codes often found in
+//! schematics and BOMs, such as "4K7" and "1M0", are also understood.
+//!
+//! Ceramic capacitors use the same three digit codes, in picofarads, with an
+//! optional tolerance letter: "104K" is 100 nF ± 10%.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::Resistance;
+//! use measurements::marking::{ColourBands, ResistorMarking};
+//!
+//! let bands: ColourBands = "yellow violet red gold".parse().unwrap();
+//! let marking = ResistorMarking::from_colour_bands(bands.as_slice()).unwrap();
+//! assert_eq!(marking.resistance(), Resistance::from_ohms(4700.0));
+//! assert_eq!(marking.tolerance(), Some(0.05));
+//! assert_eq!(marking.to_smd_code(3).unwrap().as_str(), "472");
+//! ```
+
+use super::e_series::ESeries;
+use super::{Capacitance, Resistance};
+use std::fmt;
+use std::str::FromStr;
+
+/// The colour of a band on a resistor.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Colour {
+    /// 0, ×1, 250 ppm/K
+    Black,
+    /// 1, ×10, ±1%, 100 ppm/K
+    Brown,
+    /// 2, ×100, ±2%, 50 ppm/K
+    Red,
+    /// 3, ×1k, ±0.05%, 15 ppm/K
+    Orange,
+    /// 4, ×10k, ±0.02%, 25 ppm/K
+    Yellow,
+    /// 5, ×100k, ±0.5%, 20 ppm/K
+    Green,
+    /// 6, ×1M, ±0.25%, 10 ppm/K
+    Blue,
+    /// 7, ×10M, ±0.1%, 5 ppm/K
+    Violet,
+    /// 8, ×100M, ±0.01%, 1 ppm/K
+    Grey,
+    /// 9, ×1G
+    White,
+    /// ×0.1, ±5%
+    Gold,
+    /// ×0.01, ±10%
+    Silver,
+}
+
+/// Every colour, with its name and IEC 60062 letter code.
+const COLOURS: [(Colour, &str, &str); 12] = [
+    (Colour::Black, "black", "BK"),
+    (Colour::Brown, "brown", "BN"),
+    (Colour::Red, "red", "RD"),
+    (Colour::Orange, "orange", "OG"),
+    (Colour::Yellow, "yellow", "YE"),
+    (Colour::Green, "green", "GN"),
+    (Colour::Blue, "blue", "BU"),
+    (Colour::Violet, "violet", "VT"),
+    (Colour::Grey, "grey", "GY"),
+    (Colour::White, "white", "WH"),
+    (Colour::Gold, "gold", "GD"),
+    (Colour::Silver, "silver", "SR"),
+];
+
+impl Colour {
+    /// The digit this colour stands for in a digit band, if any.
+    pub fn digit(&self) -> Option<u8> {
+        match *self {
+            Colour::Gold | Colour::Silver => None,
+            colour => Some(colour as u8),
+        }
+    }
+
+    /// The power of ten this colour stands for in the multiplier band.
+    pub fn multiplier_exponent(&self) -> i32 {
+        match *self {
+            Colour::Gold => -1,
+            Colour::Silver => -2,
+            colour => colour as i32,
+        }
+    }
+
+    /// The tolerance this colour stands for in the tolerance band, as a
+    /// fraction (so 0.05 for gold), if any.
+    pub fn tolerance(&self) -> Option<f64> {
+        match *self {
+            Colour::Brown => Some(0.01),
+            Colour::Red => Some(0.02),
+            Colour::Orange => Some(0.0005),
+            Colour::Yellow => Some(0.0002),
+            Colour::Green => Some(0.005),
+            Colour::Blue => Some(0.0025),
+            Colour::Violet => Some(0.001),
+            Colour::Grey => Some(0.0001),
+            Colour::Gold => Some(0.05),
+            Colour::Silver => Some(0.1),
+            Colour::Black | Colour::White => None,
+        }
+    }
+
+    /// The temperature coefficient this colour stands for in the sixth
+    /// band, in ppm/K, if any.
+    pub fn temperature_coefficient(&self) -> Option<f64> {
+        match *self {
+            Colour::Black => Some(250.0),
+            Colour::Brown => Some(100.0),
+            Colour::Red => Some(50.0),
+            Colour::Orange => Some(15.0),
+            Colour::Yellow => Some(25.0),
+            Colour::Green => Some(20.0),
+            Colour::Blue => Some(10.0),
+            Colour::Violet => Some(5.0),
+            Colour::Grey => Some(1.0),
+            Colour::White | Colour::Gold | Colour::Silver => None,
+        }
+    }
+
+    /// The name of this colour, such as "violet".
+    pub fn name(&self) -> &'static str {
+        COLOURS[*self as usize].1
+    }
+
+    /// The colour of a digit band.
+    fn from_digit(digit: u64) -> Colour {
+        COLOURS[digit as usize].0
+    }
+}
+
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Colour {
+    type Err = MarkingError;
+
+    /// Parse a colour from its name or IEC 60062 letter code, ignoring case.
+    /// "gray" and "purple" are also accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("gray") {
+            return Ok(Colour::Grey);
+        }
+        if s.eq_ignore_ascii_case("purple") {
+            return Ok(Colour::Violet);
+        }
+        COLOURS
+            .iter()
+            .find(|&&(_, name, code)| s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(code))
+            .map(|&(colour, _, _)| colour)
+            .ok_or(MarkingError::InvalidColour)
+    }
+}
+
+/// The colour bands on a resistor, read from left to right.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ColourBands {
+    bands: [Colour; 6],
+    len: usize,
+}
+
+impl ColourBands {
+    /// The bands, from left to right.
+    pub fn as_slice(&self) -> &[Colour] {
+        &self.bands[..self.len]
+    }
+
+    fn new() -> ColourBands {
+        ColourBands {
+            bands: [Colour::Black; 6],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, colour: Colour) -> Result<(), MarkingError> {
+        if self.len == self.bands.len() {
+            return Err(MarkingError::InvalidBandCount);
+        }
+        self.bands[self.len] = colour;
+        self.len += 1;
+        Ok(())
+    }
+}
+
+impl fmt::Display for ColourBands {
+    /// Writes the names of the bands, separated by spaces.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, band) in self.as_slice().iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(band.name())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ColourBands {
+    type Err = MarkingError;
+
+    /// Parse up to six colours separated by spaces, hyphens or commas, such
+    /// as "yellow violet red gold" or "YE-VT-RD-GD".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bands = ColourBands::new();
+        for name in s
+            .split(|c: char| c.is_whitespace() || c == '-' || c == ',')
+            .filter(|name| !name.is_empty())
+        {
+            bands.push(name.parse()?)?;
+        }
+        Ok(bands)
+    }
+}
+
+/// A short marking code, such as "472", "4R7", "01C" or "104K".
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MarkingCode {
+    bytes: [u8; 5],
+    len: usize,
+}
+
+impl MarkingCode {
+    /// The code as a string.
+    pub fn as_str(&self) -> &str {
+        ::std::str::from_utf8(&self.bytes[..self.len]).unwrap_or("")
+    }
+
+    fn new() -> MarkingCode {
+        MarkingCode {
+            bytes: [0; 5],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, c: u8) {
+        self.bytes[self.len] = c;
+        self.len += 1;
+    }
+
+    /// Push the digits of `n`, which has `digits` digits.
+    fn push_digits(&mut self, n: u64, digits: u32) {
+        for i in (0..digits).rev() {
+            self.push(b'0' + (n / pow10_int(i) % 10) as u8);
+        }
+    }
+}
+
+impl fmt::Display for MarkingCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The value, tolerance and temperature coefficient marked on a resistor.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ResistorMarking {
+    resistance: Resistance,
+    tolerance: Option<f64>,
+    temperature_coefficient: Option<f64>,
+}
+
+impl ResistorMarking {
+    /// Create a new ResistorMarking, with the tolerance as a fraction and the
+    /// temperature coefficient in ppm/K.
+    pub fn new(
+        resistance: Resistance,
+        tolerance: Option<f64>,
+        temperature_coefficient: Option<f64>,
+    ) -> Self {
+        ResistorMarking {
+            resistance,
+            tolerance,
+            temperature_coefficient,
+        }
+    }
+
+    /// Read the colour bands on a resistor. Three bands give two digits and
+    /// a multiplier, with an implied tolerance of ±20%; four bands add a
+    /// tolerance; five bands have three digits; and six bands add a
+    /// temperature coefficient. A single black band is a zero-ohm link.
+    pub fn from_colour_bands(bands: &[Colour]) -> Result<Self, MarkingError> {
+        let digits = match bands.len() {
+            1 if bands[0] == Colour::Black => {
+                return Ok(ResistorMarking::new(Resistance::from_ohms(0.0), None, None))
+            }
+            3 | 4 => 2,
+            5 | 6 => 3,
+            _ => return Err(MarkingError::InvalidBandCount),
+        };
+        let mut significand = 0;
+        for band in &bands[..digits] {
+            let digit = band.digit().ok_or(MarkingError::InvalidBand)?;
+            significand = significand * 10 + u64::from(digit);
+        }
+        let ohms = scale(significand, bands[digits].multiplier_exponent());
+        let tolerance = match bands.get(digits + 1) {
+            Some(band) => Some(band.tolerance().ok_or(MarkingError::InvalidBand)?),
+            None => Some(0.2),
+        };
+        let temperature_coefficient = match bands.get(digits + 2) {
+            Some(band) => Some(
+                band.temperature_coefficient()
+                    .ok_or(MarkingError::InvalidBand)?,
+            ),
+            None => None,
+        };
+        Ok(ResistorMarking::new(
+            Resistance::from_ohms(ohms),
+            tolerance,
+            temperature_coefficient,
+        ))
+    }
+
+    /// Read a surface-mount resistor code: three or four digits such as
+    /// "472" or "4702", a code with "R", "K" or "M" in place of the decimal
+    /// point such as "4R7" or "4K7", or an EIA-96 code such as "01C". "R" is
+    /// not accepted as an EIA-96 multiplier, as "47R" means 47 Ω.
+    pub fn from_smd_code(code: &str) -> Result<Self, MarkingError> {
+        let code = code.trim();
+        let bytes = code.as_bytes();
+        let ohms = if bytes.len() == 3
+            && bytes[0].is_ascii_digit()
+            && bytes[1].is_ascii_digit()
+            && eia96_exponent(bytes[2]).is_some()
+        {
+            let index = usize::from((bytes[0] - b'0') * 10 + (bytes[1] - b'0'));
+            let exponent = eia96_exponent(bytes[2]).unwrap_or(0);
+            scale(eia96_value(index)?, exponent)
+        } else {
+            decode(code, &[(b'R', 0), (b'K', 3), (b'M', 6)], false)?
+        };
+        Ok(ResistorMarking::new(
+            Resistance::from_ohms(ohms),
+            None,
+            None,
+        ))
+    }
+
+    /// The resistance.
+    pub fn resistance(&self) -> Resistance {
+        self.resistance
+    }
+
+    /// The tolerance, as a fraction, if one is marked.
+    pub fn tolerance(&self) -> Option<f64> {
+        self.tolerance
+    }
+
+    /// The temperature coefficient, in ppm/K, if one is marked.
+    pub fn temperature_coefficient(&self) -> Option<f64> {
+        self.temperature_coefficient
+    }
+
+    /// The colour bands for this marking, with `count` from 3 to 6 bands.
+    /// Four and five bands need a tolerance with a colour; six bands also
+    /// need a temperature coefficient with a colour. A zero-ohm link is a
+    /// single black band.
+    pub fn to_colour_bands(&self, count: usize) -> Result<ColourBands, MarkingError> {
+        let mut bands = ColourBands::new();
+        if self.resistance.as_ohms() == 0.0 {
+            bands.push(Colour::Black)?;
+            return Ok(bands);
+        }
+        let digits = match count {
+            3 | 4 => 2,
+            5 | 6 => 3,
+            _ => return Err(MarkingError::InvalidBandCount),
+        };
+        let (significand, exponent) = significant_digits(self.resistance.as_ohms(), digits)?;
+        if !(-2..=9).contains(&exponent) {
+            return Err(MarkingError::NotRepresentable);
+        }
+        for i in (0..digits).rev() {
+            bands.push(Colour::from_digit(significand / pow10_int(i) % 10))?;
+        }
+        bands.push(match exponent {
+            -2 => Colour::Silver,
+            -1 => Colour::Gold,
+            exponent => Colour::from_digit(exponent as u64),
+        })?;
+        if count == 3 {
+            return match self.tolerance {
+                None => Ok(bands),
+                Some(tolerance) if approx_eq(tolerance, 0.2) => Ok(bands),
+                Some(_) => Err(MarkingError::UnsupportedTolerance),
+            };
+        }
+        let tolerance = self.tolerance.ok_or(MarkingError::UnsupportedTolerance)?;
+        bands.push(
+            find_colour(|colour| colour.tolerance(), tolerance)
+                .ok_or(MarkingError::UnsupportedTolerance)?,
+        )?;
+        if count == 6 {
+            let coefficient = self
+                .temperature_coefficient
+                .ok_or(MarkingError::UnsupportedTemperatureCoefficient)?;
+            bands.push(
+                find_colour(|colour| colour.temperature_coefficient(), coefficient)
+                    .ok_or(MarkingError::UnsupportedTemperatureCoefficient)?,
+            )?;
+        }
+        Ok(bands)
+    }
+
+    /// The surface-mount code for this resistance, with `digits` of 3 or 4.
+    /// Values below 10 Ω (or 100 Ω with four digits) use "R" for the
+    /// decimal point, as in "4R7".
+    pub fn to_smd_code(&self, digits: u32) -> Result<MarkingCode, MarkingError> {
+        if digits != 3 && digits != 4 {
+            return Err(MarkingError::InvalidCode);
+        }
+        encode(self.resistance.as_ohms(), digits)
+    }
+
+    /// The EIA-96 code for this resistance, which must be an E96 value from
+    /// 0.1 Ω to 97.6 MΩ.
+    pub fn to_eia96_code(&self) -> Result<MarkingCode, MarkingError> {
+        let ohms = self.resistance.as_ohms();
+        if ohms <= 0.0 {
+            return Err(MarkingError::NotRepresentable);
+        }
+        let (significand, exponent) = significant_digits(ohms, 3)?;
+        let index = (1..=96)
+            .find(|&index| eia96_value(index) == Ok(significand))
+            .ok_or(MarkingError::NotRepresentable)?;
+        let letter = EIA96_MULTIPLIERS
+            .iter()
+            .find(|&&(_, e)| e == exponent)
+            .ok_or(MarkingError::NotRepresentable)?
+            .0;
+        let mut code = MarkingCode::new();
+        code.push_digits(index as u64, 2);
+        code.push(letter);
+        Ok(code)
+    }
+}
+
+impl From<Resistance> for ResistorMarking {
+    fn from(resistance: Resistance) -> Self {
+        ResistorMarking::new(resistance, None, None)
+    }
+}
+
+impl FromStr for ResistorMarking {
+    type Err = MarkingError;
+
+    /// Parse either a surface-mount code, such as "4R7" or "01C", or a
+    /// sequence of colour bands, such as "brown black orange gold".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.len() <= 4 && !s.contains(|c: char| c.is_whitespace() || c == '-' || c == ',') {
+            ResistorMarking::from_smd_code(s)
+        } else {
+            ResistorMarking::from_colour_bands(s.parse::<ColourBands>()?.as_slice())
+        }
+    }
+}
+
+/// The value and tolerance marked on a capacitor.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CapacitorMarking {
+    capacitance: Capacitance,
+    tolerance: Option<f64>,
+}
+
+/// The capacitor tolerance letters, and the tolerances they stand for.
+const CAPACITOR_TOLERANCES: [(u8, f64); 5] = [
+    (b'F', 0.01),
+    (b'G', 0.02),
+    (b'J', 0.05),
+    (b'K', 0.1),
+    (b'M', 0.2),
+];
+
+impl CapacitorMarking {
+    /// Create a new CapacitorMarking, with the tolerance as a fraction.
+    pub fn new(capacitance: Capacitance, tolerance: Option<f64>) -> Self {
+        CapacitorMarking {
+            capacitance,
+            tolerance,
+        }
+    }
+
+    /// Read a capacitor code: three digits in picofarads such as "104", or a
+    /// code with "R" or "p", "n" or "u" in place of the decimal point such
+    /// as "4R7" or "4n7", optionally followed by a tolerance letter (F, G,
+    /// J, K or M). A final digit of 8 or 9 multiplies by 0.01 or 0.1.
+    pub fn from_code(code: &str) -> Result<Self, MarkingError> {
+        let code = code.trim();
+        let (value, tolerance) = match code.as_bytes().last() {
+            Some(&letter) if code.len() > 1 => {
+                match CAPACITOR_TOLERANCES.iter().find(|&&(l, _)| l == letter) {
+                    Some(&(_, tolerance)) => (&code[..code.len() - 1], Some(tolerance)),
+                    None => (code, None),
+                }
+            }
+            _ => (code, None),
+        };
+        let markers = [(b'R', 0), (b'P', 0), (b'N', 3), (b'U', 6)];
+        let picofarads = decode(value, &markers, true)?;
+        Ok(CapacitorMarking::new(
+            Capacitance::from_picofarads(picofarads),
+            tolerance,
+        ))
+    }
+
+    /// The capacitance.
+    pub fn capacitance(&self) -> Capacitance {
+        self.capacitance
+    }
+
+    /// The tolerance, as a fraction, if one is marked.
+    pub fn tolerance(&self) -> Option<f64> {
+        self.tolerance
+    }
+
+    /// The three digit code for this capacitance, followed by a tolerance
+    /// letter if there is a tolerance. Values below 10 pF use "R" for the
+    /// decimal point, as in "4R7".
+    pub fn to_code(&self) -> Result<MarkingCode, MarkingError> {
+        let mut code = encode(self.capacitance.as_picofarads(), 3)?;
+        if let Some(tolerance) = self.tolerance {
+            let &(letter, _) = CAPACITOR_TOLERANCES
+                .iter()
+                .find(|&&(_, t)| approx_eq(t, tolerance))
+                .ok_or(MarkingError::UnsupportedTolerance)?;
+            code.push(letter);
+        }
+        Ok(code)
+    }
+}
+
+impl FromStr for CapacitorMarking {
+    type Err = MarkingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CapacitorMarking::from_code(s)
+    }
+}
+
+/// The error returned when a marking cannot be read or written.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MarkingError {
+    /// A colour name is not known.
+    InvalidColour,
+    /// There are not 3 to 6 colour bands (or a single black band).
+    InvalidBandCount,
+    /// A band has a colour which means nothing in its position, such as a
+    /// gold digit.
+    InvalidBand,
+    /// A code is not in any of the known forms.
+    InvalidCode,
+    /// The value cannot be written exactly in the requested form.
+    NotRepresentable,
+    /// The tolerance has no colour or letter.
+    UnsupportedTolerance,
+    /// The temperature coefficient has no colour.
+    UnsupportedTemperatureCoefficient,
+}
+
+impl fmt::Display for MarkingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match *self {
+            MarkingError::InvalidColour => "unknown band colour",
+            MarkingError::InvalidBandCount => "a resistor has 3 to 6 colour bands",
+            MarkingError::InvalidBand => "band colour not allowed in this position",
+            MarkingError::InvalidCode => "invalid marking code",
+            MarkingError::NotRepresentable => "value cannot be written in this marking",
+            MarkingError::UnsupportedTolerance => "tolerance has no marking",
+            MarkingError::UnsupportedTemperatureCoefficient => {
+                "temperature coefficient has no marking"
+            }
+        };
+        f.write_str(description)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl ::std::error::Error for MarkingError {}
+
+/// The EIA-96 multiplier letters, and the powers of ten they stand for.
+const EIA96_MULTIPLIERS: [(u8, i32); 9] = [
+    (b'Z', -3),
+    (b'Y', -2),
+    (b'X', -1),
+    (b'A', 0),
+    (b'B', 1),
+    (b'C', 2),
+    (b'D', 3),
+    (b'E', 4),
+    (b'F', 5),
+];
+
+/// The power of ten an EIA-96 multiplier letter stands for, including the
+/// alternative letters S (for X) and H (for B).
+fn eia96_exponent(letter: u8) -> Option<i32> {
+    let letter = match letter.to_ascii_uppercase() {
+        b'S' => b'X',
+        b'H' => b'B',
+        letter => letter,
+    };
+    EIA96_MULTIPLIERS
+        .iter()
+        .find(|&&(l, _)| l == letter)
+        .map(|&(_, exponent)| exponent)
+}
+
+/// The three significant digits of the E96 value with the given EIA-96
+/// index, from 1 to 96.
+fn eia96_value(index: usize) -> Result<u64, MarkingError> {
+    if !(1..=96).contains(&index) {
+        return Err(MarkingError::InvalidCode);
+    }
+    let value = ESeries::E96
+        .values()
+        .nth(index - 1)
+        .ok_or(MarkingError::InvalidCode)?;
+    Ok((value * 100.0 + 0.5) as u64)
+}
+
+/// Decode a code of digits followed by a power of ten, such as "472", or
+/// of digits with one of `markers` in place of the decimal point, such as
+/// "4R7". Each marker also multiplies by a power of ten. For capacitors, a
+/// final digit of 8 or 9 stands for 10⁻² or 10⁻¹.
+fn decode(code: &str, markers: &[(u8, i32)], capacitor: bool) -> Result<f64, MarkingError> {
+    let bytes = code.as_bytes();
+    if bytes.is_empty() || bytes.len() > 4 {
+        return Err(MarkingError::InvalidCode);
+    }
+    if bytes.iter().all(|&b| b == b'0') {
+        return Ok(0.0);
+    }
+    let marker = bytes.iter().enumerate().find_map(|(i, &b)| {
+        markers
+            .iter()
+            .find(|&&(m, _)| m == b.to_ascii_uppercase())
+            .map(|&(_, exponent)| (i, exponent))
+    });
+    let mut significand = 0;
+    let mut exponent = 0;
+    match marker {
+        Some((position, marker_exponent)) => {
+            for (i, &b) in bytes.iter().enumerate() {
+                if i == position {
+                    continue;
+                }
+                if !b.is_ascii_digit() {
+                    return Err(MarkingError::InvalidCode);
+                }
+                significand = significand * 10 + u64::from(b - b'0');
+                if i > position {
+                    exponent -= 1;
+                }
+            }
+            if bytes.len() < 2 {
+                return Err(MarkingError::InvalidCode);
+            }
+            exponent += marker_exponent;
+        }
+        None => {
+            if bytes.len() < 3 || !bytes.iter().all(u8::is_ascii_digit) {
+                return Err(MarkingError::InvalidCode);
+            }
+            for &b in &bytes[..bytes.len() - 1] {
+                significand = significand * 10 + u64::from(b - b'0');
+            }
+            exponent = match bytes[bytes.len() - 1] - b'0' {
+                8 if capacitor => -2,
+                9 if capacitor => -1,
+                digit => i32::from(digit),
+            };
+        }
+    }
+    Ok(scale(significand, exponent))
+}
+
+/// Encode a value as a code of `digits - 1` significant digits followed by
+/// a power of ten, using "R" for the decimal point when the power would be
+/// negative.
+fn encode(value: f64, digits: u32) -> Result<MarkingCode, MarkingError> {
+    let mut code = MarkingCode::new();
+    if value == 0.0 {
+        code.push_digits(0, digits);
+        return Ok(code);
+    }
+    if value < 0.0 {
+        return Err(MarkingError::NotRepresentable);
+    }
+    let significant = digits - 1;
+    let (significand, exponent) = significant_digits(value, significant)?;
+    if exponent > 9 {
+        return Err(MarkingError::NotRepresentable);
+    }
+    if exponent >= 0 {
+        code.push_digits(significand, significant);
+        code.push(b'0' + exponent as u8);
+        return Ok(code);
+    }
+    // The number of digits before the decimal point
+    let whole = significant as i32 + exponent;
+    if whole < 0 {
+        return Err(MarkingError::NotRepresentable);
+    }
+    let fraction = (-exponent) as u32;
+    code.push_digits(significand / pow10_int(fraction), whole as u32);
+    code.push(b'R');
+    code.push_digits(significand % pow10_int(fraction), fraction);
+    Ok(code)
+}
+
+/// Split a positive value into a significand of exactly `digits` digits and
+/// a power of ten, or fail if that would lose precision.
+fn significant_digits(value: f64, digits: u32) -> Result<(u64, i32), MarkingError> {
+    if !(value > 0.0 && value.is_finite()) {
+        return Err(MarkingError::NotRepresentable);
+    }
+    let low = pow10_int(digits - 1) as f64;
+    let mut exponent = 0;
+    while value / power_of_ten(exponent) >= low * 10.0 {
+        exponent += 1;
+        if exponent > MAX_EXPONENT {
+            return Err(MarkingError::NotRepresentable);
+        }
+    }
+    while value / power_of_ten(exponent) < low {
+        exponent -= 1;
+        if exponent < -MAX_EXPONENT {
+            return Err(MarkingError::NotRepresentable);
+        }
+    }
+    let significand = (value / power_of_ten(exponent) + 0.5) as u64;
+    if !approx_eq(scale(significand, exponent), value) {
+        return Err(MarkingError::NotRepresentable);
+    }
+    Ok((significand, exponent))
+}
+
+/// The colour for which `property` is `value`, if any.
+fn find_colour<F: Fn(Colour) -> Option<f64>>(property: F, value: f64) -> Option<Colour> {
+    COLOURS
+        .iter()
+        .map(|&(colour, _, _)| colour)
+        .find(|&colour| property(colour).is_some_and(|v| approx_eq(v, value)))
+}
+
+fn approx_eq(a: f64, b: f64) -> bool {
+    let difference = if a > b { a - b } else { b - a };
+    difference <= 1e-9 * if b > 0.0 { b } else { -b }
+}
+
+/// No marking uses a power of ten beyond this in either direction, and
+/// values which would need one are rejected before `pow10_int` overflows.
+const MAX_EXPONENT: i32 = 12;
+
+const POWERS_OF_TEN: [u64; 20] = [
+    1,
+    10,
+    100,
+    1_000,
+    10_000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+    1_000_000_000,
+    10_000_000_000,
+    100_000_000_000,
+    1_000_000_000_000,
+    10_000_000_000_000,
+    100_000_000_000_000,
+    1_000_000_000_000_000,
+    10_000_000_000_000_000,
+    100_000_000_000_000_000,
+    1_000_000_000_000_000_000,
+    10_000_000_000_000_000_000,
+];
+
+fn pow10_int(exponent: u32) -> u64 {
+    POWERS_OF_TEN[exponent as usize]
+}
+
+fn power_of_ten(exponent: i32) -> f64 {
+    scale(1, exponent)
+}
+
+/// `significand` × 10^`exponent`, dividing for negative exponents so that
+/// values such as 4.7 come out exactly.
+fn scale(significand: u64, exponent: i32) -> f64 {
+    let power = pow10_int(exponent.unsigned_abs()) as f64;
+    if exponent < 0 {
+        significand as f64 / power
+    } else {
+        significand as f64 * power
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use marking::*;
+    use test_utils::assert_almost_eq;
+
+    fn bands(s: &str) -> ResistorMarking {
+        read(s).unwrap()
+    }
+
+    fn smd(s: &str) -> f64 {
+        ResistorMarking::from_smd_code(s)
+            .unwrap()
+            .resistance()
+            .as_ohms()
+    }
+
+    #[test]
+    fn colours() {
+        assert_eq!("Violet".parse(), Ok(Colour::Violet));
+        assert_eq!("gray".parse(), Ok(Colour::Grey));
+        assert_eq!("BN".parse(), Ok(Colour::Brown));
+        assert_eq!("mauve".parse::<Colour>(), Err(MarkingError::InvalidColour));
+        assert_eq!(Colour::Orange.digit(), Some(3));
+        assert_eq!(Colour::Gold.digit(), None);
+        assert_eq!(Colour::Silver.multiplier_exponent(), -2);
+    }
+
+    #[test]
+    fn read_bands() {
+        let m = bands("yellow violet red gold");
+        assert_almost_eq(m.resistance().as_ohms(), 4700.0);
+        assert_eq!(m.tolerance(), Some(0.05));
+        assert_eq!(m.temperature_coefficient(), None);
+
+        let m = bands("brown black black brown brown");
+        assert_almost_eq(m.resistance().as_ohms(), 1000.0);
+        assert_eq!(m.tolerance(), Some(0.01));
+
+        let m = bands("BN-BK-BK-RD-BN-RD");
+        assert_almost_eq(m.resistance().as_kiloohms(), 10.0);
+        assert_eq!(m.temperature_coefficient(), Some(50.0));
+
+        let m = bands("green blue gold");
+        assert_almost_eq(m.resistance().as_ohms(), 5.6);
+        assert_eq!(m.tolerance(), Some(0.2));
+
+        assert_eq!(bands("black").resistance().as_ohms(), 0.0);
+    }
+
+    fn read(s: &str) -> Result<ResistorMarking, MarkingError> {
+        ResistorMarking::from_colour_bands(s.parse::<ColourBands>()?.as_slice())
+    }
+
+    #[test]
+    fn invalid_bands() {
+        assert_eq!(read("red red"), Err(MarkingError::InvalidBandCount));
+        assert_eq!(read("gold red red gold"), Err(MarkingError::InvalidBand));
+        assert_eq!(read("red red red black"), Err(MarkingError::InvalidBand));
+        assert_eq!(
+            read("red red red red red red red"),
+            Err(MarkingError::InvalidBandCount)
+        );
+    }
+
+    #[test]
+    fn write_bands() {
+        let m = ResistorMarking::new(Resistance::from_ohms(4700.0), Some(0.05), None);
+        assert_eq!(
+            m.to_colour_bands(4).unwrap().to_string(),
+            "yellow violet red gold"
+        );
+        assert_eq!(
+            m.to_colour_bands(5).unwrap().to_string(),
+            "yellow violet black brown gold"
+        );
+        assert_eq!(
+            m.to_colour_bands(6),
+            Err(MarkingError::UnsupportedTemperatureCoefficient)
+        );
+        let m = ResistorMarking::new(Resistance::from_ohms(4.7), Some(0.01), Some(100.0));
+        assert_eq!(
+            m.to_colour_bands(6).unwrap().to_string(),
+            "yellow violet black silver brown brown"
+        );
+        let m = ResistorMarking::from(Resistance::from_ohms(4750.0));
+        assert_eq!(m.to_colour_bands(3), Err(MarkingError::NotRepresentable));
+        assert_eq!(
+            m.to_colour_bands(5),
+            Err(MarkingError::UnsupportedTolerance)
+        );
+        let m = ResistorMarking::from(Resistance::from_ohms(1e-25));
+        assert_eq!(m.to_colour_bands(3), Err(MarkingError::NotRepresentable));
+    }
+
+    #[test]
+    fn read_smd() {
+        assert_almost_eq(smd("472"), 4700.0);
+        assert_almost_eq(smd("4702"), 47_000.0);
+        assert_almost_eq(smd("4R7"), 4.7);
+        assert_almost_eq(smd("R47"), 0.47);
+        assert_almost_eq(smd("47R"), 47.0);
+        assert_almost_eq(smd("4K7"), 4700.0);
+        assert_almost_eq(smd("1M0"), 1e6);
+        assert_almost_eq(smd("01C"), 10_000.0);
+        assert_almost_eq(smd("68X"), 49.9);
+        assert_almost_eq(smd("96F"), 97.6e6);
+        assert_eq!(smd("000"), 0.0);
+        assert_eq!(smd("0R"), 0.0);
+        assert_eq!(
+            ResistorMarking::from_smd_code("97A"),
+            Err(MarkingError::InvalidCode)
+        );
+        assert_eq!(
+            ResistorMarking::from_smd_code("4R7R"),
+            Err(MarkingError::InvalidCode)
+        );
+    }
+
+    #[test]
+    fn write_smd() {
+        let code = |ohms: f64, digits: u32| {
+            ResistorMarking::from(Resistance::from_ohms(ohms))
+                .to_smd_code(digits)
+                .map(|code| code.to_string())
+        };
+        assert_eq!(code(4700.0, 3).unwrap(), "472");
+        assert_eq!(code(47.0, 3).unwrap(), "470");
+        assert_eq!(code(4.7, 3).unwrap(), "4R7");
+        assert_eq!(code(0.47, 3).unwrap(), "R47");
+        assert_eq!(code(10_200.0, 4).unwrap(), "1022");
+        assert_eq!(code(47.5, 4).unwrap(), "47R5");
+        assert_eq!(code(0.0, 3).unwrap(), "000");
+        assert_eq!(code(10_200.0, 3), Err(MarkingError::NotRepresentable));
+        assert_eq!(code(0.047, 3), Err(MarkingError::NotRepresentable));
+        assert_eq!(code(1e25, 3), Err(MarkingError::NotRepresentable));
+        assert_eq!(code(1e-25, 3), Err(MarkingError::NotRepresentable));
+        assert_eq!(code(1e300, 4), Err(MarkingError::NotRepresentable));
+    }
+
+    #[test]
+    fn eia96() {
+        let code = |ohms: f64| {
+            ResistorMarking::from(Resistance::from_ohms(ohms))
+                .to_eia96_code()
+                .map(|code| code.to_string())
+        };
+        assert_eq!(code(10_000.0).unwrap(), "01C");
+        assert_eq!(code(49.9).unwrap(), "68X");
+        assert_eq!(code(97.6e6).unwrap(), "96F");
+        assert_eq!(code(4700.0), Err(MarkingError::NotRepresentable));
+        assert_eq!(code(1e25), Err(MarkingError::NotRepresentable));
+    }
+
+    #[test]
+    fn from_str() {
+        let m: ResistorMarking = "4R7".parse().unwrap();
+        assert_almost_eq(m.resistance().as_ohms(), 4.7);
+        let m: ResistorMarking = "brown black orange gold".parse().unwrap();
+        assert_almost_eq(m.resistance().as_kiloohms(), 10.0);
+    }
+
+    #[test]
+    fn capacitors() {
+        let m: CapacitorMarking = "104K".parse().unwrap();
+        assert_almost_eq(m.capacitance().as_nanofarads(), 100.0);
+        assert_eq!(m.tolerance(), Some(0.1));
+        let m: CapacitorMarking = "4n7".parse().unwrap();
+        assert_almost_eq(m.capacitance().as_nanofarads(), 4.7);
+        let m: CapacitorMarking = "159".parse().unwrap();
+        assert_almost_eq(m.capacitance().as_picofarads(), 1.5);
+        assert_eq!(
+            CapacitorMarking::from_code("22"),
+            Err(MarkingError::InvalidCode)
+        );
+
+        let code = CapacitorMarking::new(Capacitance::from_nanofarads(4.7), Some(0.05)).to_code();
+        assert_eq!(code.unwrap().as_str(), "472J");
+        let code = CapacitorMarking::new(Capacitance::from_picofarads(2.2), None).to_code();
+        assert_eq!(code.unwrap().as_str(), "2R2");
+        let code = CapacitorMarking::new(Capacitance::from_farads(1e20), None).to_code();
+        assert_eq!(code, Err(MarkingError::NotRepresentable));
+    }
+}
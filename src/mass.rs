@@ -1,6 +1,8 @@
 //! Types and constants for handling masses.
 
+use super::display::split_whole;
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 
 // Constants, metric
 
@@ -131,6 +133,16 @@ impl Mass {
         Self::from_kilograms(stones / KILOGRAM_STONES_FACTOR)
     }
 
+    /// Create a Mass from stones and pounds, as in 11 st 4 lb.
+    pub fn from_stones_pounds(stones: f64, pounds: f64) -> Self {
+        Self::from_pounds(stones * 14.0 + pounds)
+    }
+
+    /// Create a Mass from pounds and ounces, as in 7 lb 8 oz.
+    pub fn from_pounds_ounces(pounds: f64, ounces: f64) -> Self {
+        Self::from_ounces(pounds * 16.0 + ounces)
+    }
+
     /// Create a Mass from a floating point value in short (US) tons
     pub fn from_short_tons(short_tons: f64) -> Self {
         Self::from_kilograms(short_tons / KILOGRAM_SHORT_TONS_FACTOR)
@@ -211,6 +223,18 @@ impl Mass {
         self.kilograms * KILOGRAM_STONES_FACTOR
     }
 
+    /// Convert this Mass into whole stones and the pounds left over. Both
+    /// have the sign of the mass.
+    pub fn as_stones_pounds(&self) -> (f64, f64) {
+        split_whole(self.as_pounds(), 14.0)
+    }
+
+    /// Convert this Mass into whole pounds and the ounces left over. Both
+    /// have the sign of the mass.
+    pub fn as_pounds_ounces(&self) -> (f64, f64) {
+        split_whole(self.as_ounces(), 16.0)
+    }
+
     /// Convert this Mass to a floating point value in short (US) Tons
     pub fn as_short_tons(&self) -> f64 {
         self.kilograms * KILOGRAM_SHORT_TONS_FACTOR
@@ -253,6 +277,95 @@ impl Measurement for Mass {
 
 implement_measurement! { Mass }
 
+implement_units! {
+    Mass, MassUnit {
+        Nanogram => ("ng", "nanogram", "nanograms", 1.0 / KILOGRAM_NANOGRAM_FACTOR) in Si,
+        Microgram => ("\u{00B5}g", "microgram", "micrograms", 1.0 / KILOGRAM_MICROGRAM_FACTOR) in Si,
+        Milligram => ("mg", "milligram", "milligrams", 1.0 / KILOGRAM_MILLIGRAM_FACTOR) in Si,
+        Gram => ("g", "gram", "grams", 1.0 / KILOGRAM_GRAM_FACTOR) in Si,
+        Kilogram => ("kg", "kilogram", "kilograms", 1.0) in Si,
+        Tonne => ("t", "tonne", "tonnes", 1.0 / KILOGRAM_TONNE_FACTOR) in Si,
+        Carat => ("ct", "carat", "carats", 1.0 / KILOGRAM_CARAT_FACTOR),
+        Grain => ("gr", "grain", "grains", 1.0 / KILOGRAM_GRAINS_FACTOR),
+        Pennyweight => ("dwt", "pennyweight", "pennyweights", 1.0 / KILOGRAM_PENNYWEIGHTS_FACTOR),
+        Ounce => ("oz", "ounce", "ounces", 1.0 / KILOGRAM_OUNCES_FACTOR) in UsCustomary | Imperial,
+        TroyOunce => ("oz t", "troy ounce", "troy ounces", 1.0 / KILOGRAM_TROY_OUNCES_FACTOR),
+        Pound => ("lb", "pound", "pounds", 1.0 / KILOGRAM_POUNDS_FACTOR) in UsCustomary | Imperial,
+        TroyPound => ("lb t", "troy pound", "troy pounds", 1.0 / KILOGRAM_TROY_POUNDS_FACTOR),
+        Stone => ("st", "stone", "stones", 1.0 / KILOGRAM_STONES_FACTOR) in Imperial,
+        ShortTon => ("short tn", "short ton", "short tons", 1.0 / KILOGRAM_SHORT_TONS_FACTOR) in UsCustomary,
+        LongTon => ("long tn", "long ton", "long tons", 1.0 / KILOGRAM_LONG_TONS_FACTOR) in Imperial,
+    }
+}
+
+/// The unit names understood when parsing a `Mass`.
+const UNIT_NAMES: &[UnitConstructor<'static, Mass>] = &[
+    ("ng", |v| Mass::from_micrograms(v / 1000.0)),
+    ("\u{00B5}g", Mass::from_micrograms),
+    ("mg", Mass::from_milligrams),
+    ("g", Mass::from_grams),
+    ("kg", Mass::from_kilograms),
+    ("tonnes", Mass::from_tonnes),
+    ("thousand tonnes", |v| Mass::from_tonnes(v * 1e3)),
+    ("million tonnes", |v| Mass::from_tonnes(v * 1e6)),
+    ("t", Mass::from_tonnes),
+    ("ct", Mass::from_carats),
+    ("gr", Mass::from_grains),
+    ("dwt", Mass::from_pennyweights),
+    ("oz", Mass::from_ounces),
+    ("oz t", Mass::from_troy_ounces),
+    ("ozt", Mass::from_troy_ounces),
+    ("lb", Mass::from_pounds),
+    ("lbs", Mass::from_pounds),
+    ("lb t", Mass::from_troy_pounds),
+    ("st", Mass::from_stones),
+    ("microgram", Mass::from_micrograms),
+    ("micrograms", Mass::from_micrograms),
+    ("milligram", Mass::from_milligrams),
+    ("milligrams", Mass::from_milligrams),
+    ("gram", Mass::from_grams),
+    ("grams", Mass::from_grams),
+    ("kilogram", Mass::from_kilograms),
+    ("kilograms", Mass::from_kilograms),
+    ("tonne", Mass::from_tonnes),
+    ("metric ton", Mass::from_metric_tons),
+    ("metric tons", Mass::from_metric_tons),
+    ("carat", Mass::from_carats),
+    ("carats", Mass::from_carats),
+    ("grain", Mass::from_grains),
+    ("grains", Mass::from_grains),
+    ("pennyweight", Mass::from_pennyweights),
+    ("pennyweights", Mass::from_pennyweights),
+    ("ounce", Mass::from_ounces),
+    ("ounces", Mass::from_ounces),
+    ("troy ounce", Mass::from_troy_ounces),
+    ("troy ounces", Mass::from_troy_ounces),
+    ("pound", Mass::from_pounds),
+    ("pounds", Mass::from_pounds),
+    ("troy pound", Mass::from_troy_pounds),
+    ("troy pounds", Mass::from_troy_pounds),
+    ("stone", Mass::from_stones),
+    ("stones", Mass::from_stones),
+    ("short ton", Mass::from_short_tons),
+    ("short tons", Mass::from_short_tons),
+    ("long ton", Mass::from_long_tons),
+    ("long tons", Mass::from_long_tons),
+];
+
+impl UnitNames for Mass {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Mass {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use mass::*;
@@ -484,4 +597,46 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Mass = "11 st 4 lb".parse().unwrap();
+        let b: Mass = "250 mg".parse().unwrap();
+        assert_almost_eq(a.as_pounds(), 158.0);
+        assert_almost_eq(b.as_grams(), 0.25);
+    }
+
+    #[test]
+    fn units() {
+        let a = Mass::from_unit(2.0, MassUnit::Stone);
+        assert_almost_eq(a.as_pounds(), 28.0);
+        assert_almost_eq(a.as_unit(MassUnit::Kilogram), 12.700586);
+    }
+
+    #[test]
+    fn stones_pounds() {
+        let a = Mass::from_stones_pounds(11.0, 4.0);
+        assert_almost_eq(a.as_pounds(), 158.0);
+        let (stones, pounds) = a.as_stones_pounds();
+        assert_eq!(stones, 11.0);
+        assert_almost_eq(pounds, 4.0);
+    }
+
+    #[test]
+    fn pounds_ounces() {
+        let a = Mass::from_pounds_ounces(7.0, 8.0);
+        assert_almost_eq(a.as_pounds(), 7.5);
+        let (pounds, ounces) = a.as_pounds_ounces();
+        assert_eq!(pounds, 7.0);
+        assert_almost_eq(ounces, 8.0);
+    }
+
+    #[test]
+    fn compound_display() {
+        const STONES_POUNDS: &[MassUnit] = &[MassUnit::Stone, MassUnit::Pound];
+        let a = Mass::from_stones_pounds(11.0, 4.0);
+        let text = a.display().compound(STONES_POUNDS).precision(0).to_string();
+        assert_eq!(text, "11\u{00A0}st\u{00A0}4\u{00A0}lb");
+        let b: Mass = text.parse().unwrap();
+        assert_almost_eq(b.as_pounds(), 158.0);
+    }
 }
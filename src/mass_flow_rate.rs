@@ -0,0 +1,255 @@
+//! Types and constants for handling mass flow rate (mass per unit time).
+
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::speed::{SECONDS_HOURS_FACTOR, SECONDS_MINUTES_FACTOR};
+use super::*;
+
+/// Number of kg/s in a g/s
+pub const KILOGRAMS_PER_SECOND_GRAMS_PER_SECOND_FACTOR: f64 = 1.0 / mass::KILOGRAM_GRAM_FACTOR;
+/// Number of kg/s in a t/h
+pub const KILOGRAMS_PER_SECOND_TONNES_PER_HOUR_FACTOR: f64 =
+    1.0 / mass::KILOGRAM_TONNE_FACTOR / SECONDS_HOURS_FACTOR;
+/// Number of kg/s in a lb/h
+pub const KILOGRAMS_PER_SECOND_POUNDS_PER_HOUR_FACTOR: f64 =
+    1.0 / mass::KILOGRAM_POUNDS_FACTOR / SECONDS_HOURS_FACTOR;
+
+/// The `MassFlowRate` struct can be used to deal with mass flow rates in a
+/// common way, such as the fuel burned by an engine or the gas passing
+/// through a line. Common metric and imperial units are supported.
+///
+/// # Example
+///
+/// ```
+/// use measurements::MassFlowRate;
+/// use std::time::Duration;
+///
+/// let burner = MassFlowRate::from_kilograms_per_hour(12.0);
+/// let burned = burner * Duration::from_secs(30 * 60);
+/// println!("The burner used {} of gas in half an hour.", burned);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct MassFlowRate {
+    kilograms_per_second: f64,
+}
+
+impl MassFlowRate {
+    /// Create a new MassFlowRate from a floating point value in kilograms
+    /// per second
+    pub fn from_kilograms_per_second(kilograms_per_second: f64) -> Self {
+        MassFlowRate {
+            kilograms_per_second,
+        }
+    }
+
+    /// Create a new MassFlowRate from a floating point value in kilograms
+    /// per minute
+    pub fn from_kilograms_per_minute(kilograms_per_minute: f64) -> Self {
+        MassFlowRate::from_kilograms_per_second(kilograms_per_minute / SECONDS_MINUTES_FACTOR)
+    }
+
+    /// Create a new MassFlowRate from a floating point value in kilograms
+    /// per hour
+    pub fn from_kilograms_per_hour(kilograms_per_hour: f64) -> Self {
+        MassFlowRate::from_kilograms_per_second(kilograms_per_hour / SECONDS_HOURS_FACTOR)
+    }
+
+    /// Create a new MassFlowRate from a floating point value in grams per
+    /// second
+    pub fn from_grams_per_second(grams_per_second: f64) -> Self {
+        MassFlowRate::from_kilograms_per_second(
+            grams_per_second * KILOGRAMS_PER_SECOND_GRAMS_PER_SECOND_FACTOR,
+        )
+    }
+
+    /// Create a new MassFlowRate from a floating point value in tonnes per
+    /// hour
+    pub fn from_tonnes_per_hour(tonnes_per_hour: f64) -> Self {
+        MassFlowRate::from_kilograms_per_second(
+            tonnes_per_hour * KILOGRAMS_PER_SECOND_TONNES_PER_HOUR_FACTOR,
+        )
+    }
+
+    /// Create a new MassFlowRate from a floating point value in pounds per
+    /// hour
+    pub fn from_pounds_per_hour(pounds_per_hour: f64) -> Self {
+        MassFlowRate::from_kilograms_per_second(
+            pounds_per_hour * KILOGRAMS_PER_SECOND_POUNDS_PER_HOUR_FACTOR,
+        )
+    }
+
+    /// Convert this MassFlowRate into a floating point value in kilograms
+    /// per second
+    pub fn as_kilograms_per_second(&self) -> f64 {
+        self.kilograms_per_second
+    }
+
+    /// Convert this MassFlowRate into a floating point value in kilograms
+    /// per minute
+    pub fn as_kilograms_per_minute(&self) -> f64 {
+        self.kilograms_per_second * SECONDS_MINUTES_FACTOR
+    }
+
+    /// Convert this MassFlowRate into a floating point value in kilograms
+    /// per hour
+    pub fn as_kilograms_per_hour(&self) -> f64 {
+        self.kilograms_per_second * SECONDS_HOURS_FACTOR
+    }
+
+    /// Convert this MassFlowRate into a floating point value in grams per
+    /// second
+    pub fn as_grams_per_second(&self) -> f64 {
+        self.kilograms_per_second / KILOGRAMS_PER_SECOND_GRAMS_PER_SECOND_FACTOR
+    }
+
+    /// Convert this MassFlowRate into a floating point value in tonnes per
+    /// hour
+    pub fn as_tonnes_per_hour(&self) -> f64 {
+        self.kilograms_per_second / KILOGRAMS_PER_SECOND_TONNES_PER_HOUR_FACTOR
+    }
+
+    /// Convert this MassFlowRate into a floating point value in pounds per
+    /// hour
+    pub fn as_pounds_per_hour(&self) -> f64 {
+        self.kilograms_per_second / KILOGRAMS_PER_SECOND_POUNDS_PER_HOUR_FACTOR
+    }
+}
+
+impl Measurement for MassFlowRate {
+    fn as_base_units(&self) -> f64 {
+        self.kilograms_per_second
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_kilograms_per_second(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "kg/s"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = [
+            ("\u{00B5}g/s", 1e-9),
+            ("mg/s", 1e-6),
+            ("g/s", 1e-3),
+            ("kg/s", 1e0),
+            ("t/s", 1e3),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { MassFlowRate }
+
+implement_units! {
+    MassFlowRate, MassFlowRateUnit {
+        KilogramPerSecond => ("kg/s", "kilogram per second", "kilograms per second", 1.0) in Si,
+        KilogramPerMinute => ("kg/min", "kilogram per minute", "kilograms per minute", 1.0 / SECONDS_MINUTES_FACTOR),
+        KilogramPerHour => ("kg/h", "kilogram per hour", "kilograms per hour", 1.0 / SECONDS_HOURS_FACTOR),
+        GramPerSecond => ("g/s", "gram per second", "grams per second", KILOGRAMS_PER_SECOND_GRAMS_PER_SECOND_FACTOR) in Si,
+        TonnePerHour => ("t/h", "tonne per hour", "tonnes per hour", KILOGRAMS_PER_SECOND_TONNES_PER_HOUR_FACTOR),
+        PoundPerHour => ("lb/h", "pound per hour", "pounds per hour", KILOGRAMS_PER_SECOND_POUNDS_PER_HOUR_FACTOR) in UsCustomary | Imperial,
+    }
+}
+
+/// The unit names understood when parsing a `MassFlowRate`.
+const UNIT_NAMES: &[UnitConstructor<'static, MassFlowRate>] = &[
+    ("\u{00B5}g/s", |v| {
+        MassFlowRate::from_kilograms_per_second(v * 1e-9)
+    }),
+    ("mg/s", |v| {
+        MassFlowRate::from_kilograms_per_second(v * 1e-6)
+    }),
+    ("g/s", MassFlowRate::from_grams_per_second),
+    ("kg/s", MassFlowRate::from_kilograms_per_second),
+    ("t/s", |v| MassFlowRate::from_kilograms_per_second(v * 1e3)),
+    ("kg/min", MassFlowRate::from_kilograms_per_minute),
+    ("kg/h", MassFlowRate::from_kilograms_per_hour),
+    ("kg/hr", MassFlowRate::from_kilograms_per_hour),
+    ("t/h", MassFlowRate::from_tonnes_per_hour),
+    ("lb/h", MassFlowRate::from_pounds_per_hour),
+    ("lb/hr", MassFlowRate::from_pounds_per_hour),
+    (
+        "kilograms per second",
+        MassFlowRate::from_kilograms_per_second,
+    ),
+    (
+        "kilograms per minute",
+        MassFlowRate::from_kilograms_per_minute,
+    ),
+    ("kilograms per hour", MassFlowRate::from_kilograms_per_hour),
+    ("grams per second", MassFlowRate::from_grams_per_second),
+    ("tonnes per hour", MassFlowRate::from_tonnes_per_hour),
+    ("pounds per hour", MassFlowRate::from_pounds_per_hour),
+];
+
+impl UnitNames for MassFlowRate {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for MassFlowRate {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use mass::Mass;
+    use mass_flow_rate::*;
+    use test_utils::assert_almost_eq;
+    use time::Duration;
+
+    #[test]
+    fn metric() {
+        let f = MassFlowRate::from_kilograms_per_hour(3600.0);
+        assert_almost_eq(f.as_kilograms_per_second(), 1.0);
+        assert_almost_eq(f.as_grams_per_second(), 1000.0);
+        assert_almost_eq(f.as_tonnes_per_hour(), 3.6);
+        assert_almost_eq(f.as_kilograms_per_minute(), 60.0);
+    }
+
+    #[test]
+    fn imperial() {
+        let f = MassFlowRate::from_pounds_per_hour(1000.0);
+        assert_almost_eq(f.as_kilograms_per_hour(), 453.59237);
+    }
+
+    #[test]
+    fn mass_and_duration() {
+        let f = MassFlowRate::from_kilograms_per_hour(12.0);
+        let m: Mass = f * Duration::new(1800, 0);
+        assert_almost_eq(m.as_kilograms(), 6.0);
+        let f: MassFlowRate = Mass::from_kilograms(10.0) / Duration::new(4, 0);
+        assert_almost_eq(f.as_kilograms_per_second(), 2.5);
+        let t: Duration = Mass::from_kilograms(10.0) / f;
+        assert_eq!(t, Duration::new(4, 0));
+    }
+
+    #[test]
+    fn display() {
+        let f = MassFlowRate::from_grams_per_second(25.0);
+        assert_eq!(format!("{}", f), "25\u{00A0}g/s");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: MassFlowRate = "7200 kg/h".parse().unwrap();
+        let b: MassFlowRate = "10 lb/hr".parse().unwrap();
+        assert_almost_eq(a.as_kilograms_per_second(), 2.0);
+        assert_almost_eq(b.as_pounds_per_hour(), 10.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = MassFlowRate::from_unit(1.0, MassFlowRateUnit::TonnePerHour);
+        assert_almost_eq(a.as_kilograms_per_hour(), 1000.0);
+        assert_almost_eq(a.as_unit(MassFlowRateUnit::KilogramPerSecond), 1.0 / 3.6);
+    }
+}
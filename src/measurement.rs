@@ -84,7 +84,8 @@ pub trait Measurement {
 }
 
 /// This is a special macro that creates the code to implement
-/// `std::fmt::Display`.
+/// `std::fmt::Display`. The width, fill and alignment flags apply to the
+/// value and unit together.
 #[macro_export]
 macro_rules! implement_display {
     ($($t:ty)*) => ($(
@@ -92,8 +93,8 @@ macro_rules! implement_display {
         impl ::std::fmt::Display for $t {
             fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                 let (unit, value) = self.get_appropriate_units();
-                value.fmt(f)?;      // Value
-                write!(f, "\u{00A0}{}", unit)
+                let precision = f.precision();
+                $crate::display::write_measurement(f, value, precision, '.', '\u{00A0}', &unit)
             }
         }
     )*)
@@ -0,0 +1,472 @@
+//! Support for parsing measurements from strings such as "12.5 km",
+//! "101.3kPa" or "3'6\"".
+//!
+//! Every quantity in this crate implements `std::str::FromStr` using the
+//! functions in this module. An input is one or more terms, each made of a
+//! number followed by a unit. When there is more than one term, the terms
+//! are added together, so "5 ft 11 in" parses as a single `Length`. A
+//! number may be a fraction or a whole number and a fraction, as in
+//! "5 ft 10 7/8 in".
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::Length;
+//!
+//! let height: Length = "5 ft 11 in".parse().unwrap();
+//! println!("That's {} metres", height.as_metres());
+//! ```
+
+use super::measurement::*;
+use std::fmt;
+
+/// Describes which part of the input could not be parsed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input was empty, or contained only whitespace.
+    Empty,
+    /// A number was expected but could not be read.
+    InvalidNumber,
+    /// A number was not followed by a unit.
+    MissingUnit,
+    /// The unit is not one this quantity knows about.
+    UnknownUnit,
+    /// More than one term was given for a quantity which cannot be summed
+    /// (such as an absolute Temperature).
+    UnexpectedTerm,
+}
+
+/// The error returned when a measurement cannot be parsed from a string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseMeasurementError {
+    kind: ParseErrorKind,
+    position: usize,
+}
+
+impl ParseMeasurementError {
+    /// Create a new error of the given kind, at the given byte offset into
+    /// the input.
+    pub fn new(kind: ParseErrorKind, position: usize) -> Self {
+        ParseMeasurementError { kind, position }
+    }
+
+    /// Which part of the input failed to parse.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset into the input at which the failing part starts.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseMeasurementError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self.kind {
+            ParseErrorKind::Empty => "no measurement given",
+            ParseErrorKind::InvalidNumber => "invalid number",
+            ParseErrorKind::MissingUnit => "missing unit",
+            ParseErrorKind::UnknownUnit => "unknown unit",
+            ParseErrorKind::UnexpectedTerm => "unexpected extra term",
+        };
+        write!(f, "{} at byte {}", description, self.position)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl ::std::error::Error for ParseMeasurementError {}
+
+/// A unit name, paired with the function which creates a measurement from a
+/// value in that unit.
+pub type UnitConstructor<'a, T> = (&'a str, fn(f64) -> T);
+
+/// Implemented by measurements which can be parsed from strings.
+pub trait UnitNames: Sized + 'static {
+    /// Every unit name this measurement understands, paired with the function
+    /// which creates the measurement from a value in that unit.
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>];
+
+    /// Create a measurement from a value and the name of its unit, or `None`
+    /// if the unit is not known.
+    fn from_unit_name(value: f64, unit: &str) -> Option<Self> {
+        let units = Self::unit_names();
+        lookup_unit(unit, units).map(|index| units[index].1(value))
+    }
+}
+
+/// Parse `input` as one or more value/unit terms and add them together.
+///
+/// Each unit is looked up in `units` with `lookup_unit`, and the matching
+/// function is used to turn the value into a measurement. If the first term
+/// is negative, the following terms without a sign of their own are negated
+/// too, so "-3'6\"" is minus three and a half feet. "NaN", "inf" and "-inf"
+/// are read as `f64`'s `Display` writes them, so "NaN m" parses.
+pub fn parse_measurement<T: Measurement>(
+    input: &str,
+    units: &[UnitConstructor<T>],
+) -> Result<T, ParseMeasurementError> {
+    let mut position = skip_whitespace(input, 0);
+    if position == input.len() {
+        return Err(ParseMeasurementError::new(ParseErrorKind::Empty, position));
+    }
+    let mut total = 0.0;
+    let mut negative = None;
+    while position < input.len() {
+        let signed = input[position..].starts_with(['-', '+']);
+        let (value, unit, next) = next_term(input, position, units)?;
+        let value = match negative {
+            None => {
+                negative = Some(value.is_sign_negative());
+                value
+            }
+            Some(true) if !signed => -value,
+            Some(_) => value,
+        };
+        total += units[unit].1(value).as_base_units();
+        position = next;
+    }
+    Ok(T::from_base_units(total))
+}
+
+/// As `parse_measurement`, but only a single value/unit term is accepted.
+pub fn parse_single_measurement<T>(
+    input: &str,
+    units: &[UnitConstructor<T>],
+) -> Result<T, ParseMeasurementError> {
+    let position = skip_whitespace(input, 0);
+    if position == input.len() {
+        return Err(ParseMeasurementError::new(ParseErrorKind::Empty, position));
+    }
+    let (value, unit, next) = next_term(input, position, units)?;
+    if next < input.len() {
+        return Err(ParseMeasurementError::new(
+            ParseErrorKind::UnexpectedTerm,
+            next,
+        ));
+    }
+    Ok(units[unit].1(value))
+}
+
+/// Find `unit` in a list of unit names and return its index.
+///
+/// An exact match is preferred. Failing that, a leading "u" or Greek mu is
+/// accepted in place of the micro sign, and finally a case-insensitive match
+/// of a name which is a word (see `is_word`) is accepted as long as only one
+/// entry matches. Symbols are never matched regardless of case, since "Mm"
+/// and "mm" are different units.
+pub fn lookup_unit<T>(unit: &str, units: &[(&str, T)]) -> Option<usize> {
+    if let Some(index) = units.iter().position(|&(name, _)| name == unit) {
+        return Some(index);
+    }
+    if let Some(index) = units.iter().position(|&(name, _)| micro_eq(name, unit)) {
+        return Some(index);
+    }
+    let listed = |name: &str| units.iter().any(|&(other, _)| other == name);
+    let mut matches = units
+        .iter()
+        .enumerate()
+        .filter(|&(_, &(name, _))| name.eq_ignore_ascii_case(unit) && is_word(name, &listed));
+    match (matches.next(), matches.next()) {
+        (Some((index, _)), None) => Some(index),
+        _ => None,
+    }
+}
+
+/// Whether a unit name is a word, such as "meters" or "degree Celsius",
+/// which may be matched regardless of case. A word has at least four
+/// letters, and only its first letter or the first letter after a space may
+/// be upper case. Prefixed symbols such as "mbar" or "kbit" look like words,
+/// so a name is not one if it is a prefix letter followed by another listed
+/// name.
+pub(crate) fn is_word(name: &str, listed: &dyn Fn(&str) -> bool) -> bool {
+    let bytes = name.as_bytes();
+    let letters = bytes.len() >= 4
+        && bytes.iter().enumerate().all(|(i, &b)| {
+            b.is_ascii_lowercase()
+                || b == b' '
+                || b == b'-'
+                || (b.is_ascii_uppercase() && (i == 0 || bytes[i - 1] == b' '))
+        });
+    letters && !listed(&name[1..])
+}
+
+/// Compare a unit name against some input, allowing "u" or Greek mu in the
+/// input to stand in for a leading micro sign in the name.
+pub(crate) fn micro_eq(name: &str, input: &str) -> bool {
+    let mut input_chars = input.chars();
+    match (name.chars().next(), input_chars.next()) {
+        (Some('\u{00B5}'), Some('u')) | (Some('\u{00B5}'), Some('\u{03BC}')) => {
+            name['\u{00B5}'.len_utf8()..] == *input_chars.as_str()
+        }
+        _ => false,
+    }
+}
+
+/// Read one term starting at `start`. Returns the value, the index of the
+/// unit in `units` and the position at which the next term starts.
+fn next_term<T>(
+    input: &str,
+    start: usize,
+    units: &[(&str, T)],
+) -> Result<(f64, usize, usize), ParseMeasurementError> {
+    let number_end = scan_number(input, start);
+    let number = &input[start..number_end];
+    let negative = number.starts_with('-');
+    let (value, number_end) = if input[number_end..].starts_with('/') {
+        let digits_start = if negative || number.starts_with('+') { start + 1 } else { start };
+        match scan_fraction(input, digits_start) {
+            Some((fraction, end)) if negative => (-fraction, end),
+            Some((fraction, end)) => (fraction, end),
+            None => return Err(ParseMeasurementError::new(ParseErrorKind::InvalidNumber, start)),
+        }
+    } else {
+        let whole: f64 = number
+            .parse()
+            .map_err(|_| ParseMeasurementError::new(ParseErrorKind::InvalidNumber, start))?;
+        let is_integer = number.bytes().all(|b| b.is_ascii_digit() || b == b'+' || b == b'-');
+        match scan_fraction(input, skip_whitespace(input, number_end)) {
+            Some((fraction, end)) if is_integer && negative => (whole - fraction, end),
+            Some((fraction, end)) if is_integer => (whole + fraction, end),
+            _ => (whole, number_end),
+        }
+    };
+    let unit_start = skip_whitespace(input, number_end);
+    let unit_end = scan_unit(input, unit_start);
+    if unit_start == unit_end {
+        return Err(ParseMeasurementError::new(
+            ParseErrorKind::MissingUnit,
+            unit_start,
+        ));
+    }
+    let index = lookup_unit(&input[unit_start..unit_end], units)
+        .ok_or_else(|| ParseMeasurementError::new(ParseErrorKind::UnknownUnit, unit_start))?;
+    Ok((value, index, skip_whitespace(input, unit_end)))
+}
+
+/// Read a fraction of two whole numbers, such as "3/16", starting at
+/// `start`. Returns its value and the position after it.
+fn scan_fraction(input: &str, start: usize) -> Option<(f64, usize)> {
+    let bytes = input.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+    let numerator_end = digits(start);
+    if numerator_end == start || bytes.get(numerator_end) != Some(&b'/') {
+        return None;
+    }
+    let denominator_end = digits(numerator_end + 1);
+    let numerator: f64 = input[start..numerator_end].parse().ok()?;
+    let denominator: f64 = input[numerator_end + 1..denominator_end].parse().ok()?;
+    if denominator == 0.0 {
+        return None;
+    }
+    Some((numerator / denominator, denominator_end))
+}
+
+pub(crate) fn skip_whitespace(input: &str, start: usize) -> usize {
+    match input[start..].find(|c: char| !c.is_whitespace()) {
+        Some(offset) => start + offset,
+        None => input.len(),
+    }
+}
+
+/// Find the end of the number starting at `start`: an optional sign, digits
+/// with an optional decimal point, and an optional exponent, or "NaN" or
+/// "inf" after the sign.
+pub(crate) fn scan_number(input: &str, start: usize) -> usize {
+    let bytes = input.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+    let mut i = start;
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        i += 1;
+    }
+    for &word in &["NaN", "inf"] {
+        let end = i + word.len();
+        if input[i..].starts_with(word) && !input[end..].starts_with(char::is_alphabetic) {
+            return end;
+        }
+    }
+    i = digits(i);
+    if i < bytes.len() && bytes[i] == b'.' {
+        i = digits(i + 1);
+    }
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        let mut j = i + 1;
+        if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+            j += 1;
+        }
+        if j < bytes.len() && bytes[j].is_ascii_digit() {
+            i = digits(j);
+        }
+    }
+    i
+}
+
+/// Whether a new number starts at `start`.
+pub(crate) fn starts_number(input: &str, start: usize) -> bool {
+    let bytes = &input.as_bytes()[start..];
+    match bytes.first() {
+        Some(b) if b.is_ascii_digit() => true,
+        Some(&b'+') | Some(&b'-') | Some(&b'.') => bytes.len() > 1 && bytes[1].is_ascii_digit(),
+        _ => false,
+    }
+}
+
+/// Find the end of the unit starting at `start`.
+///
+/// A unit may contain spaces ("lbf ft") and digits used as exponents ("m^2",
+/// "cm3"), so it ends at the end of the input, at whitespace followed by a
+/// number, or at a digit that does not follow a letter or '^' ("3'6\"").
+fn scan_unit(input: &str, start: usize) -> usize {
+    let mut end = start;
+    let mut previous: Option<char> = None;
+    for (index, c) in input[start..].char_indices() {
+        let index = start + index;
+        if c.is_whitespace() {
+            if starts_number(input, skip_whitespace(input, index)) {
+                break;
+            }
+        } else if c.is_ascii_digit() {
+            match previous {
+                Some(p) if p == '^' || p.is_alphabetic() => end = index + 1,
+                _ => break,
+            }
+        } else {
+            end = index + c.len_utf8();
+        }
+        previous = if c.is_whitespace() { None } else { Some(c) };
+    }
+    end
+}
+
+#[cfg(test)]
+mod test {
+    use data::Data;
+    use length::Length;
+    use parse::*;
+    use pressure::Pressure;
+    use test_utils::assert_almost_eq;
+
+    fn units() -> [UnitConstructor<'static, Length>; 7] {
+        [
+            ("m", Length::from_meters),
+            ("km", Length::from_kilometers),
+            ("'", Length::from_feet),
+            ("\"", Length::from_inches),
+            ("\u{00B5}m", Length::from_micrometers),
+            ("Mm", |m| Length::from_kilometers(m * 1e3)),
+            ("kilometers", Length::from_kilometers),
+        ]
+    }
+
+    #[test]
+    fn single_term() {
+        let l = parse_measurement("12.5 km", &units()).unwrap();
+        assert_almost_eq(l.as_meters(), 12500.0);
+    }
+
+    #[test]
+    fn no_space() {
+        let l = parse_measurement("12.5km", &units()).unwrap();
+        assert_almost_eq(l.as_meters(), 12500.0);
+    }
+
+    #[test]
+    fn exponent() {
+        let l = parse_measurement("1.5e3 m", &units()).unwrap();
+        assert_almost_eq(l.as_meters(), 1500.0);
+    }
+
+    #[test]
+    fn non_finite() {
+        let l = parse_measurement("NaN m", &units()).unwrap();
+        assert!(l.as_meters().is_nan());
+        let l = parse_measurement("inf km", &units()).unwrap();
+        assert_eq!(l.as_meters(), f64::INFINITY);
+        let l = parse_measurement("-inf\"", &units()).unwrap();
+        assert_eq!(l.as_meters(), f64::NEG_INFINITY);
+        assert!(parse_measurement("info m", &units()).is_err());
+    }
+
+    #[test]
+    fn compound() {
+        let l = parse_measurement("3'6\"", &units()).unwrap();
+        assert_almost_eq(l.as_feet(), 3.5);
+    }
+
+    #[test]
+    fn negative_compound() {
+        let l = parse_measurement("-3' 6\"", &units()).unwrap();
+        assert_almost_eq(l.as_feet(), -3.5);
+        let l = parse_measurement("-3' -6\"", &units()).unwrap();
+        assert_almost_eq(l.as_feet(), -3.5);
+        let l = parse_measurement("-3' +6\"", &units()).unwrap();
+        assert_almost_eq(l.as_feet(), -2.5);
+    }
+
+    #[test]
+    fn fractions() {
+        let l = parse_measurement("3/4\"", &units()).unwrap();
+        assert_almost_eq(l.as_inches(), 0.75);
+        let l = parse_measurement("5' 10 7/8\"", &units()).unwrap();
+        assert_almost_eq(l.as_inches(), 70.875);
+        let l = parse_measurement("-1 1/2 m", &units()).unwrap();
+        assert_almost_eq(l.as_meters(), -1.5);
+        let l = parse_measurement("-1/4 m", &units()).unwrap();
+        assert_almost_eq(l.as_meters(), -0.25);
+        let l = parse_measurement("1.5 m 3/4 m", &units()).unwrap();
+        assert_almost_eq(l.as_meters(), 2.25);
+        let e = parse_measurement("3/0 m", &units()).unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::InvalidNumber);
+    }
+
+    #[test]
+    fn micro() {
+        let l = parse_measurement("7 um", &units()).unwrap();
+        assert_almost_eq(l.as_micrometers(), 7.0);
+        let l = parse_measurement("7 \u{03BC}m", &units()).unwrap();
+        assert_almost_eq(l.as_micrometers(), 7.0);
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let l = parse_measurement("2 KILOMETERS", &units()).unwrap();
+        assert_almost_eq(l.as_meters(), 2000.0);
+        let l = parse_measurement("2 Kilometers", &units()).unwrap();
+        assert_almost_eq(l.as_meters(), 2000.0);
+        let l = parse_measurement("5 Mm", &units()).unwrap();
+        assert_almost_eq(l.as_meters(), 5e6);
+        assert!(parse_measurement("2 KM", &units()).is_err());
+        assert!(parse_measurement("5 MM", &units()).is_err());
+        assert!("5 Mm".parse::<Length>().is_err());
+        assert!("5 MBAR".parse::<Pressure>().is_err());
+        assert_ne!("1 Mb".parse::<Data>().ok(), Some(Data::from_megaoctets(1.0)));
+    }
+
+    #[test]
+    fn errors() {
+        let e = parse_measurement("  ", &units()).unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::Empty);
+        let e = parse_measurement("km", &units()).unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(e.position(), 0);
+        let e = parse_measurement("12", &units()).unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::MissingUnit);
+        assert_eq!(e.position(), 2);
+        let e = parse_measurement("12 furlongs", &units()).unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(e.position(), 3);
+        let e = parse_single_measurement("1 m 2 m", &units()).unwrap_err();
+        assert_eq!(e.kind(), ParseErrorKind::UnexpectedTerm);
+        assert_eq!(e.position(), 4);
+    }
+}
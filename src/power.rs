@@ -1,6 +1,7 @@
 //! Types and constants for handling power.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 
 /// Number of horsepower in a watt
 pub const WATT_HORSEPOWER_FACTOR: f64 = 1.0 / 745.6998715822702;
@@ -149,6 +150,62 @@ impl Measurement for Power {
 
 implement_measurement! { Power }
 
+implement_units! {
+    Power, PowerUnit {
+        Microwatt => ("\u{00B5}W", "microwatt", "microwatts", 1.0 / WATT_MICROWATT_FACTOR) in Si,
+        Milliwatt => ("mW", "milliwatt", "milliwatts", 1.0 / WATT_MILLIWATT_FACTOR) in Si,
+        Watt => ("W", "watt", "watts", 1.0) in Si,
+        Kilowatt => ("kW", "kilowatt", "kilowatts", 1.0 / WATT_KILOWATT_FACTOR) in Si,
+        Horsepower => ("hp", "horsepower", "horsepower", 1.0 / WATT_HORSEPOWER_FACTOR) in UsCustomary | Imperial,
+        MetricHorsepower => ("PS", "metric horsepower", "metric horsepower", 1.0 / WATT_PS_FACTOR),
+        BtuPerMinute => ("BTU/min", "BTU per minute", "BTU per minute", 1.0 / WATT_BTU_MIN_FACTOR),
+    }
+}
+
+/// The unit names understood when parsing a `Power`.
+const UNIT_NAMES: &[UnitConstructor<'static, Power>] = &[
+    ("fW", |v| Power::from_watts(v * 1e-15)),
+    ("pW", |v| Power::from_watts(v * 1e-12)),
+    ("nW", |v| Power::from_watts(v * 1e-9)),
+    ("\u{00B5}W", |v| Power::from_watts(v * 1e-6)),
+    ("mW", |v| Power::from_watts(v * 1e-3)),
+    ("W", Power::from_watts),
+    ("kW", |v| Power::from_watts(v * 1e3)),
+    ("MW", |v| Power::from_watts(v * 1e6)),
+    ("GW", |v| Power::from_watts(v * 1e9)),
+    ("TW", |v| Power::from_watts(v * 1e12)),
+    ("PW", |v| Power::from_watts(v * 1e15)),
+    ("EW", |v| Power::from_watts(v * 1e18)),
+    ("watt", Power::from_watts),
+    ("watts", Power::from_watts),
+    ("milliwatt", Power::from_milliwatts),
+    ("milliwatts", Power::from_milliwatts),
+    ("microwatt", Power::from_microwatts),
+    ("microwatts", Power::from_microwatts),
+    ("kilowatt", Power::from_kilowatts),
+    ("kilowatts", Power::from_kilowatts),
+    ("hp", Power::from_horsepower),
+    ("horsepower", Power::from_horsepower),
+    ("PS", Power::from_ps),
+    ("metric horsepower", Power::from_metric_horsepower),
+    ("BTU/min", Power::from_btu_per_minute),
+    ("BTU per minute", Power::from_btu_per_minute),
+];
+
+impl UnitNames for Power {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Power {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use power::*;
@@ -302,4 +359,18 @@ mod test {
         assert_eq!(u.as_volts(), 230.0);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Power = "225 PS".parse().unwrap();
+        let b: Power = "1.5 kW".parse().unwrap();
+        assert_almost_eq(a.as_ps(), 225.0);
+        assert_almost_eq(b.as_watts(), 1500.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Power::from_unit(1.0, PowerUnit::Horsepower);
+        assert_almost_eq(a.as_horsepower(), 1.0);
+        assert_almost_eq(a.as_unit(PowerUnit::Watt), 745.69987);
+    }
 }
@@ -2,3 +2,6 @@
 //! You should wildcard import this file.
 
 pub use super::Measurement;
+pub use super::Unit;
+pub use super::Dimensioned;
+pub use super::parse::UnitNames;
@@ -1,6 +1,7 @@
 //! Types and constants for handling pressure.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 
 /// Number of Pascals in an atomosphere
 pub const PASCAL_ATMOSPHERE_FACTOR: f64 = 101_325.0;
@@ -133,6 +134,58 @@ impl Measurement for Pressure {
 
 implement_measurement! { Pressure }
 
+implement_units! {
+    Pressure, PressureUnit {
+        Pascal => ("Pa", "pascal", "pascals", 1.0) in Si,
+        Hectopascal => ("hPa", "hectopascal", "hectopascals", PASCAL_HECTOPASCAL_FACTOR),
+        Millibar => ("mbar", "millibar", "millibars", PASCAL_MILLIBAR_FACTOR),
+        Kilopascal => ("kPa", "kilopascal", "kilopascals", PASCAL_KILOPASCAL_FACTOR) in Si,
+        Psi => ("psi", "pound per square inch", "pounds per square inch", PASCAL_PSI_FACTOR) in UsCustomary | Imperial,
+        Bar => ("bar", "bar", "bars", PASCAL_BAR_FACTOR),
+        Atmosphere => ("atm", "atmosphere", "atmospheres", PASCAL_ATMOSPHERE_FACTOR),
+    }
+}
+
+/// The unit names understood when parsing a `Pressure`.
+const UNIT_NAMES: &[UnitConstructor<'static, Pressure>] = &[
+    ("mPa", |v| Pressure::from_pascals(v * 1e-3)),
+    ("Pa", Pressure::from_pascals),
+    ("hPa", Pressure::from_hectopascals),
+    ("kPa", Pressure::from_kilopascals),
+    ("MPa", |v| Pressure::from_pascals(v * 1e6)),
+    ("GPa", |v| Pressure::from_pascals(v * 1e9)),
+    ("TPa", |v| Pressure::from_pascals(v * 1e12)),
+    ("mbar", Pressure::from_millibars),
+    ("bar", Pressure::from_bars),
+    ("psi", Pressure::from_psi),
+    ("atm", Pressure::from_atmospheres),
+    ("pascal", Pressure::from_pascals),
+    ("pascals", Pressure::from_pascals),
+    ("hectopascal", Pressure::from_hectopascals),
+    ("hectopascals", Pressure::from_hectopascals),
+    ("kilopascal", Pressure::from_kilopascals),
+    ("kilopascals", Pressure::from_kilopascals),
+    ("millibar", Pressure::from_millibars),
+    ("millibars", Pressure::from_millibars),
+    ("bars", Pressure::from_bars),
+    ("atmosphere", Pressure::from_atmospheres),
+    ("atmospheres", Pressure::from_atmospheres),
+];
+
+impl UnitNames for Pressure {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Pressure {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -261,4 +314,21 @@ mod test {
         assert_eq!(a > b, false);
         assert_eq!(a >= b, false);
     }
+
+    #[test]
+    fn from_str() {
+        let a: Pressure = "101.3kPa".parse().unwrap();
+        let b: Pressure = "30 psi".parse().unwrap();
+        let c: Pressure = "1013 hPa".parse().unwrap();
+        assert_almost_eq(a.as_pascals(), 101300.0);
+        assert_almost_eq(b.as_psi(), 30.0);
+        assert_almost_eq(c.as_millibars(), 1013.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Pressure::from_unit(1.0, PressureUnit::Bar);
+        assert_almost_eq(a.as_pascals(), 100_000.0);
+        assert_almost_eq(a.as_unit(PressureUnit::Psi), 14.503774);
+    }
 }
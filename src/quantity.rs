@@ -0,0 +1,566 @@
+//! A generic quantity whose dimension is checked at compile time.
+//!
+//! The named measurement types (`Length`, `Force`, `Power`, ...) only
+//! support the relations written out in lib.rs, so a product such as
+//! Mass × Length² has no type. A `Quantity<D>` holds a value in coherent SI
+//! units (m, kg, s, A, K, mol, cd) and carries its dimension `D` in its type,
+//! so multiplying or dividing any two quantities gives a quantity of the
+//! correct dimension.
+//!
+//! Every named type which has an SI dimension implements `Dimensioned`, so it
+//! can be turned into a `Quantity` and back again. The named types can also
+//! be multiplied and divided by a `Quantity` or by each other directly, and
+//! give a `Quantity` where lib.rs has no named result. An absolute
+//! `Temperature`, `Data` and `DataRate` have no SI dimension, so they are not
+//! included; use `TemperatureDelta` for differences in temperature. Nor is
+//! `AngularVelocity`, whose radians per second would share the dimension of
+//! a `Frequency` in hertz while differing from it by 2π; convert between the
+//! two with `From` instead.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::{Energy, Length, Mass};
+//! use measurements::prelude::*;
+//!
+//! let mass = Mass::from_kilograms(2.0);
+//! let length = Length::from_meters(3.0);
+//!
+//! // Mass × Length² has no named type, but is still a quantity...
+//! let moment = mass * length * length;
+//! assert_eq!(moment.value(), 18.0);
+//!
+//! // ...and Energy / Mass gives the right dimensions too.
+//! let specific_energy = Energy::from_joules(10.0) / mass;
+//! println!("{}", specific_energy);
+//! ```
+
+use super::dimension::{self, Dimension, Product, Quotient};
+use super::measurement::*;
+use super::*;
+use std::fmt;
+use std::marker::PhantomData;
+use time::Duration;
+
+/// A value in coherent SI units, with the dimension `D`.
+pub struct Quantity<D> {
+    value: f64,
+    dimension: PhantomData<D>,
+}
+
+impl<D> Quantity<D> {
+    /// Create a new Quantity from a floating point value in coherent SI units
+    pub fn new(value: f64) -> Self {
+        Quantity {
+            value,
+            dimension: PhantomData,
+        }
+    }
+
+    /// Convert this Quantity to a floating point value in coherent SI units
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Convert this Quantity into a named measurement type of the same
+    /// dimension.
+    pub fn to<T: Dimensioned<Dimension = D>>(&self) -> T {
+        T::from_quantity(*self)
+    }
+}
+
+impl<D> Clone for Quantity<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D> Copy for Quantity<D> {}
+
+impl<D: Dimension> fmt::Debug for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Quantity")
+            .field("value", &self.value)
+            .field("exponents", &D::EXPONENTS)
+            .finish()
+    }
+}
+
+impl<D: Dimension> fmt::Display for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)?;
+        dimension::write_unit(f, &D::EXPONENTS, "\u{00A0}")
+    }
+}
+
+impl<D: Dimension> Measurement for Quantity<D> {
+    fn as_base_units(&self) -> f64 {
+        self.value
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Quantity::new(units)
+    }
+
+    /// The symbol of the coherent SI unit for this dimension, if it has one,
+    /// or an empty string. `Display` spells out the units in full.
+    fn get_base_units_name(&self) -> &'static str {
+        dimension::unit_name(&D::EXPONENTS).unwrap_or("")
+    }
+}
+
+impl<D> ::std::ops::Add for Quantity<D> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Quantity::new(self.value + rhs.value)
+    }
+}
+
+impl<D> ::std::ops::Sub for Quantity<D> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Quantity::new(self.value - rhs.value)
+    }
+}
+
+impl<D> ::std::ops::Neg for Quantity<D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Quantity::new(-self.value)
+    }
+}
+
+impl<D> ::std::ops::Mul<f64> for Quantity<D> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Quantity::new(self.value * rhs)
+    }
+}
+
+impl<D> ::std::ops::Mul<Quantity<D>> for f64 {
+    type Output = Quantity<D>;
+
+    fn mul(self, rhs: Quantity<D>) -> Quantity<D> {
+        rhs * self
+    }
+}
+
+impl<D> ::std::ops::Div<f64> for Quantity<D> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Quantity::new(self.value / rhs)
+    }
+}
+
+impl<D1: Product<D2>, D2> ::std::ops::Mul<Quantity<D2>> for Quantity<D1> {
+    type Output = Quantity<D1::Output>;
+
+    fn mul(self, rhs: Quantity<D2>) -> Self::Output {
+        Quantity::new(self.value * rhs.value)
+    }
+}
+
+impl<D1: Quotient<D2>, D2> ::std::ops::Div<Quantity<D2>> for Quantity<D1> {
+    type Output = Quantity<D1::Output>;
+
+    fn div(self, rhs: Quantity<D2>) -> Self::Output {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+impl<D> ::std::cmp::PartialEq for Quantity<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<D> ::std::cmp::PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl From<Quantity<dimension::Dimensionless>> for f64 {
+    fn from(q: Quantity<dimension::Dimensionless>) -> f64 {
+        q.value
+    }
+}
+
+/// Implemented by the named measurement types which have an SI dimension, so
+/// they can be used as a `Quantity`.
+pub trait Dimensioned: Measurement + Sized {
+    /// The dimension of this measurement.
+    type Dimension: Dimension;
+
+    /// The number of coherent SI units in one of this measurement's base
+    /// units. This is 1.0 for everything except the types based on the litre:
+    /// `Volume`, `Density` (kg/L) and `VolumetricFlowRate` (L/s).
+    const SI_FACTOR: f64 = 1.0;
+
+    /// Convert this measurement into a `Quantity`.
+    fn to_quantity(&self) -> Quantity<Self::Dimension> {
+        Quantity::new(self.as_base_units() * Self::SI_FACTOR)
+    }
+
+    /// Create this measurement from a `Quantity` of the same dimension.
+    fn from_quantity(quantity: Quantity<Self::Dimension>) -> Self {
+        Self::from_base_units(quantity.value / Self::SI_FACTOR)
+    }
+}
+
+/// Implement `Dimensioned` for a named type, conversions to and from
+/// `Quantity`, and multiplication and division by any `Quantity`.
+macro_rules! implement_dimensioned {
+    ($($t:ty => $d:ty $(, $factor:expr)?;)*) => ($(
+        impl Dimensioned for $t {
+            type Dimension = $d;
+            $(const SI_FACTOR: f64 = $factor;)?
+        }
+
+        impl From<$t> for Quantity<$d> {
+            fn from(measurement: $t) -> Self {
+                measurement.to_quantity()
+            }
+        }
+
+        impl From<Quantity<$d>> for $t {
+            fn from(quantity: Quantity<$d>) -> Self {
+                <$t>::from_quantity(quantity)
+            }
+        }
+
+        impl<D: Product<$d>> ::std::ops::Mul<$t> for Quantity<D> {
+            type Output = Quantity<D::Output>;
+
+            fn mul(self, rhs: $t) -> Self::Output {
+                self * rhs.to_quantity()
+            }
+        }
+
+        impl<D> ::std::ops::Mul<Quantity<D>> for $t
+        where
+            $d: Product<D>,
+        {
+            type Output = Quantity<<$d as Product<D>>::Output>;
+
+            fn mul(self, rhs: Quantity<D>) -> Self::Output {
+                self.to_quantity() * rhs
+            }
+        }
+
+        impl<D: Quotient<$d>> ::std::ops::Div<$t> for Quantity<D> {
+            type Output = Quantity<D::Output>;
+
+            fn div(self, rhs: $t) -> Self::Output {
+                self / rhs.to_quantity()
+            }
+        }
+
+        impl<D> ::std::ops::Div<Quantity<D>> for $t
+        where
+            $d: Quotient<D>,
+        {
+            type Output = Quantity<<$d as Quotient<D>>::Output>;
+
+            fn div(self, rhs: Quantity<D>) -> Self::Output {
+                self.to_quantity() / rhs
+            }
+        }
+    )*)
+}
+
+implement_dimensioned! {
+    Length => dimension::Length;
+    Area => dimension::Area;
+    Volume => dimension::Volume, 1e-3;
+    Mass => dimension::Mass;
+    time::Duration => dimension::Time;
+    TemperatureDelta => dimension::Temperature;
+    Speed => dimension::Speed;
+    Acceleration => dimension::Acceleration;
+    Force => dimension::Force;
+    Pressure => dimension::Pressure;
+    Energy => dimension::Energy;
+    Torque => dimension::Energy;
+    TorqueEnergy => dimension::Energy;
+    Power => dimension::Power;
+    Voltage => dimension::Voltage;
+    Current => dimension::Current;
+    Resistance => dimension::Resistance;
+    Frequency => dimension::Frequency;
+    Angle => dimension::Dimensionless;
+    Density => dimension::Density, 1e3;
+    VolumetricFlowRate => dimension::VolumetricFlowRate, 1e-3;
+    MassFlowRate => dimension::MassFlowRate;
+    ElectricCharge => dimension::ElectricCharge;
+    Capacitance => dimension::Capacitance;
+    Inductance => dimension::Inductance;
+    Conductance => dimension::Conductance;
+    ApparentPower => dimension::Power;
+    ReactivePower => dimension::Power;
+}
+
+/// Implement multiplication and division between every pair of the named
+/// types given, with a `Quantity` as the result.
+macro_rules! implement_named_products {
+    ($($t:ident)*) => {
+        implement_named_products!(@rows [$($t)*] $($t)*);
+    };
+    (@rows $all:tt $($a:ident)*) => {
+        $(implement_named_products!(@row $a $all);)*
+    };
+    (@row $a:ident [$($b:ident)*]) => {
+        $(named_mul!($a, $b); named_div!($a, $b);)*
+    };
+}
+
+/// Implement `$a * $b` giving a `Quantity`, except for the products which
+/// lib.rs gives a named type, and Duration × Duration, which the orphan
+/// rules forbid.
+macro_rules! named_mul {
+    (Length, Length) => {};
+    (Power, Duration) => {};
+    (Duration, Power) => {};
+    (Acceleration, Mass) => {};
+    (Mass, Acceleration) => {};
+    (Area, Pressure) => {};
+    (Pressure, Area) => {};
+    (Speed, Duration) => {};
+    (Duration, Speed) => {};
+    (Speed, Force) => {};
+    (Force, Speed) => {};
+    (Acceleration, Duration) => {};
+    (Duration, Acceleration) => {};
+    (Area, Length) => {};
+    (Length, Area) => {};
+    (Current, Voltage) => {};
+    (Voltage, Current) => {};
+    (Current, Resistance) => {};
+    (Resistance, Current) => {};
+    (Density, Volume) => {};
+    (Volume, Density) => {};
+    (VolumetricFlowRate, Duration) => {};
+    (Duration, VolumetricFlowRate) => {};
+    (MassFlowRate, Duration) => {};
+    (Duration, MassFlowRate) => {};
+    (Density, VolumetricFlowRate) => {};
+    (VolumetricFlowRate, Density) => {};
+    (Current, Duration) => {};
+    (Duration, Current) => {};
+    (Voltage, ElectricCharge) => {};
+    (ElectricCharge, Voltage) => {};
+    (Capacitance, Resistance) => {};
+    (Resistance, Capacitance) => {};
+    (Resistance, Duration) => {};
+    (Duration, Resistance) => {};
+    (Conductance, Voltage) => {};
+    (Voltage, Conductance) => {};
+    (Voltage, Capacitance) => {};
+    (Capacitance, Voltage) => {};
+    (Length, Force) => {};
+    (Force, Length) => {};
+    (Area, Speed) => {};
+    (Speed, Area) => {};
+    (Duration, Duration) => {};
+    ($a:ident, $b:ident) => {
+        impl ::std::ops::Mul<$b> for $a {
+            type Output = Quantity<
+                <<$a as Dimensioned>::Dimension as Product<<$b as Dimensioned>::Dimension>>::Output,
+            >;
+
+            fn mul(self, rhs: $b) -> Self::Output {
+                self.to_quantity() * rhs.to_quantity()
+            }
+        }
+    };
+}
+
+/// Implement `$a / $b` giving a `Quantity`, except for the quotients which
+/// lib.rs gives a named type and the ratio of a type to itself, which is an
+/// `f64`.
+macro_rules! named_div {
+    (Area, Length) => {};
+    (Energy, Power) => {};
+    (Energy, Duration) => {};
+    (Force, Acceleration) => {};
+    (Force, Mass) => {};
+    (Force, Area) => {};
+    (Force, Pressure) => {};
+    (Length, Speed) => {};
+    (Length, Duration) => {};
+    (Power, Speed) => {};
+    (Power, Force) => {};
+    (Speed, Acceleration) => {};
+    (Speed, Duration) => {};
+    (Volume, Area) => {};
+    (Volume, Length) => {};
+    (Power, Torque) => {};
+    (Power, Current) => {};
+    (Power, Voltage) => {};
+    (Voltage, Current) => {};
+    (Voltage, Resistance) => {};
+    (Mass, Density) => {};
+    (Mass, Volume) => {};
+    (Volume, VolumetricFlowRate) => {};
+    (Volume, Duration) => {};
+    (Mass, MassFlowRate) => {};
+    (Mass, Duration) => {};
+    (MassFlowRate, Density) => {};
+    (MassFlowRate, VolumetricFlowRate) => {};
+    (ElectricCharge, Current) => {};
+    (ElectricCharge, Duration) => {};
+    (Energy, Voltage) => {};
+    (Energy, ElectricCharge) => {};
+    (Duration, Capacitance) => {};
+    (Duration, Resistance) => {};
+    (Inductance, Resistance) => {};
+    (Inductance, Duration) => {};
+    (Current, Conductance) => {};
+    (Current, Voltage) => {};
+    (ElectricCharge, Voltage) => {};
+    (ElectricCharge, Capacitance) => {};
+    (TorqueEnergy, Length) => {};
+    (TorqueEnergy, Force) => {};
+    (Torque, Length) => {};
+    (Torque, Force) => {};
+    (Energy, Length) => {};
+    (Energy, Force) => {};
+    (VolumetricFlowRate, Area) => {};
+    (VolumetricFlowRate, Speed) => {};
+    (Length, Length) => {};
+    (Area, Area) => {};
+    (Volume, Volume) => {};
+    (Mass, Mass) => {};
+    (Duration, Duration) => {};
+    (TemperatureDelta, TemperatureDelta) => {};
+    (Speed, Speed) => {};
+    (Acceleration, Acceleration) => {};
+    (Force, Force) => {};
+    (Pressure, Pressure) => {};
+    (Energy, Energy) => {};
+    (Torque, Torque) => {};
+    (TorqueEnergy, TorqueEnergy) => {};
+    (Power, Power) => {};
+    (Voltage, Voltage) => {};
+    (Current, Current) => {};
+    (Resistance, Resistance) => {};
+    (Frequency, Frequency) => {};
+    (Angle, Angle) => {};
+    (Density, Density) => {};
+    (VolumetricFlowRate, VolumetricFlowRate) => {};
+    (MassFlowRate, MassFlowRate) => {};
+    (ElectricCharge, ElectricCharge) => {};
+    (Capacitance, Capacitance) => {};
+    (Inductance, Inductance) => {};
+    (Conductance, Conductance) => {};
+    (ApparentPower, ApparentPower) => {};
+    (ReactivePower, ReactivePower) => {};
+    ($a:ident, $b:ident) => {
+        impl ::std::ops::Div<$b> for $a {
+            type Output = Quantity<
+                <<$a as Dimensioned>::Dimension as Quotient<<$b as Dimensioned>::Dimension>>::Output,
+            >;
+
+            fn div(self, rhs: $b) -> Self::Output {
+                self.to_quantity() / rhs.to_quantity()
+            }
+        }
+    };
+}
+
+implement_named_products! {
+    Length Area Volume Mass Duration TemperatureDelta Speed Acceleration Force Pressure
+    Energy Torque TorqueEnergy Power Voltage Current Resistance Frequency Angle Density
+    VolumetricFlowRate MassFlowRate ElectricCharge Capacitance Inductance Conductance
+    ApparentPower ReactivePower
+}
+
+#[cfg(test)]
+mod test {
+    use quantity::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn named_relations() {
+        let f: Force = (Mass::from_kilograms(2.0)
+            * Acceleration::from_meters_per_second_per_second(3.0).to_quantity())
+        .to();
+        assert_almost_eq(f.as_newtons(), 6.0);
+        let p = Voltage::from_volts(12.0).to_quantity() * Current::from_amperes(2.0);
+        assert_almost_eq(p.to::<Power>().as_watts(), 24.0);
+    }
+
+    #[test]
+    fn new_relations() {
+        let mass = Mass::from_kilograms(2.0).to_quantity();
+        let length = Length::from_meters(3.0);
+        let moment = mass * length * length;
+        assert_almost_eq(moment.value(), 18.0);
+        let specific_energy = Energy::from_joules(10.0) / mass;
+        assert_eq!(
+            format!("{}", specific_energy),
+            "5\u{00A0}m\u{00B2}\u{00B7}s\u{207B}\u{00B2}"
+        );
+    }
+
+    #[test]
+    fn named_products() {
+        let mass = Mass::from_kilograms(2.0);
+        let length = Length::from_meters(3.0);
+        assert_almost_eq((mass * length).value(), 6.0);
+        assert_almost_eq((mass / length).value(), 2.0 / 3.0);
+        let energy: Energy = (Pressure::from_pascals(4.0) * Volume::from_cubic_meters(2.0)).to();
+        assert_almost_eq(energy.as_joules(), 8.0);
+        // A named quotient is unchanged.
+        let p = Force::from_newtons(6.0) / Area::from_square_meters(2.0);
+        assert_almost_eq(p.as_pascals(), 3.0);
+    }
+
+    #[test]
+    fn volume() {
+        let l = Length::from_meters(2.0).to_quantity();
+        let v: Volume = (l * l * l).to();
+        assert_almost_eq(v.as_cubic_meters(), 8.0);
+        assert_almost_eq(v.to_quantity().value(), 8.0);
+    }
+
+    #[test]
+    fn ratio() {
+        let a = Length::from_meters(6.0).to_quantity();
+        let b = Length::from_meters(3.0).to_quantity();
+        assert_almost_eq(f64::from(a / b), 2.0);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            format!("{}", Force::from_newtons(2.0).to_quantity()),
+            "2\u{00A0}N"
+        );
+        let q = Mass::from_kilograms(1.0)
+            / (Length::from_meters(1.0).to_quantity() * time::Duration::new(1, 0));
+        assert_eq!(
+            format!("{}", q),
+            "1\u{00A0}kg\u{00B7}m\u{207B}\u{00B9}\u{00B7}s\u{207B}\u{00B9}"
+        );
+    }
+
+    #[test]
+    fn ops() {
+        let a = Length::from_meters(2.0).to_quantity();
+        let b = Length::from_meters(4.0).to_quantity();
+        assert_eq!((a + b).value(), 6.0);
+        assert_eq!((a - b).value(), -2.0);
+        assert_eq!((-a).value(), -2.0);
+        assert_eq!((a * 3.0).value(), 6.0);
+        assert_eq!((3.0 * a).value(), 6.0);
+        assert_eq!((b / 2.0).value(), 2.0);
+        assert!(a < b);
+        assert_eq!(a, Quantity::from(Length::from_meters(2.0)));
+    }
+}
@@ -0,0 +1,160 @@
+//! Types and constants for handling reactive power in AC circuits.
+
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+
+/// The `ReactivePower` struct can be used to deal with the reactive power of
+/// an AC circuit, which flows back and forth between source and load without
+/// doing any work, in a common way. It is positive for an inductive load,
+/// where the current lags the voltage, and negative for a capacitive one.
+///
+/// # Example
+///
+/// ```
+/// use measurements::ReactivePower;
+///
+/// let q = ReactivePower::from_kilovars(1.2);
+/// println!("The motor draws {}", q);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ReactivePower {
+    vars: f64,
+}
+
+impl ReactivePower {
+    /// Create a new ReactivePower from a floating point value in
+    /// volt-amperes reactive (var)
+    pub fn from_vars(vars: f64) -> Self {
+        ReactivePower { vars }
+    }
+
+    /// Create a new ReactivePower from a floating point value in kilovars
+    /// (kvar)
+    pub fn from_kilovars(kilovars: f64) -> Self {
+        Self::from_vars(kilovars * 1e3)
+    }
+
+    /// Create a new ReactivePower from a floating point value in megavars
+    /// (Mvar)
+    pub fn from_megavars(megavars: f64) -> Self {
+        Self::from_vars(megavars * 1e6)
+    }
+
+    /// Convert this ReactivePower into a floating point value in
+    /// volt-amperes reactive (var)
+    pub fn as_vars(&self) -> f64 {
+        self.vars
+    }
+
+    /// Convert this ReactivePower into a floating point value in kilovars
+    /// (kvar)
+    pub fn as_kilovars(&self) -> f64 {
+        self.vars / 1e3
+    }
+
+    /// Convert this ReactivePower into a floating point value in megavars
+    /// (Mvar)
+    pub fn as_megavars(&self) -> f64 {
+        self.vars / 1e6
+    }
+}
+
+impl Measurement for ReactivePower {
+    fn as_base_units(&self) -> f64 {
+        self.vars
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_vars(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "var"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("mvar", 1e-3),
+            ("var", 1e0),
+            ("kvar", 1e3),
+            ("Mvar", 1e6),
+            ("Gvar", 1e9),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { ReactivePower }
+
+implement_units! {
+    ReactivePower, ReactivePowerUnit {
+        Var => ("var", "var", "vars", 1.0) in Si,
+        Kilovar => ("kvar", "kilovar", "kilovars", 1e3) in Si,
+        Megavar => ("Mvar", "megavar", "megavars", 1e6) in Si,
+    }
+}
+
+/// The unit names understood when parsing a `ReactivePower`.
+const UNIT_NAMES: &[UnitConstructor<'static, ReactivePower>] = &[
+    ("mvar", |v| ReactivePower::from_vars(v * 1e-3)),
+    ("var", ReactivePower::from_vars),
+    ("kvar", ReactivePower::from_kilovars),
+    ("Mvar", ReactivePower::from_megavars),
+    ("Gvar", |v| ReactivePower::from_vars(v * 1e9)),
+    ("VAr", ReactivePower::from_vars),
+    ("kVAr", ReactivePower::from_kilovars),
+    ("MVAr", ReactivePower::from_megavars),
+    ("vars", ReactivePower::from_vars),
+    ("kilovar", ReactivePower::from_kilovars),
+    ("kilovars", ReactivePower::from_kilovars),
+    ("megavar", ReactivePower::from_megavars),
+    ("megavars", ReactivePower::from_megavars),
+];
+
+impl UnitNames for ReactivePower {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for ReactivePower {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use reactive_power::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn as_vars() {
+        let q = ReactivePower::from_kilovars(2.5);
+        assert_almost_eq(q.as_vars(), 2500.0);
+        assert_almost_eq(q.as_megavars(), 0.0025);
+    }
+
+    #[test]
+    fn display() {
+        let q = ReactivePower::from_vars(-1500.0);
+        assert_eq!(format!("{}", q), "-1.5\u{00A0}kvar");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: ReactivePower = "20 kvar".parse().unwrap();
+        let b: ReactivePower = "350 VAr".parse().unwrap();
+        assert_almost_eq(a.as_vars(), 20_000.0);
+        assert_almost_eq(b.as_vars(), 350.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = ReactivePower::from_unit(1.0, ReactivePowerUnit::Megavar);
+        assert_almost_eq(a.as_unit(ReactivePowerUnit::Kilovar), 1000.0);
+    }
+}
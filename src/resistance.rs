@@ -1,6 +1,10 @@
 //! Types and constants for handling electrical resistance.
 
+use super::conductance::Conductance;
+use super::e_series::ESeries;
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::voltage::Voltage;
 
 /// The `Resistance` struct can be used to deal with electrical resistance in a
 /// common way.
@@ -36,6 +40,11 @@ impl Resistance {
         Self::from_ohms(megaohms * 1000.0 * 1000.0)
     }
 
+    /// Create a new Resistance from the reciprocal of a Conductance
+    pub fn from_conductance(conductance: Conductance) -> Self {
+        Self::from_ohms(1.0 / conductance.as_siemens())
+    }
+
     /// Convert this Resistance into a floating point value in ohms
     pub fn as_ohms(&self) -> f64 {
         self.ohms
@@ -50,6 +59,53 @@ impl Resistance {
     pub fn as_megaohms(&self) -> f64 {
         self.ohms / 1000.0 / 1000.0
     }
+
+    /// Convert this Resistance into its reciprocal, a Conductance
+    pub fn as_conductance(&self) -> Conductance {
+        Conductance::from_siemens(1.0 / self.ohms)
+    }
+
+    /// Create a new Resistance from resistors connected in series
+    pub fn series<I: IntoIterator<Item = Resistance>>(resistances: I) -> Self {
+        Self::from_ohms(resistances.into_iter().fold(0.0, |sum, r| sum + r.ohms))
+    }
+
+    /// Create a new Resistance from resistors connected in parallel. No
+    /// resistors at all are an open circuit, with infinite resistance.
+    pub fn parallel<I: IntoIterator<Item = Resistance>>(resistances: I) -> Self {
+        Self::from_ohms(1.0 / resistances.into_iter().fold(0.0, |sum, r| sum + 1.0 / r.ohms))
+    }
+
+    /// The output voltage of an unloaded voltage divider, with `top` between
+    /// the input and the output and `bottom` between the output and ground.
+    pub fn voltage_divider(input: Voltage, top: Resistance, bottom: Resistance) -> Voltage {
+        input * (bottom.ohms / (top.ohms + bottom.ohms))
+    }
+
+    /// The resistance needed between the output of an unloaded voltage
+    /// divider and ground to get `output` from `input`, given `top` between
+    /// the input and the output.
+    pub fn divider_bottom(input: Voltage, output: Voltage, top: Resistance) -> Self {
+        top * (output.as_volts() / (input - output).as_volts())
+    }
+
+    /// The resistance needed between the input and output of an unloaded
+    /// voltage divider to get `output` from `input`, given `bottom` between
+    /// the output and ground.
+    pub fn divider_top(input: Voltage, output: Voltage, bottom: Resistance) -> Self {
+        bottom * ((input - output).as_volts() / output.as_volts())
+    }
+
+    /// Round this Resistance to the nearest preferred value in an E-series,
+    /// also returning the relative error of that value. A 4.6 kΩ resistance
+    /// rounds to 4.7 kΩ in E12, an error of about +0.022.
+    pub fn to_e_series(&self, series: ESeries) -> (Resistance, f64) {
+        let preferred = series.nearest(self.ohms);
+        (
+            Resistance::from_ohms(preferred),
+            (preferred - self.ohms) / self.ohms,
+        )
+    }
 }
 
 impl Measurement for Resistance {
@@ -87,6 +143,58 @@ impl Measurement for Resistance {
 
 implement_measurement! { Resistance }
 
+implement_units! {
+    Resistance, ResistanceUnit {
+        Ohm => ("\u{2126}", "ohm", "ohms", 1.0) in Si,
+        Kiloohm => ("k\u{2126}", "kiloohm", "kiloohms", 1e3) in Si,
+        Megaohm => ("M\u{2126}", "megaohm", "megaohms", 1e6) in Si,
+    }
+}
+
+/// The unit names understood when parsing a `Resistance`.
+const UNIT_NAMES: &[UnitConstructor<'static, Resistance>] = &[
+    ("f\u{2126}", |v| Resistance::from_ohms(v * 1e-15)),
+    ("p\u{2126}", |v| Resistance::from_ohms(v * 1e-12)),
+    ("n\u{2126}", |v| Resistance::from_ohms(v * 1e-9)),
+    ("\u{00B5}\u{2126}", |v| Resistance::from_ohms(v * 1e-6)),
+    ("m\u{2126}", |v| Resistance::from_ohms(v * 1e-3)),
+    ("\u{2126}", Resistance::from_ohms),
+    ("k\u{2126}", |v| Resistance::from_ohms(v * 1e3)),
+    ("M\u{2126}", |v| Resistance::from_ohms(v * 1e6)),
+    ("G\u{2126}", |v| Resistance::from_ohms(v * 1e9)),
+    ("T\u{2126}", |v| Resistance::from_ohms(v * 1e12)),
+    ("P\u{2126}", |v| Resistance::from_ohms(v * 1e15)),
+    ("E\u{2126}", |v| Resistance::from_ohms(v * 1e18)),
+    ("m\u{03A9}", |v| Resistance::from_ohms(v * 1e-3)),
+    ("\u{03A9}", Resistance::from_ohms),
+    ("k\u{03A9}", |v| Resistance::from_ohms(v * 1e3)),
+    ("M\u{03A9}", |v| Resistance::from_ohms(v * 1e6)),
+    ("ohm", Resistance::from_ohms),
+    ("ohms", Resistance::from_ohms),
+    ("kiloohm", Resistance::from_kiloohms),
+    ("kiloohms", Resistance::from_kiloohms),
+    ("kilohm", Resistance::from_kiloohms),
+    ("kilohms", Resistance::from_kiloohms),
+    ("megaohm", Resistance::from_megaohms),
+    ("megaohms", Resistance::from_megaohms),
+    ("megohm", Resistance::from_megaohms),
+    ("megohms", Resistance::from_megaohms),
+];
+
+impl UnitNames for Resistance {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Resistance {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use resistance::*;
@@ -167,4 +275,51 @@ mod test {
         assert_eq!(a > b, false);
         assert_eq!(a >= b, false);
     }
+
+    #[test]
+    fn from_str() {
+        let a: Resistance = "4.7 k\u{2126}".parse().unwrap();
+        let b: Resistance = "10 ohms".parse().unwrap();
+        assert_almost_eq(a.as_ohms(), 4700.0);
+        assert_almost_eq(b.as_ohms(), 10.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Resistance::from_unit(4.7, ResistanceUnit::Kiloohm);
+        assert_almost_eq(a.as_ohms(), 4700.0);
+        assert_almost_eq(a.as_unit(ResistanceUnit::Megaohm), 0.0047);
+    }
+
+    #[test]
+    fn series_parallel() {
+        let r = [Resistance::from_ohms(100.0), Resistance::from_ohms(300.0)];
+        assert_almost_eq(Resistance::series(r.iter().cloned()).as_ohms(), 400.0);
+        assert_almost_eq(Resistance::parallel(r.iter().cloned()).as_ohms(), 75.0);
+        assert_eq!(Resistance::series(None).as_ohms(), 0.0);
+        assert_eq!(Resistance::parallel(None).as_ohms(), f64::INFINITY);
+    }
+
+    #[test]
+    fn voltage_divider() {
+        let input = Voltage::from_volts(12.0);
+        let top = Resistance::from_kiloohms(10.0);
+        let bottom = Resistance::from_kiloohms(5.0);
+        let output = Resistance::voltage_divider(input, top, bottom);
+        assert_almost_eq(output.as_volts(), 4.0);
+        let b = Resistance::divider_bottom(input, output, top);
+        assert_almost_eq(b.as_kiloohms(), 5.0);
+        let t = Resistance::divider_top(input, output, bottom);
+        assert_almost_eq(t.as_kiloohms(), 10.0);
+    }
+
+    #[test]
+    fn e_series() {
+        let (r, error) = Resistance::from_ohms(4600.0).to_e_series(ESeries::E12);
+        assert_almost_eq(r.as_kiloohms(), 4.7);
+        assert_almost_eq(error, 100.0 / 4600.0);
+        let (r, error) = Resistance::from_ohms(1234.0).to_e_series(ESeries::E96);
+        assert_almost_eq(r.as_ohms(), 1240.0);
+        assert!(error.abs() < ESeries::E96.tolerance());
+    }
 }
@@ -0,0 +1,181 @@
+//! Implements `Serialize` and `Deserialize` for every quantity, when the
+//! `serde` feature is enabled.
+//!
+//! Formats which are not human readable store the quantity as a single
+//! floating point value in base units. Human readable formats store it as a
+//! string such as "1.5 m". When deserializing from a human readable format,
+//! a bare number (in base units), a string in any form accepted by `FromStr`
+//! ("5 ft", "3'6\"") or a map such as `{ value = 5, unit = "ft" }` are all
+//! accepted.
+
+use super::*;
+use parse::{lookup_unit, UnitNames};
+use serde::de::{self, DeserializeSeed, Deserializer, MapAccess, Visitor};
+use serde::ser::Serializer;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Serialize a measurement as described in the module documentation.
+fn serialize<T, S>(measurement: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Measurement,
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        serializer.collect_str(&format_args!(
+            "{} {}",
+            measurement.as_base_units(),
+            measurement.get_base_units_name()
+        ))
+    } else {
+        serializer.serialize_f64(measurement.as_base_units())
+    }
+}
+
+/// Deserialize a measurement as described in the module documentation.
+fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Measurement + UnitNames + FromStr<Err = ParseMeasurementError>,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(MeasurementVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_f64(MeasurementVisitor(PhantomData))
+    }
+}
+
+struct MeasurementVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for MeasurementVisitor<T>
+where
+    T: Measurement + UnitNames + FromStr<Err = ParseMeasurementError>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number, a string such as \"5 ft\" or a map with a value and a unit")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<T, E> {
+        Ok(T::from_base_units(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<T, E> {
+        Ok(T::from_base_units(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<T, E> {
+        Ok(T::from_base_units(v as f64))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        let mut value = None;
+        let mut unit = None;
+        while let Some(field) = map.next_key::<Field>()? {
+            match field {
+                Field::Value => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field("value"));
+                    }
+                    value = Some(map.next_value::<f64>()?);
+                }
+                Field::Unit => {
+                    if unit.is_some() {
+                        return Err(de::Error::duplicate_field("unit"));
+                    }
+                    unit = Some(map.next_value_seed(UnitSeed::<T>(PhantomData))?);
+                }
+            }
+        }
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+        Ok(T::unit_names()[unit].1(value))
+    }
+}
+
+/// The keys of the map form.
+enum Field {
+    Value,
+    Unit,
+}
+
+const FIELDS: &[&str] = &["value", "unit"];
+
+impl<'de> de::Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Field, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("`value` or `unit`")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+                match v {
+                    "value" => Ok(Field::Value),
+                    "unit" => Ok(Field::Unit),
+                    _ => Err(de::Error::unknown_field(v, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// Looks a unit name up while it is being deserialized, so the name never
+/// needs to be stored. Produces the index of the unit in `T::unit_names()`.
+struct UnitSeed<T>(PhantomData<T>);
+
+impl<'de, T: UnitNames> DeserializeSeed<'de> for UnitSeed<T> {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, T: UnitNames> Visitor<'de> for UnitSeed<T> {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a unit name")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<usize, E> {
+        lookup_unit(v, T::unit_names())
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+macro_rules! implement_serde {
+    ($($t:ty)*) => ($(
+        impl ::serde::Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize(self, serializer)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserialize(deserializer)
+            }
+        }
+    )*)
+}
+
+implement_serde! {
+    Acceleration Angle AngularVelocity ApparentPower Area Capacitance
+    Conductance Current Data DataRate Density ElectricCharge Energy Force Frequency
+    Inductance Length Mass MassFlowRate Power Pressure ReactivePower
+    Resistance Speed Temperature TemperatureDelta Torque Voltage Volume
+    VolumetricFlowRate
+}
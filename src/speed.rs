@@ -1,6 +1,7 @@
 //! Types and constants for handling speed.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 use super::*;
 
 /// Number of seconds in a minute
@@ -113,6 +114,48 @@ impl Measurement for Speed {
 
 implement_measurement! { Speed }
 
+implement_units! {
+    Speed, SpeedUnit {
+        MeterPerSecond => ("m/s", "meter per second", "meters per second", 1.0) in Si,
+        KilometerPerHour => ("km/h", "kilometer per hour", "kilometers per hour", 1.0 / length::METER_KILOMETER_FACTOR / SECONDS_HOURS_FACTOR) in Si,
+        MilePerHour => ("mph", "mile per hour", "miles per hour", 1609.0 / 3600.0) in UsCustomary | Imperial,
+    }
+}
+
+/// The unit names understood when parsing a `Speed`.
+const UNIT_NAMES: &[UnitConstructor<'static, Speed>] = &[
+    ("nm/s", |v| Speed::from_meters_per_second(v * 1e-9)),
+    ("\u{00B5}m/s", |v| Speed::from_meters_per_second(v * 1e-6)),
+    ("mm/s", |v| Speed::from_meters_per_second(v * 1e-3)),
+    ("m/s", Speed::from_meters_per_second),
+    ("km/s", |v| Speed::from_meters_per_second(v * 1e3)),
+    ("thousand km/s", |v| Speed::from_meters_per_second(v * 1e6)),
+    ("million km/s", |v| Speed::from_meters_per_second(v * 1e9)),
+    ("km/h", Speed::from_kilometers_per_hour),
+    ("kph", Speed::from_kilometers_per_hour),
+    ("mph", Speed::from_miles_per_hour),
+    ("mi/h", Speed::from_miles_per_hour),
+    ("meters per second", Speed::from_meters_per_second),
+    ("metres per second", Speed::from_meters_per_second),
+    ("kilometers per hour", Speed::from_kilometers_per_hour),
+    ("kilometres per hour", Speed::from_kilometers_per_hour),
+    ("miles per hour", Speed::from_miles_per_hour),
+];
+
+impl UnitNames for Speed {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Speed {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use speed::*;
@@ -238,4 +281,18 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Speed = "100 km/h".parse().unwrap();
+        let b: Speed = "60 mph".parse().unwrap();
+        assert_almost_eq(a.as_kilometers_per_hour(), 100.0);
+        assert_almost_eq(b.as_miles_per_hour(), 60.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Speed::from_unit(36.0, SpeedUnit::KilometerPerHour);
+        assert_almost_eq(a.as_meters_per_second(), 10.0);
+        assert_almost_eq(a.as_unit(SpeedUnit::MeterPerSecond), 10.0);
+    }
 }
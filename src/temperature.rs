@@ -1,6 +1,10 @@
 //! Types and constants for handling temperature.
 
 use super::measurement::*;
+use super::unit::Unit;
+use super::parse::{
+    parse_measurement, parse_single_measurement, ParseMeasurementError, UnitConstructor, UnitNames,
+};
 
 /// The `Temperature` struct can be used to deal with absolute temperatures in
 /// a common way.
@@ -83,6 +87,18 @@ impl TemperatureDelta {
     pub fn as_rankine(&self) -> f64 {
         self.kelvin_degrees * 1.8
     }
+
+    /// Create a new TemperatureDelta from a floating point value in the given
+    /// unit. Only the size of the unit matters here, not where its zero is.
+    pub fn from_unit(value: f64, unit: TemperatureUnit) -> Self {
+        TemperatureDelta::from_kelvin(value * unit.factor())
+    }
+
+    /// Convert this TemperatureDelta into a floating point value in the given
+    /// unit.
+    pub fn as_unit(&self, unit: TemperatureUnit) -> f64 {
+        self.kelvin_degrees / unit.factor()
+    }
 }
 
 impl Temperature {
@@ -205,6 +221,79 @@ impl ::std::cmp::PartialOrd for Temperature {
 implement_display!(Temperature);
 implement_measurement!(TemperatureDelta);
 
+implement_units! {
+    Temperature, TemperatureUnit {
+        Kelvin => ("K", "kelvin", "kelvin", 1.0),
+        Celsius => ("\u{00B0}C", "degree Celsius", "degrees Celsius", 1.0, 273.15) in Si,
+        Fahrenheit => ("\u{00B0}F", "degree Fahrenheit", "degrees Fahrenheit", 1.0 / 1.8, 273.15 - 32.0 / 1.8) in UsCustomary | Imperial,
+        Rankine => ("\u{00B0}R", "degree Rankine", "degrees Rankine", 1.0 / 1.8),
+    }
+}
+
+/// The unit names understood when parsing a `Temperature`.
+const TEMPERATURE_UNIT_NAMES: &[UnitConstructor<'static, Temperature>] = &[
+    ("K", Temperature::from_kelvin),
+    ("kelvin", Temperature::from_kelvin),
+    ("\u{00B0}C", Temperature::from_celsius),
+    ("C", Temperature::from_celsius),
+    ("celsius", Temperature::from_celsius),
+    ("degrees celsius", Temperature::from_celsius),
+    ("\u{00B0}F", Temperature::from_fahrenheit),
+    ("F", Temperature::from_fahrenheit),
+    ("fahrenheit", Temperature::from_fahrenheit),
+    ("degrees fahrenheit", Temperature::from_fahrenheit),
+    ("\u{00B0}R", Temperature::from_rankine),
+    ("R", Temperature::from_rankine),
+    ("rankine", Temperature::from_rankine),
+    ("degrees rankine", Temperature::from_rankine),
+];
+
+impl UnitNames for Temperature {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        TEMPERATURE_UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Temperature {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_single_measurement(s, TEMPERATURE_UNIT_NAMES)
+    }
+}
+
+/// The unit names understood when parsing a `TemperatureDelta`.
+const TEMPERATURE_DELTA_UNIT_NAMES: &[UnitConstructor<'static, TemperatureDelta>] = &[
+    ("K", TemperatureDelta::from_kelvin),
+    ("kelvin", TemperatureDelta::from_kelvin),
+    ("\u{00B0}C", TemperatureDelta::from_celsius),
+    ("C", TemperatureDelta::from_celsius),
+    ("celsius", TemperatureDelta::from_celsius),
+    ("degrees celsius", TemperatureDelta::from_celsius),
+    ("\u{00B0}F", TemperatureDelta::from_fahrenheit),
+    ("F", TemperatureDelta::from_fahrenheit),
+    ("fahrenheit", TemperatureDelta::from_fahrenheit),
+    ("degrees fahrenheit", TemperatureDelta::from_fahrenheit),
+    ("\u{00B0}R", TemperatureDelta::from_rankine),
+    ("R", TemperatureDelta::from_rankine),
+    ("rankine", TemperatureDelta::from_rankine),
+    ("degrees rankine", TemperatureDelta::from_rankine),
+];
+
+impl UnitNames for TemperatureDelta {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        TEMPERATURE_DELTA_UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for TemperatureDelta {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, TEMPERATURE_DELTA_UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use temperature::*;
@@ -321,4 +410,21 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn from_str() {
+        let t: Temperature = "100 \u{00B0}C".parse().unwrap();
+        let d: TemperatureDelta = "9 \u{00B0}F".parse().unwrap();
+        assert_almost_eq(t.as_kelvin(), 373.15);
+        assert_almost_eq(d.as_kelvin(), 5.0);
+        assert!("1 \u{00B0}C 2 \u{00B0}C".parse::<Temperature>().is_err());
+    }
+
+    #[test]
+    fn units() {
+        let t = Temperature::from_unit(212.0, TemperatureUnit::Fahrenheit);
+        assert_almost_eq(t.as_celsius(), 100.0);
+        assert_almost_eq(t.as_unit(TemperatureUnit::Rankine), 671.67);
+        let d = TemperatureDelta::from_unit(9.0, TemperatureUnit::Fahrenheit);
+        assert_almost_eq(d.as_unit(TemperatureUnit::Celsius), 5.0);
+    }
 }
@@ -0,0 +1,591 @@
+//! Lengths with a tolerance, ISO 286 fits and tolerance stack-ups.
+//!
+//! A `TolerancedLength` is a nominal size with an upper and a lower
+//! deviation, such as a 25 mm bore which may be up to 0.021 mm oversize. It
+//! can be given directly, or from an ISO 286 tolerance class such as "H7" or
+//! "g6". A `Fit` pairs a hole with a shaft and reports the clearance between
+//! them.
+//!
+//! A chain of dimensions is stacked up by adding (or subtracting) toleranced
+//! lengths. Adding them gives the worst case, where every part is at the
+//! same limit at once; `rss` gives the statistical (root sum of squares)
+//! result, which assumes independent, centred variations.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::Length;
+//! use measurements::tolerance::{Fit, TolerancedLength};
+//!
+//! let fit = Fit::iso_286(Length::from_millimeters(25.0), "H7/g6").unwrap();
+//! assert_eq!(fit.hole().to_string(), "25.00 +0.021/-0.000\u{00A0}mm");
+//! assert!((fit.max_clearance().as_millimeters() - 0.041).abs() < 1e-9);
+//!
+//! let plate = TolerancedLength::symmetric(Length::from_millimeters(10.0), Length::from_millimeters(0.1));
+//! let stack = plate + plate + plate;
+//! assert!((stack.upper_deviation().as_millimeters() - 0.3).abs() < 1e-9);
+//! ```
+
+use super::length::Length;
+use std::fmt;
+
+/// A nominal length with upper and lower limits, given as deviations from
+/// the nominal size.
+#[derive(Copy, Clone, Debug)]
+pub struct TolerancedLength {
+    nominal: Length,
+    upper_deviation: Length,
+    lower_deviation: Length,
+}
+
+impl TolerancedLength {
+    /// Create a new TolerancedLength from a nominal size and its upper and
+    /// lower deviations. The deviations are signed, so a shaft which may be
+    /// 0.007 to 0.020 mm undersize has deviations of -0.007 mm and -0.020 mm.
+    /// They are swapped if given the wrong way around.
+    pub fn new(nominal: Length, upper_deviation: Length, lower_deviation: Length) -> Self {
+        if upper_deviation < lower_deviation {
+            TolerancedLength::new(nominal, lower_deviation, upper_deviation)
+        } else {
+            TolerancedLength {
+                nominal,
+                upper_deviation,
+                lower_deviation,
+            }
+        }
+    }
+
+    /// Create a new TolerancedLength which may vary by `tolerance` either
+    /// side of the nominal size.
+    pub fn symmetric(nominal: Length, tolerance: Length) -> Self {
+        TolerancedLength::new(nominal, tolerance, tolerance * -1.0)
+    }
+
+    /// Create a new TolerancedLength with no tolerance.
+    pub fn exact(nominal: Length) -> Self {
+        let zero = Length::from_meters(0.0);
+        TolerancedLength::new(nominal, zero, zero)
+    }
+
+    /// Create a new TolerancedLength from an ISO 286 tolerance class, such
+    /// as "H7" for a hole or "g6" for a shaft.
+    ///
+    /// Nominal sizes up to 500 mm are supported, with grades IT1 to IT18.
+    /// Holes may have the fundamental deviations D, E, F, G, H and JS, and
+    /// shafts d, e, f, g, h, js, k, m, n and p, which covers the preferred
+    /// hole-basis fits from loose running (H9/d9) to press (H7/p6).
+    pub fn iso_286(nominal: Length, class: &str) -> Result<Self, Iso286Error> {
+        let class = class.trim();
+        let split = class
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or(Iso286Error::InvalidClass)?;
+        let (letters, grade) = class.split_at(split);
+        let grade: usize = grade.parse().map_err(|_| Iso286Error::InvalidClass)?;
+        if !(1..=18).contains(&grade) {
+            return Err(Iso286Error::InvalidClass);
+        }
+        let size = nominal.as_millimeters();
+        let range = SIZE_RANGES
+            .iter()
+            .position(|&upper| size <= upper)
+            .filter(|_| size > 0.0)
+            .ok_or(Iso286Error::SizeOutOfRange)?;
+        let it = STANDARD_TOLERANCES[range][grade - 1];
+
+        // Deviations in micrometres.
+        let mut chars = letters.chars();
+        let (upper, lower) = match (chars.next(), chars.next(), chars.next()) {
+            (Some('J'), Some('S'), None) | (Some('j'), Some('s'), None) => (it / 2.0, -it / 2.0),
+            (Some(hole), None, None) if hole.is_ascii_uppercase() => {
+                // Holes A to H mirror the shaft of the same letter.
+                let es = shaft_upper_deviation(hole.to_ascii_lowercase(), range)
+                    .ok_or(Iso286Error::UnsupportedDeviation)?;
+                (-es + it, -es)
+            }
+            (Some(shaft), None, None) => {
+                if let Some(es) = shaft_upper_deviation(shaft, range) {
+                    (es, es - it)
+                } else {
+                    let ei = shaft_lower_deviation(shaft, range, grade)
+                        .ok_or(Iso286Error::UnsupportedDeviation)?;
+                    (ei + it, ei)
+                }
+            }
+            _ => return Err(Iso286Error::UnsupportedDeviation),
+        };
+        Ok(TolerancedLength::new(
+            nominal,
+            Length::from_micrometers(upper),
+            Length::from_micrometers(lower),
+        ))
+    }
+
+    /// The nominal size.
+    pub fn nominal(&self) -> Length {
+        self.nominal
+    }
+
+    /// The difference between the largest permitted size and the nominal
+    /// size.
+    pub fn upper_deviation(&self) -> Length {
+        self.upper_deviation
+    }
+
+    /// The difference between the smallest permitted size and the nominal
+    /// size.
+    pub fn lower_deviation(&self) -> Length {
+        self.lower_deviation
+    }
+
+    /// The largest permitted size.
+    pub fn max(&self) -> Length {
+        self.nominal + self.upper_deviation
+    }
+
+    /// The smallest permitted size.
+    pub fn min(&self) -> Length {
+        self.nominal + self.lower_deviation
+    }
+
+    /// The size halfway between the limits.
+    pub fn mean(&self) -> Length {
+        (self.max() + self.min()) / 2.0
+    }
+
+    /// The total tolerance, which is the difference between the limits.
+    pub fn tolerance(&self) -> Length {
+        self.upper_deviation - self.lower_deviation
+    }
+
+    /// Whether `length` lies within the limits.
+    pub fn contains(&self, length: Length) -> bool {
+        length >= self.min() && length <= self.max()
+    }
+
+    /// Stack up a chain of toleranced lengths statistically, by adding half
+    /// of each tolerance in quadrature (root sum of squares) around the sum
+    /// of their mean sizes. Subtract a dimension from the chain by negating
+    /// it.
+    #[cfg(not(feature = "no_std"))]
+    pub fn rss<I: IntoIterator<Item = TolerancedLength>>(chain: I) -> TolerancedLength {
+        let mut nominal = 0.0;
+        let mut mean_deviation = 0.0;
+        let mut variance = 0.0;
+        for length in chain {
+            let half = length.tolerance().as_meters() / 2.0;
+            nominal += length.nominal.as_meters();
+            mean_deviation += length.upper_deviation.as_meters() - half;
+            variance += half * half;
+        }
+        let half = variance.sqrt();
+        TolerancedLength::new(
+            Length::from_meters(nominal),
+            Length::from_meters(mean_deviation + half),
+            Length::from_meters(mean_deviation - half),
+        )
+    }
+
+    /// Stack up a chain of toleranced lengths for the worst case, where every
+    /// dimension is at the same limit at once. This is the same as adding
+    /// them together.
+    pub fn worst_case<I: IntoIterator<Item = TolerancedLength>>(chain: I) -> TolerancedLength {
+        chain
+            .into_iter()
+            .fold(TolerancedLength::exact(Length::from_meters(0.0)), |a, b| {
+                a + b
+            })
+    }
+}
+
+impl ::std::ops::Add for TolerancedLength {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        TolerancedLength::new(
+            self.nominal + rhs.nominal,
+            self.upper_deviation + rhs.upper_deviation,
+            self.lower_deviation + rhs.lower_deviation,
+        )
+    }
+}
+
+impl ::std::ops::Sub for TolerancedLength {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl ::std::ops::Neg for TolerancedLength {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        TolerancedLength::new(
+            self.nominal * -1.0,
+            self.lower_deviation * -1.0,
+            self.upper_deviation * -1.0,
+        )
+    }
+}
+
+impl ::std::ops::Add<Length> for TolerancedLength {
+    type Output = Self;
+
+    fn add(self, rhs: Length) -> Self {
+        self + TolerancedLength::exact(rhs)
+    }
+}
+
+impl ::std::ops::Sub<Length> for TolerancedLength {
+    type Output = Self;
+
+    fn sub(self, rhs: Length) -> Self {
+        self - TolerancedLength::exact(rhs)
+    }
+}
+
+impl ::std::cmp::PartialEq for TolerancedLength {
+    fn eq(&self, other: &Self) -> bool {
+        self.nominal == other.nominal
+            && self.upper_deviation == other.upper_deviation
+            && self.lower_deviation == other.lower_deviation
+    }
+}
+
+/// Formats as "25.00 +0.021/-0.000 mm", in millimetres as on a drawing. A
+/// precision applies to the deviations, and the nominal size is given to
+/// one fewer decimal place.
+impl fmt::Display for TolerancedLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(3);
+        let upper = self.upper_deviation.as_millimeters();
+        let lower = self.lower_deviation.as_millimeters();
+        write!(
+            f,
+            "{:.*} {}{:.*}/{}{:.*}\u{00A0}mm",
+            precision.saturating_sub(1),
+            self.nominal.as_millimeters(),
+            if upper < 0.0 { '-' } else { '+' },
+            precision,
+            upper.abs(),
+            if lower > 0.0 { '+' } else { '-' },
+            precision,
+            lower.abs()
+        )
+    }
+}
+
+/// The kind of fit between a hole and a shaft.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FitKind {
+    /// The shaft is always smaller than the hole.
+    Clearance,
+    /// The shaft may be smaller or larger than the hole.
+    Transition,
+    /// The shaft is always larger than the hole.
+    Interference,
+}
+
+/// A hole and the shaft which fits into it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Fit {
+    hole: TolerancedLength,
+    shaft: TolerancedLength,
+}
+
+impl Fit {
+    /// Create a new Fit from a hole and a shaft.
+    pub fn new(hole: TolerancedLength, shaft: TolerancedLength) -> Self {
+        Fit { hole, shaft }
+    }
+
+    /// Create a new Fit from an ISO 286 fit designation such as "H7/g6",
+    /// which gives the tolerance class of the hole and then of the shaft.
+    pub fn iso_286(nominal: Length, fit: &str) -> Result<Self, Iso286Error> {
+        let mut classes = fit.split('/');
+        match (classes.next(), classes.next(), classes.next()) {
+            (Some(hole), Some(shaft), None) => Ok(Fit::new(
+                TolerancedLength::iso_286(nominal, hole)?,
+                TolerancedLength::iso_286(nominal, shaft)?,
+            )),
+            _ => Err(Iso286Error::InvalidClass),
+        }
+    }
+
+    /// The hole.
+    pub fn hole(&self) -> TolerancedLength {
+        self.hole
+    }
+
+    /// The shaft.
+    pub fn shaft(&self) -> TolerancedLength {
+        self.shaft
+    }
+
+    /// The largest clearance, between the largest hole and the smallest
+    /// shaft. This is negative for an interference fit.
+    pub fn max_clearance(&self) -> Length {
+        self.hole.max() - self.shaft.min()
+    }
+
+    /// The smallest clearance, between the smallest hole and the largest
+    /// shaft. This is negative when the parts may interfere.
+    pub fn min_clearance(&self) -> Length {
+        self.hole.min() - self.shaft.max()
+    }
+
+    /// Whether this is a clearance, transition or interference fit.
+    pub fn kind(&self) -> FitKind {
+        let zero = Length::from_meters(0.0);
+        if self.min_clearance() >= zero {
+            FitKind::Clearance
+        } else if self.max_clearance() <= zero {
+            FitKind::Interference
+        } else {
+            FitKind::Transition
+        }
+    }
+}
+
+/// The error returned when an ISO 286 tolerance class cannot be used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Iso286Error {
+    /// The class is not a fundamental deviation followed by a grade from 1
+    /// to 18, such as "H7", or a fit is not two classes such as "H7/g6".
+    InvalidClass,
+    /// The fundamental deviation is not one of those supported.
+    UnsupportedDeviation,
+    /// The nominal size is not above 0 mm and up to 500 mm.
+    SizeOutOfRange,
+}
+
+impl fmt::Display for Iso286Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match *self {
+            Iso286Error::InvalidClass => "invalid ISO 286 tolerance class",
+            Iso286Error::UnsupportedDeviation => "unsupported ISO 286 fundamental deviation",
+            Iso286Error::SizeOutOfRange => "nominal size outside the ISO 286 range of 0 to 500 mm",
+        };
+        f.write_str(description)
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl ::std::error::Error for Iso286Error {}
+
+/// The upper limit, in mm, of each range of nominal sizes in ISO 286-1.
+const SIZE_RANGES: [f64; 13] = [
+    3.0, 6.0, 10.0, 18.0, 30.0, 50.0, 80.0, 120.0, 180.0, 250.0, 315.0, 400.0, 500.0,
+];
+
+/// Standard tolerance grades IT1 to IT18, in µm, for each size range.
+const STANDARD_TOLERANCES: [[f64; 18]; 13] = [
+    [
+        0.8, 1.2, 2.0, 3.0, 4.0, 6.0, 10.0, 14.0, 25.0, 40.0, 60.0, 100.0, 140.0, 250.0, 400.0,
+        600.0, 1000.0, 1400.0,
+    ],
+    [
+        1.0, 1.5, 2.5, 4.0, 5.0, 8.0, 12.0, 18.0, 30.0, 48.0, 75.0, 120.0, 180.0, 300.0, 480.0,
+        750.0, 1200.0, 1800.0,
+    ],
+    [
+        1.0, 1.5, 2.5, 4.0, 6.0, 9.0, 15.0, 22.0, 36.0, 58.0, 90.0, 150.0, 220.0, 360.0, 580.0,
+        900.0, 1500.0, 2200.0,
+    ],
+    [
+        1.2, 2.0, 3.0, 5.0, 8.0, 11.0, 18.0, 27.0, 43.0, 70.0, 110.0, 180.0, 270.0, 430.0, 700.0,
+        1100.0, 1800.0, 2700.0,
+    ],
+    [
+        1.5, 2.5, 4.0, 6.0, 9.0, 13.0, 21.0, 33.0, 52.0, 84.0, 130.0, 210.0, 330.0, 520.0, 840.0,
+        1300.0, 2100.0, 3300.0,
+    ],
+    [
+        1.5, 2.5, 4.0, 7.0, 11.0, 16.0, 25.0, 39.0, 62.0, 100.0, 160.0, 250.0, 390.0, 620.0,
+        1000.0, 1600.0, 2500.0, 3900.0,
+    ],
+    [
+        2.0, 3.0, 5.0, 8.0, 13.0, 19.0, 30.0, 46.0, 74.0, 120.0, 190.0, 300.0, 460.0, 740.0,
+        1200.0, 1900.0, 3000.0, 4600.0,
+    ],
+    [
+        2.5, 4.0, 6.0, 10.0, 15.0, 22.0, 35.0, 54.0, 87.0, 140.0, 220.0, 350.0, 540.0, 870.0,
+        1400.0, 2200.0, 3500.0, 5400.0,
+    ],
+    [
+        3.5, 5.0, 8.0, 12.0, 18.0, 25.0, 40.0, 63.0, 100.0, 160.0, 250.0, 400.0, 630.0, 1000.0,
+        1600.0, 2500.0, 4000.0, 6300.0,
+    ],
+    [
+        4.5, 7.0, 10.0, 14.0, 20.0, 29.0, 46.0, 72.0, 115.0, 185.0, 290.0, 460.0, 720.0, 1150.0,
+        1850.0, 2900.0, 4600.0, 7200.0,
+    ],
+    [
+        6.0, 8.0, 12.0, 16.0, 23.0, 32.0, 52.0, 81.0, 130.0, 210.0, 320.0, 520.0, 810.0, 1300.0,
+        2100.0, 3200.0, 5200.0, 8100.0,
+    ],
+    [
+        7.0, 9.0, 13.0, 18.0, 25.0, 36.0, 57.0, 89.0, 140.0, 230.0, 360.0, 570.0, 890.0, 1400.0,
+        2300.0, 3600.0, 5700.0, 8900.0,
+    ],
+    [
+        8.0, 10.0, 15.0, 20.0, 27.0, 40.0, 63.0, 97.0, 155.0, 250.0, 400.0, 630.0, 970.0, 1550.0,
+        2500.0, 4000.0, 6300.0, 9700.0,
+    ],
+];
+
+/// The fundamental (upper) deviation es, in µm, of shafts d to h.
+fn shaft_upper_deviation(letter: char, range: usize) -> Option<f64> {
+    let deviations: [f64; 13] = match letter {
+        'd' => [
+            -20.0, -30.0, -40.0, -50.0, -65.0, -80.0, -100.0, -120.0, -145.0, -170.0, -190.0,
+            -210.0, -230.0,
+        ],
+        'e' => [
+            -14.0, -20.0, -25.0, -32.0, -40.0, -50.0, -60.0, -72.0, -85.0, -100.0, -110.0, -125.0,
+            -135.0,
+        ],
+        'f' => [
+            -6.0, -10.0, -13.0, -16.0, -20.0, -25.0, -30.0, -36.0, -43.0, -50.0, -56.0, -62.0,
+            -68.0,
+        ],
+        'g' => [
+            -2.0, -4.0, -5.0, -6.0, -7.0, -9.0, -10.0, -12.0, -14.0, -15.0, -17.0, -18.0, -20.0,
+        ],
+        'h' => [0.0; 13],
+        _ => return None,
+    };
+    Some(deviations[range])
+}
+
+/// The fundamental (lower) deviation ei, in µm, of shafts k to p.
+fn shaft_lower_deviation(letter: char, range: usize, grade: usize) -> Option<f64> {
+    let deviations: [f64; 13] = match letter {
+        // k only has a deviation for grades 4 to 7.
+        'k' if !(4..=7).contains(&grade) => [0.0; 13],
+        'k' => [
+            0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 4.0, 5.0,
+        ],
+        'm' => [
+            2.0, 4.0, 6.0, 7.0, 8.0, 9.0, 11.0, 13.0, 15.0, 17.0, 20.0, 21.0, 23.0,
+        ],
+        'n' => [
+            4.0, 8.0, 10.0, 12.0, 15.0, 17.0, 20.0, 23.0, 27.0, 31.0, 34.0, 37.0, 40.0,
+        ],
+        'p' => [
+            6.0, 12.0, 15.0, 18.0, 22.0, 26.0, 32.0, 37.0, 43.0, 50.0, 56.0, 62.0, 68.0,
+        ],
+        _ => return None,
+    };
+    Some(deviations[range])
+}
+
+#[cfg(test)]
+mod test {
+    use length::Length;
+    use test_utils::assert_almost_eq;
+    use tolerance::*;
+
+    fn mm(millimeters: f64) -> Length {
+        Length::from_millimeters(millimeters)
+    }
+
+    #[test]
+    fn limits() {
+        let t = TolerancedLength::new(mm(10.0), mm(-0.2), mm(0.1));
+        assert_almost_eq(t.upper_deviation().as_millimeters(), 0.1);
+        assert_almost_eq(t.max().as_millimeters(), 10.1);
+        assert_almost_eq(t.min().as_millimeters(), 9.8);
+        assert_almost_eq(t.mean().as_millimeters(), 9.95);
+        assert_almost_eq(t.tolerance().as_millimeters(), 0.3);
+        assert!(t.contains(mm(9.9)));
+        assert!(!t.contains(mm(10.2)));
+    }
+
+    #[test]
+    fn iso_286_classes() {
+        let h7 = TolerancedLength::iso_286(mm(25.0), "H7").unwrap();
+        assert_almost_eq(h7.upper_deviation().as_micrometers(), 21.0);
+        assert_eq!(h7.lower_deviation().as_micrometers(), 0.0);
+        let g6 = TolerancedLength::iso_286(mm(25.0), "g6").unwrap();
+        assert_almost_eq(g6.upper_deviation().as_micrometers(), -7.0);
+        assert_almost_eq(g6.lower_deviation().as_micrometers(), -20.0);
+        let p6 = TolerancedLength::iso_286(mm(50.0), "p6").unwrap();
+        assert_almost_eq(p6.upper_deviation().as_micrometers(), 42.0);
+        assert_almost_eq(p6.lower_deviation().as_micrometers(), 26.0);
+        let js = TolerancedLength::iso_286(mm(3.0), "js6").unwrap();
+        assert_almost_eq(js.upper_deviation().as_micrometers(), 3.0);
+        let f8 = TolerancedLength::iso_286(mm(100.0), "F8").unwrap();
+        assert_almost_eq(f8.lower_deviation().as_micrometers(), 36.0);
+        assert_almost_eq(f8.upper_deviation().as_micrometers(), 90.0);
+    }
+
+    #[test]
+    fn iso_286_errors() {
+        assert_eq!(
+            TolerancedLength::iso_286(mm(25.0), "H"),
+            Err(Iso286Error::InvalidClass)
+        );
+        assert_eq!(
+            TolerancedLength::iso_286(mm(25.0), "H19"),
+            Err(Iso286Error::InvalidClass)
+        );
+        assert_eq!(
+            TolerancedLength::iso_286(mm(25.0), "x7"),
+            Err(Iso286Error::UnsupportedDeviation)
+        );
+        assert_eq!(
+            TolerancedLength::iso_286(mm(600.0), "H7"),
+            Err(Iso286Error::SizeOutOfRange)
+        );
+        assert_eq!(Fit::iso_286(mm(25.0), "H7"), Err(Iso286Error::InvalidClass));
+    }
+
+    #[test]
+    fn fits() {
+        let running = Fit::iso_286(mm(25.0), "H7/g6").unwrap();
+        assert_eq!(running.kind(), FitKind::Clearance);
+        assert_almost_eq(running.max_clearance().as_micrometers(), 41.0);
+        assert_almost_eq(running.min_clearance().as_micrometers(), 7.0);
+        let locating = Fit::iso_286(mm(25.0), "H7/k6").unwrap();
+        assert_eq!(locating.kind(), FitKind::Transition);
+        let press = Fit::iso_286(mm(25.0), "H7/p6").unwrap();
+        assert_eq!(press.kind(), FitKind::Interference);
+        assert_almost_eq(press.max_clearance().as_micrometers(), -1.0);
+    }
+
+    #[test]
+    fn stack_up() {
+        let a = TolerancedLength::new(mm(50.0), mm(0.1), mm(-0.1));
+        let b = TolerancedLength::new(mm(20.0), mm(0.05), mm(-0.05));
+        let gap = a - b - b;
+        assert_almost_eq(gap.nominal().as_millimeters(), 10.0);
+        assert_almost_eq(gap.upper_deviation().as_millimeters(), 0.2);
+        assert_almost_eq(gap.lower_deviation().as_millimeters(), -0.2);
+        let worst = TolerancedLength::worst_case(vec![a, -b, -b]);
+        assert_eq!(worst, gap);
+        let rss = TolerancedLength::rss(vec![a, -b, -b]);
+        assert_almost_eq(rss.nominal().as_millimeters(), 10.0);
+        assert_almost_eq(rss.upper_deviation().as_millimeters(), 0.015f64.sqrt());
+        assert_almost_eq((a + mm(1.0)).nominal().as_millimeters(), 51.0);
+    }
+
+    #[test]
+    fn rss_keeps_offset_tolerances() {
+        let hole = TolerancedLength::new(mm(10.0), mm(0.3), mm(0.1));
+        let rss = TolerancedLength::rss(vec![hole, hole]);
+        assert_almost_eq(rss.mean().as_millimeters(), 20.4);
+        assert_almost_eq(rss.tolerance().as_millimeters(), 2.0 * 0.02f64.sqrt());
+    }
+
+    #[test]
+    fn display() {
+        let h7 = TolerancedLength::iso_286(mm(25.0), "H7").unwrap();
+        assert_eq!(format!("{}", h7), "25.00 +0.021/-0.000\u{00A0}mm");
+        let g6 = TolerancedLength::iso_286(mm(25.0), "g6").unwrap();
+        assert_eq!(format!("{}", g6), "25.00 -0.007/-0.020\u{00A0}mm");
+        let p6 = TolerancedLength::iso_286(mm(50.0), "p6").unwrap();
+        assert_eq!(format!("{:.2}", p6), "50.0 +0.04/+0.03\u{00A0}mm");
+    }
+}
@@ -1,6 +1,7 @@
 //! Types and constants for handling torque
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 
 /// Number of pound-foot in a newton-metre
 const NEWTON_METRE_POUND_FOOT_FACTOR: f64 = 0.73756326522588;
@@ -70,6 +71,53 @@ impl Measurement for Torque {
 
 implement_measurement! { Torque }
 
+implement_units! {
+    Torque, TorqueUnit {
+        NewtonMetre => ("Nm", "newton metre", "newton metres", 1.0) in Si,
+        PoundFoot => ("lbf\u{00B7}ft", "pound-foot", "pound-feet", 1.0 / NEWTON_METRE_POUND_FOOT_FACTOR) in UsCustomary | Imperial,
+    }
+}
+
+/// The unit names understood when parsing a `Torque`.
+const UNIT_NAMES: &[UnitConstructor<'static, Torque>] = &[
+    ("Nm", Torque::from_newton_metres),
+    ("N\u{00B7}m", Torque::from_newton_metres),
+    ("N m", Torque::from_newton_metres),
+    ("N-m", Torque::from_newton_metres),
+    ("newton metre", Torque::from_newton_metres),
+    ("newton metres", Torque::from_newton_metres),
+    ("newton meter", Torque::from_newton_metres),
+    ("newton meters", Torque::from_newton_metres),
+    ("lbf\u{00B7}ft", Torque::from_pound_foot),
+    ("lbf ft", Torque::from_pound_foot),
+    ("lbf-ft", Torque::from_pound_foot),
+    ("lb\u{00B7}ft", Torque::from_pound_foot),
+    ("lb ft", Torque::from_pound_foot),
+    ("lb-ft", Torque::from_pound_foot),
+    ("ft\u{00B7}lbf", Torque::from_pound_foot),
+    ("ft lbf", Torque::from_pound_foot),
+    ("ft-lbf", Torque::from_pound_foot),
+    ("ft-lb", Torque::from_pound_foot),
+    ("pound foot", Torque::from_pound_foot),
+    ("pound feet", Torque::from_pound_foot),
+    ("pound-foot", Torque::from_pound_foot),
+    ("pound-feet", Torque::from_pound_foot),
+];
+
+impl UnitNames for Torque {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Torque {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -84,4 +132,21 @@ mod test {
         assert_almost_eq(r1, 338.954);
         assert_almost_eq(r2, 221.269);
     }
+
+    #[test]
+    fn from_str() {
+        let a: Torque = "250 lbf\u{00B7}ft".parse().unwrap();
+        let b: Torque = "250 lbf ft".parse().unwrap();
+        let c: Torque = "40 Nm".parse().unwrap();
+        assert_almost_eq(a.as_pound_foot(), 250.0);
+        assert_eq!(a, b);
+        assert_almost_eq(c.as_newton_metres(), 40.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = Torque::from_unit(250.0, TorqueUnit::PoundFoot);
+        assert_almost_eq(a.as_pound_foot(), 250.0);
+        assert_almost_eq(a.as_unit(TorqueUnit::NewtonMetre), 338.954);
+    }
 }
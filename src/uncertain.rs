@@ -0,0 +1,390 @@
+//! Measurements with a standard uncertainty.
+//!
+//! An `Uncertain<T>` is a measurement together with its standard uncertainty
+//! (one standard deviation), such as a length read from a caliper as
+//! 12.3 ± 0.2 mm. The usual operators propagate the uncertainty to first
+//! order, treating the two operands as independent:
+//!
+//! * for a sum or difference, the absolute uncertainties are added in
+//!   quadrature;
+//! * for a product or quotient, including the relations between types such as
+//!   Voltage / Current → Resistance, the relative uncertainties are added in
+//!   quadrature.
+//!
+//! When the operands are not independent, for example two readings taken
+//! with the same mis-calibrated instrument, use `add_correlated` and friends,
+//! which take the correlation coefficient between the two errors. A
+//! coefficient of 1.0 means the errors always move together, and -1.0 means
+//! they always move in opposite directions.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::{Current, Resistance, Uncertain, Voltage};
+//!
+//! let voltage = Uncertain::new(Voltage::from_volts(12.0), Voltage::from_volts(0.12));
+//! let current = Uncertain::new(Current::from_amperes(2.0), Current::from_amperes(0.04));
+//! let resistance: Uncertain<Resistance> = voltage / current;
+//! assert_eq!(resistance.value().as_ohms(), 6.0);
+//! assert!((resistance.relative_uncertainty() - 0.0224).abs() < 1e-4);
+//! ```
+
+use super::measurement::*;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A measurement with a standard uncertainty.
+#[derive(Copy, Clone, Debug)]
+pub struct Uncertain<T> {
+    value: T,
+    uncertainty: f64,
+}
+
+impl<T: Measurement> Uncertain<T> {
+    /// Create a new Uncertain from a measurement and its standard
+    /// uncertainty. The uncertainty is a difference, so only its size in base
+    /// units is used: give the uncertainty of a `Temperature` in kelvin.
+    pub fn new(value: T, uncertainty: T) -> Self {
+        Uncertain::from_base_units(value.as_base_units(), uncertainty.as_base_units())
+    }
+
+    /// Create a new Uncertain from a measurement and its standard uncertainty
+    /// as a fraction of the measurement, e.g. 0.01 for 1%.
+    pub fn with_relative_uncertainty(value: T, fraction: f64) -> Self {
+        let uncertainty = value.as_base_units() * fraction;
+        Uncertain::from_base_units(value.as_base_units(), uncertainty)
+    }
+
+    /// Create a new Uncertain from a value and a standard uncertainty, both
+    /// in base units.
+    pub fn from_base_units(value: f64, uncertainty: f64) -> Self {
+        Uncertain {
+            value: T::from_base_units(value),
+            uncertainty: uncertainty.abs(),
+        }
+    }
+
+    /// Create a new Uncertain with no uncertainty, such as a defined
+    /// constant.
+    pub fn exact(value: T) -> Self {
+        Uncertain {
+            value,
+            uncertainty: 0.0,
+        }
+    }
+
+    /// The measured value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The standard uncertainty.
+    pub fn uncertainty(&self) -> T {
+        T::from_base_units(self.uncertainty)
+    }
+
+    /// The standard uncertainty in base units.
+    pub fn uncertainty_as_base_units(&self) -> f64 {
+        self.uncertainty
+    }
+
+    /// The standard uncertainty as a fraction of the measured value.
+    pub fn relative_uncertainty(&self) -> f64 {
+        (self.uncertainty / self.value.as_base_units()).abs()
+    }
+
+    /// Add another measurement whose error has the given correlation
+    /// coefficient (from -1.0 to 1.0) with this one.
+    pub fn add_correlated<B>(self, rhs: Uncertain<B>, correlation: f64) -> Uncertain<T::Output>
+    where
+        T: Add<B>,
+        T::Output: Measurement,
+    {
+        let uncertainty = combine(self.uncertainty, rhs.uncertainty, correlation);
+        Uncertain::with_base_uncertainty(self.value + rhs.value, uncertainty)
+    }
+
+    /// Subtract another measurement whose error has the given correlation
+    /// coefficient (from -1.0 to 1.0) with this one.
+    pub fn sub_correlated<B>(self, rhs: Uncertain<B>, correlation: f64) -> Uncertain<T::Output>
+    where
+        T: Sub<B>,
+        T::Output: Measurement,
+    {
+        let uncertainty = combine(self.uncertainty, -rhs.uncertainty, correlation);
+        Uncertain::with_base_uncertainty(self.value - rhs.value, uncertainty)
+    }
+
+    /// Multiply by another measurement whose error has the given correlation
+    /// coefficient (from -1.0 to 1.0) with this one.
+    pub fn mul_correlated<B>(self, rhs: Uncertain<B>, correlation: f64) -> Uncertain<T::Output>
+    where
+        T: Mul<B>,
+        B: Measurement,
+        T::Output: Measurement,
+    {
+        // Every relation is c = k·a·b, for some k which depends on the units.
+        let k = (T::from_base_units(1.0) * B::from_base_units(1.0)).as_base_units();
+        let (a, b) = (self.value.as_base_units(), rhs.value.as_base_units());
+        let uncertainty = combine(
+            k * b * self.uncertainty,
+            k * a * rhs.uncertainty,
+            correlation,
+        );
+        Uncertain::with_base_uncertainty(self.value * rhs.value, uncertainty)
+    }
+
+    /// Divide by another measurement whose error has the given correlation
+    /// coefficient (from -1.0 to 1.0) with this one.
+    pub fn div_correlated<B>(self, rhs: Uncertain<B>, correlation: f64) -> Uncertain<T::Output>
+    where
+        T: Div<B>,
+        B: Measurement,
+        T::Output: Measurement,
+    {
+        // Every relation is c = k·a/b, for some k which depends on the units.
+        let k = (T::from_base_units(1.0) / B::from_base_units(1.0)).as_base_units();
+        let (a, b) = (self.value.as_base_units(), rhs.value.as_base_units());
+        let uncertainty = combine(
+            k / b * self.uncertainty,
+            -k * a / (b * b) * rhs.uncertainty,
+            correlation,
+        );
+        Uncertain::with_base_uncertainty(self.value / rhs.value, uncertainty)
+    }
+
+    fn with_base_uncertainty(value: T, uncertainty: f64) -> Self {
+        Uncertain {
+            value,
+            uncertainty: uncertainty.abs(),
+        }
+    }
+}
+
+/// Combine the contributions of two errors to the uncertainty of a result,
+/// given how strongly those errors are correlated. Each contribution is the
+/// partial derivative of the result multiplied by the uncertainty of the
+/// input, so it keeps its sign.
+fn combine(a: f64, b: f64, correlation: f64) -> f64 {
+    let variance = a * a + b * b + 2.0 * correlation * a * b;
+    variance.max(0.0).sqrt()
+}
+
+impl<A, B> Add<Uncertain<B>> for Uncertain<A>
+where
+    A: Measurement + Add<B>,
+    A::Output: Measurement,
+{
+    type Output = Uncertain<A::Output>;
+
+    fn add(self, rhs: Uncertain<B>) -> Self::Output {
+        self.add_correlated(rhs, 0.0)
+    }
+}
+
+impl<A, B> Sub<Uncertain<B>> for Uncertain<A>
+where
+    A: Measurement + Sub<B>,
+    A::Output: Measurement,
+{
+    type Output = Uncertain<A::Output>;
+
+    fn sub(self, rhs: Uncertain<B>) -> Self::Output {
+        self.sub_correlated(rhs, 0.0)
+    }
+}
+
+impl<A, B> Mul<Uncertain<B>> for Uncertain<A>
+where
+    A: Measurement + Mul<B>,
+    B: Measurement,
+    A::Output: Measurement,
+{
+    type Output = Uncertain<A::Output>;
+
+    fn mul(self, rhs: Uncertain<B>) -> Self::Output {
+        self.mul_correlated(rhs, 0.0)
+    }
+}
+
+impl<A, B> Div<Uncertain<B>> for Uncertain<A>
+where
+    A: Measurement + Div<B>,
+    B: Measurement,
+    A::Output: Measurement,
+{
+    type Output = Uncertain<A::Output>;
+
+    fn div(self, rhs: Uncertain<B>) -> Self::Output {
+        self.div_correlated(rhs, 0.0)
+    }
+}
+
+impl<T: Measurement> Mul<f64> for Uncertain<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Uncertain::from_base_units(self.value.as_base_units() * rhs, self.uncertainty * rhs)
+    }
+}
+
+impl<T: Measurement> Mul<Uncertain<T>> for f64 {
+    type Output = Uncertain<T>;
+
+    fn mul(self, rhs: Uncertain<T>) -> Uncertain<T> {
+        rhs * self
+    }
+}
+
+impl<T: Measurement> Div<f64> for Uncertain<T> {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Uncertain::from_base_units(self.value.as_base_units() / rhs, self.uncertainty / rhs)
+    }
+}
+
+impl<T: Measurement> PartialEq for Uncertain<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.as_base_units() == other.value.as_base_units()
+            && self.uncertainty == other.uncertainty
+    }
+}
+
+/// Formats as "12.3 ± 0.2 mm", in the units `Display` would pick for the
+/// value. Without a precision, the uncertainty is rounded to one significant
+/// figure, or two if it starts with a 1, and the value to the same number of
+/// decimal places.
+impl<T: Measurement> fmt::Display for Uncertain<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.value.as_base_units();
+        // A value of zero gives no hint of a sensible unit, but its
+        // uncertainty does.
+        let reference = if value != 0.0 {
+            value
+        } else {
+            self.uncertainty
+        };
+        let (unit, scaled) = T::from_base_units(reference).get_appropriate_units();
+        let scale = if reference != 0.0 {
+            scaled / reference
+        } else {
+            1.0
+        };
+        let (value, uncertainty) = (value * scale, self.uncertainty * scale);
+        let precision = f.precision().unwrap_or_else(|| decimal_places(uncertainty));
+        write!(
+            f,
+            "{:.*} \u{00B1} {:.*}\u{00A0}{}",
+            precision, value, precision, uncertainty, unit
+        )
+    }
+}
+
+/// The number of decimal places which shows the significant figures of an
+/// uncertainty.
+fn decimal_places(uncertainty: f64) -> usize {
+    if uncertainty == 0.0 || !uncertainty.is_finite() {
+        return 0;
+    }
+    let exponent = uncertainty.log10().floor() as i32;
+    let leading = uncertainty / 10f64.powi(exponent);
+    let figures = if leading < 2.0 { 2 } else { 1 };
+    (figures - 1 - exponent).max(0) as usize
+}
+
+#[cfg(test)]
+mod test {
+    use test_utils::assert_almost_eq;
+    use uncertain::*;
+    use *;
+
+    #[test]
+    fn sum_and_difference() {
+        let a = Uncertain::new(Length::from_meters(3.0), Length::from_meters(0.3));
+        let b = Uncertain::new(Length::from_meters(1.0), Length::from_meters(0.4));
+        let sum = a + b;
+        assert_almost_eq(sum.value().as_meters(), 4.0);
+        assert_almost_eq(sum.uncertainty().as_meters(), 0.5);
+        let difference = a - b;
+        assert_almost_eq(difference.value().as_meters(), 2.0);
+        assert_almost_eq(difference.uncertainty().as_meters(), 0.5);
+        assert_almost_eq(a.add_correlated(b, 1.0).uncertainty().as_meters(), 0.7);
+        assert_almost_eq(a.sub_correlated(b, 1.0).uncertainty().as_meters(), 0.1);
+    }
+
+    #[test]
+    fn product_and_quotient() {
+        let voltage = Uncertain::with_relative_uncertainty(Voltage::from_volts(12.0), 0.03);
+        let current = Uncertain::with_relative_uncertainty(Current::from_amperes(2.0), 0.04);
+        let resistance: Uncertain<Resistance> = voltage / current;
+        assert_almost_eq(resistance.value().as_ohms(), 6.0);
+        assert_almost_eq(resistance.relative_uncertainty(), 0.05);
+        let power: Uncertain<Power> = voltage * current;
+        assert_almost_eq(power.value().as_watts(), 24.0);
+        assert_almost_eq(power.relative_uncertainty(), 0.05);
+        assert_almost_eq(
+            voltage.mul_correlated(current, 1.0).relative_uncertainty(),
+            0.07,
+        );
+        assert_almost_eq(
+            voltage.div_correlated(current, 1.0).relative_uncertainty(),
+            0.01,
+        );
+    }
+
+    #[test]
+    fn relations_with_other_units() {
+        // Length × Length gives an Area in its base unit of square metres.
+        let side = Uncertain::new(Length::from_meters(2.0), Length::from_meters(0.02));
+        let area: Uncertain<Area> = side * Uncertain::exact(Length::from_meters(3.0));
+        assert_almost_eq(area.value().as_square_meters(), 6.0);
+        assert_almost_eq(area.relative_uncertainty(), 0.01);
+        // Uncertainty survives a value of zero.
+        let zero = Uncertain::new(Length::from_meters(0.0), Length::from_meters(0.1));
+        let area: Uncertain<Area> = zero * Uncertain::exact(Length::from_meters(3.0));
+        assert_almost_eq(area.uncertainty().as_square_meters(), 0.3);
+    }
+
+    #[test]
+    fn scalars() {
+        let a = Uncertain::new(Mass::from_grams(10.0), Mass::from_grams(1.0));
+        assert_almost_eq((a * 2.0).uncertainty().as_grams(), 2.0);
+        assert_almost_eq((2.0 * a).value().as_grams(), 20.0);
+        assert_almost_eq((a / -2.0).uncertainty().as_grams(), 0.5);
+    }
+
+    #[test]
+    fn temperature() {
+        let t = Uncertain::new(
+            Temperature::from_celsius(20.0),
+            Temperature::from_kelvin(0.5),
+        );
+        let delta = Uncertain::new(
+            TemperatureDelta::from_kelvin(5.0),
+            TemperatureDelta::from_kelvin(1.2),
+        );
+        let t = t + delta;
+        assert_almost_eq(t.value().as_celsius(), 25.0);
+        assert_almost_eq(t.uncertainty_as_base_units(), 1.3);
+    }
+
+    #[test]
+    fn display() {
+        let a = Uncertain::new(
+            Length::from_millimeters(8.3),
+            Length::from_millimeters(0.2),
+        );
+        assert_eq!(format!("{}", a), "8.3 \u{00B1} 0.2\u{00A0}mm");
+        let b = Uncertain::new(
+            Length::from_millimeters(8.345),
+            Length::from_millimeters(0.14),
+        );
+        assert_eq!(format!("{}", b), "8.35 \u{00B1} 0.14\u{00A0}mm");
+        assert_eq!(format!("{:.3}", b), "8.345 \u{00B1} 0.140\u{00A0}mm");
+        let c = Uncertain::new(Length::from_meters(0.0), Length::from_millimeters(3.0));
+        assert_eq!(format!("{}", c), "0 \u{00B1} 3\u{00A0}mm");
+        let d = Uncertain::exact(Length::from_meters(2.0));
+        assert_eq!(format!("{}", d), "2 \u{00B1} 0\u{00A0}m");
+    }
+}
@@ -0,0 +1,307 @@
+//! Runtime descriptions of the units each measurement can be expressed in.
+//!
+//! Every quantity in this crate has a matching unit enum (`LengthUnit`,
+//! `PressureUnit`, ...) which implements the `Unit` trait. This lets code
+//! pick a unit at runtime rather than calling a particular `as_X` method.
+//!
+//! # Example
+//!
+//! ```
+//! use measurements::{Length, LengthUnit};
+//! use measurements::prelude::*;
+//!
+//! let length = Length::from_meters(1.0);
+//! for unit in LengthUnit::iter() {
+//!     println!("{} {}", length.as_unit(unit), unit.plural());
+//! }
+//! ```
+
+use super::parse::{micro_eq, ParseErrorKind, ParseMeasurementError};
+
+/// A system of units, used to choose which units a measurement is displayed
+/// in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnitSystem {
+    /// The International System of Units, along with the metric units
+    /// commonly used with it such as the litre, tonne and hectare.
+    Si,
+    /// The customary units of the United States, including US liquid
+    /// measures such as the US gallon.
+    UsCustomary,
+    /// The British imperial units, including the stone and the imperial
+    /// gallon.
+    Imperial,
+}
+
+/// Describes a single unit.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UnitInfo {
+    /// The short symbol for this unit, for example "ft"
+    pub symbol: &'static str,
+    /// The name of one of this unit, for example "foot"
+    pub singular: &'static str,
+    /// The name of several of this unit, for example "feet"
+    pub plural: &'static str,
+    /// The number of base units in one of this unit
+    pub factor: f64,
+    /// The number of base units at zero of this unit. This is only non-zero
+    /// for scales such as degrees Celsius.
+    pub offset: f64,
+    /// The systems of units from which this unit may be chosen automatically.
+    /// Specialised units, such as the furlong or the troy ounce, belong to
+    /// none.
+    pub systems: &'static [UnitSystem],
+}
+
+/// All unit enums implement this.
+pub trait Unit: Copy + 'static {
+    /// Every unit of this kind.
+    fn all() -> &'static [Self];
+
+    /// The details of this unit.
+    fn info(self) -> &'static UnitInfo;
+
+    /// Iterate over every unit of this kind.
+    fn iter() -> ::std::iter::Cloned<::std::slice::Iter<'static, Self>> {
+        Self::all().iter().cloned()
+    }
+
+    /// The short symbol for this unit, for example "ft"
+    fn symbol(self) -> &'static str {
+        self.info().symbol
+    }
+
+    /// The name of one of this unit, for example "foot"
+    fn singular(self) -> &'static str {
+        self.info().singular
+    }
+
+    /// The name of several of this unit, for example "feet"
+    fn plural(self) -> &'static str {
+        self.info().plural
+    }
+
+    /// The number of base units in one of this unit
+    fn factor(self) -> f64 {
+        self.info().factor
+    }
+
+    /// The systems of units from which this unit may be chosen automatically.
+    fn systems(self) -> &'static [UnitSystem] {
+        self.info().systems
+    }
+
+    /// Whether this unit may be chosen automatically for the given system.
+    fn in_system(self, system: UnitSystem) -> bool {
+        self.systems().contains(&system)
+    }
+
+    /// Convert a value in this unit into base units
+    fn convert_to_base(self, value: f64) -> f64 {
+        let info = self.info();
+        value * info.factor + info.offset
+    }
+
+    /// Convert a value in base units into this unit
+    fn convert_from_base(self, base_units: f64) -> f64 {
+        let info = self.info();
+        (base_units - info.offset) / info.factor
+    }
+
+    /// Find the unit with the given symbol, singular or plural name. The same
+    /// matching rules as `parse::lookup_unit` are used, except that only the
+    /// singular and plural names are matched regardless of case.
+    fn from_name(name: &str) -> Option<Self> {
+        let names = |u: Self| IntoIterator::into_iter([u.symbol(), u.singular(), u.plural()]);
+        let units = Self::iter();
+        units
+            .clone()
+            .find(|&u| names(u).any(|n| n == name))
+            .or_else(|| units.clone().find(|&u| names(u).any(|n| micro_eq(n, name))))
+            .or_else(|| {
+                let mut matches = units.filter(|&u| {
+                    u.singular().eq_ignore_ascii_case(name) || u.plural().eq_ignore_ascii_case(name)
+                });
+                match (matches.next(), matches.next()) {
+                    (Some(u), None) => Some(u),
+                    _ => None,
+                }
+            })
+    }
+}
+
+/// Parse a unit name, for use in `FromStr` implementations of unit enums.
+pub fn parse_unit<U: Unit>(name: &str) -> Result<U, ParseMeasurementError> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err(ParseMeasurementError::new(ParseErrorKind::MissingUnit, 0));
+    }
+    U::from_name(trimmed).ok_or_else(|| {
+        ParseMeasurementError::new(ParseErrorKind::UnknownUnit, name.len() - name.trim_start().len())
+    })
+}
+
+/// This is a special macro that creates a unit enum for a measurement, and
+/// `from_unit` and `as_unit` functions on the measurement which use it.
+///
+/// Each unit is given as its symbol, singular name, plural name and the
+/// number of base units in one of it, with an optional offset for units
+/// whose zero is not the base unit's zero. A unit may be followed by `in`
+/// and the `UnitSystem`s it belongs to, separated by `|`.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate measurements;
+///
+/// use measurements::Measurement;
+///
+/// struct Cubits {
+///     forearms: f64
+/// }
+///
+/// impl Measurement for Cubits {
+///     fn as_base_units(&self) -> f64 {
+///         self.forearms
+///     }
+///
+///     fn from_base_units(units: f64) -> Self {
+///         Cubits { forearms: units }
+///     }
+///
+///    fn get_base_units_name(&self) -> &'static str {
+///        "cu"
+///    }
+/// }
+///
+/// implement_units! {
+///     Cubits, CubitUnit {
+///         Cubit => ("cu", "cubit", "cubits", 1.0) in Si,
+///         RoyalCubit => ("rcu", "royal cubit", "royal cubits", 7.0 / 6.0),
+///     }
+/// }
+///
+/// # fn main() {
+/// let c = Cubits::from_unit(6.0, CubitUnit::RoyalCubit);
+/// assert_eq!(c.as_unit(CubitUnit::Cubit), 7.0);
+/// assert_eq!(c.display().si().to_string(), "7\u{00A0}cu");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! implement_units {
+    ($t:ty, $unit:ident {
+        $($variant:ident => ($symbol:expr, $singular:expr, $plural:expr, $factor:expr $(, $offset:expr)?)
+            $(in $($system:ident)|+)?,)*
+    }) => {
+        #[doc = concat!("The units in which a `", stringify!($t), "` can be expressed.")]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $unit {
+            $(
+                #[doc = concat!("The ", $singular, " (", $symbol, ")")]
+                $variant,
+            )*
+        }
+
+        impl $crate::unit::Unit for $unit {
+            fn all() -> &'static [Self] {
+                &[$($unit::$variant),*]
+            }
+
+            fn info(self) -> &'static $crate::unit::UnitInfo {
+                match self {
+                    $(
+                        $unit::$variant => {
+                            const INFO: $crate::unit::UnitInfo = $crate::unit::UnitInfo {
+                                symbol: $symbol,
+                                singular: $singular,
+                                plural: $plural,
+                                factor: $factor,
+                                offset: 0.0 $(+ $offset)?,
+                                systems: &[$($($crate::unit::UnitSystem::$system),+)?],
+                            };
+                            &INFO
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $unit {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str($crate::unit::Unit::symbol(*self))
+            }
+        }
+
+        impl ::std::str::FromStr for $unit {
+            type Err = $crate::parse::ParseMeasurementError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::unit::parse_unit(s)
+            }
+        }
+
+        impl $t {
+            #[doc = concat!("Create a new ", stringify!($t), " from a floating point value in the given unit")]
+            pub fn from_unit(value: f64, unit: $unit) -> Self {
+                <Self as $crate::Measurement>::from_base_units(
+                    $crate::unit::Unit::convert_to_base(unit, value),
+                )
+            }
+
+            #[doc = concat!("Convert this ", stringify!($t), " into a floating point value in the given unit")]
+            pub fn as_unit(&self, unit: $unit) -> f64 {
+                $crate::unit::Unit::convert_from_base(
+                    unit,
+                    <Self as $crate::Measurement>::as_base_units(self),
+                )
+            }
+
+            #[doc = concat!("Choose how this ", stringify!($t), " is displayed, for example its unit or precision")]
+            pub fn display(&self) -> $crate::display::QuantityDisplay<$unit> {
+                $crate::display::QuantityDisplay::new(self)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use unit::*;
+    use length::LengthUnit;
+    use pressure::PressureUnit;
+    use temperature::TemperatureUnit;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn iter() {
+        assert_eq!(LengthUnit::iter().count(), LengthUnit::all().len());
+        assert!(LengthUnit::iter().any(|u| u == LengthUnit::Mile));
+    }
+
+    #[test]
+    fn info() {
+        assert_eq!(LengthUnit::Foot.symbol(), "ft");
+        assert_eq!(LengthUnit::Foot.singular(), "foot");
+        assert_eq!(LengthUnit::Foot.plural(), "feet");
+        assert_almost_eq(LengthUnit::Foot.factor(), 0.3048);
+        assert_eq!(TemperatureUnit::Celsius.info().offset, 273.15);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", PressureUnit::Kilopascal), "kPa");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("ft".parse::<LengthUnit>(), Ok(LengthUnit::Foot));
+        assert_eq!("miles".parse::<LengthUnit>(), Ok(LengthUnit::Mile));
+        assert_eq!("um".parse::<LengthUnit>(), Ok(LengthUnit::Micrometer));
+        assert_eq!("Miles".parse::<LengthUnit>(), Ok(LengthUnit::Mile));
+        assert!("MM".parse::<LengthUnit>().is_err());
+        assert_eq!(
+            "furlongs per fortnight".parse::<LengthUnit>().unwrap_err().kind(),
+            ParseErrorKind::UnknownUnit
+        );
+    }
+}
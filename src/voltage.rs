@@ -1,9 +1,12 @@
 //! Types and constants for handling voltage.
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use std::f64::consts::SQRT_2;
 
 /// The `Voltage` struct can be used to deal with electric potential difference
-/// in a common way.
+/// in a common way. For a sinusoidal alternating voltage, the `Voltage`
+/// holds the RMS value.
 ///
 /// # Example
 ///
@@ -60,6 +63,42 @@ impl Voltage {
     pub fn as_kilovolts(&self) -> f64 {
         self.volts / 1000.0
     }
+
+    /// Create a new Voltage from the peak value in Volts of a sinusoidal
+    /// voltage
+    pub fn from_peak_volts(peak_volts: f64) -> Self {
+        Self::from_volts(peak_volts / SQRT_2)
+    }
+
+    /// Create a new Voltage from the peak-to-peak value in Volts of a
+    /// sinusoidal voltage
+    pub fn from_peak_to_peak_volts(peak_to_peak_volts: f64) -> Self {
+        Self::from_peak_volts(peak_to_peak_volts / 2.0)
+    }
+
+    /// Convert this Voltage into the peak value in Volts of a sinusoidal
+    /// voltage
+    pub fn as_peak_volts(&self) -> f64 {
+        self.volts * SQRT_2
+    }
+
+    /// Convert this Voltage into the peak-to-peak value in Volts of a
+    /// sinusoidal voltage
+    pub fn as_peak_to_peak_volts(&self) -> f64 {
+        self.as_peak_volts() * 2.0
+    }
+
+    /// Convert this phase (line-to-neutral) Voltage of a balanced
+    /// star-connected three-phase supply into the line-to-line Voltage
+    pub fn star_phase_to_line(&self) -> Self {
+        Self::from_volts(self.volts * ::SQRT_3)
+    }
+
+    /// Convert this line-to-line Voltage of a balanced star-connected
+    /// three-phase supply into the phase (line-to-neutral) Voltage
+    pub fn star_line_to_phase(&self) -> Self {
+        Self::from_volts(self.volts / ::SQRT_3)
+    }
 }
 
 impl Measurement for Voltage {
@@ -97,6 +136,53 @@ impl Measurement for Voltage {
 
 implement_measurement! { Voltage }
 
+implement_units! {
+    Voltage, VoltageUnit {
+        Microvolt => ("\u{00B5}V", "microvolt", "microvolts", 1e-6) in Si,
+        Millivolt => ("mV", "millivolt", "millivolts", 1e-3) in Si,
+        Volt => ("V", "volt", "volts", 1.0) in Si,
+        Kilovolt => ("kV", "kilovolt", "kilovolts", 1e3) in Si,
+    }
+}
+
+/// The unit names understood when parsing a `Voltage`.
+const UNIT_NAMES: &[UnitConstructor<'static, Voltage>] = &[
+    ("fV", |v| Voltage::from_volts(v * 1e-15)),
+    ("pV", |v| Voltage::from_volts(v * 1e-12)),
+    ("nV", |v| Voltage::from_volts(v * 1e-9)),
+    ("\u{00B5}V", |v| Voltage::from_volts(v * 1e-6)),
+    ("mV", |v| Voltage::from_volts(v * 1e-3)),
+    ("V", Voltage::from_volts),
+    ("kV", |v| Voltage::from_volts(v * 1e3)),
+    ("MV", |v| Voltage::from_volts(v * 1e6)),
+    ("GV", |v| Voltage::from_volts(v * 1e9)),
+    ("TV", |v| Voltage::from_volts(v * 1e12)),
+    ("PV", |v| Voltage::from_volts(v * 1e15)),
+    ("EV", |v| Voltage::from_volts(v * 1e18)),
+    ("volt", Voltage::from_volts),
+    ("volts", Voltage::from_volts),
+    ("microvolt", Voltage::from_microvolts),
+    ("microvolts", Voltage::from_microvolts),
+    ("millivolt", Voltage::from_millivolts),
+    ("millivolts", Voltage::from_millivolts),
+    ("kilovolt", Voltage::from_kilovolts),
+    ("kilovolts", Voltage::from_kilovolts),
+];
+
+impl UnitNames for Voltage {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Voltage {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use voltage::*;
@@ -213,4 +299,40 @@ mod test {
         assert_eq!(r.as_ohms(), 470.0);
     }
 
+    #[test]
+    fn peak() {
+        let mains = Voltage::from_volts(230.0);
+        assert_almost_eq(mains.as_peak_volts(), 325.269_119_345_812_2);
+        assert_almost_eq(mains.as_peak_to_peak_volts(), 650.538_238_691_624_4);
+        assert_almost_eq(Voltage::from_peak_volts(10.0).as_volts(), 7.071_067_811_865_475);
+        assert_almost_eq(
+            Voltage::from_peak_to_peak_volts(2.0).as_volts(),
+            0.707_106_781_186_547_5,
+        );
+    }
+
+    #[test]
+    fn three_phase() {
+        let phase = Voltage::from_volts(230.0);
+        assert_almost_eq(phase.star_phase_to_line().as_volts(), 398.371_685_740_842);
+        assert_almost_eq(
+            Voltage::from_volts(400.0).star_line_to_phase().as_volts(),
+            230.940_107_675_850_3,
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let a: Voltage = "3.3 V".parse().unwrap();
+        let b: Voltage = "150 mV".parse().unwrap();
+        assert_almost_eq(a.as_volts(), 3.3);
+        assert_almost_eq(b.as_volts(), 0.15);
+    }
+
+    #[test]
+    fn units() {
+        let a = Voltage::from_unit(1.5, VoltageUnit::Volt);
+        assert_almost_eq(a.as_millivolts(), 1500.0);
+        assert_almost_eq(a.as_unit(VoltageUnit::Kilovolt), 0.0015);
+    }
 }
@@ -1,6 +1,7 @@
 //! Types and constants for handling volumes (that is, three-dimensional space, not loudness).
 
 use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
 
 /// The `Volume` struct can be used to deal with volumes in a common way.
 ///
@@ -318,6 +319,122 @@ impl Measurement for Volume {
 
 implement_measurement! { Volume }
 
+implement_units! {
+    Volume, VolumeUnit {
+        Milliliter => ("ml", "milliliter", "milliliters", 1.0 / LITER_MILLILITERS_FACTOR) in Si,
+        CubicCentimeter => ("cm\u{00B3}", "cubic centimeter", "cubic centimeters", 1.0 / LITER_CUBIC_CENTIMETER_FACTOR),
+        Liter => ("l", "liter", "liters", 1.0) in Si,
+        CubicMeter => ("m\u{00B3}", "cubic meter", "cubic meters", 1.0 / LITER_CUBIC_METER_FACTOR) in Si,
+        Drop => ("gtt", "drop", "drops", 1.0 / LITER_DROP_FACTOR),
+        Dram => ("fl dr", "dram", "drams", 1.0 / LITER_DRAM_FACTOR),
+        Teaspoon => ("tsp", "teaspoon", "teaspoons", 1.0 / LITER_TEASPOONS_FACTOR) in UsCustomary,
+        Tablespoon => ("tbsp", "tablespoon", "tablespoons", 1.0 / LITER_TABLESPOONS_FACTOR) in UsCustomary,
+        CubicInch => ("in\u{00B3}", "cubic inch", "cubic inches", 1.0 / LITER_CUBIC_INCHES_FACTOR),
+        FluidOunceUk => ("fl oz (UK)", "UK fluid ounce", "UK fluid ounces", 1.0 / LITER_FLUID_OUNCES_UK_FACTOR) in Imperial,
+        FluidOunce => ("fl oz", "fluid ounce", "fluid ounces", 1.0 / LITER_FLUID_OUNCES_FACTOR) in UsCustomary,
+        Cup => ("cup", "cup", "cups", 1.0 / LITER_CUP_FACTOR) in UsCustomary,
+        Pint => ("pt", "pint", "pints", 1.0 / LITER_PINTS_FACTOR) in UsCustomary,
+        PintUk => ("pt (UK)", "UK pint", "UK pints", 1.0 / LITER_PINTS_UK_FACTOR) in Imperial,
+        Quart => ("qt", "quart", "quarts", 1.0 / LITER_QUARTS_FACTOR) in UsCustomary,
+        Gallon => ("gal", "gallon", "gallons", 1.0 / LITER_GALLONS_FACTOR) in UsCustomary,
+        GallonUk => ("gal (UK)", "UK gallon", "UK gallons", 1.0 / LITER_GALLONS_UK_FACTOR) in Imperial,
+        CubicFoot => ("ft\u{00B3}", "cubic foot", "cubic feet", 1.0 / LITER_CUBIC_FEET_FACTOR),
+        CubicYard => ("yd\u{00B3}", "cubic yard", "cubic yards", 1.0 / LITER_CUBIC_YARD_FACTOR),
+    }
+}
+
+/// The unit names understood when parsing a `Volume`.
+const UNIT_NAMES: &[UnitConstructor<'static, Volume>] = &[
+    ("pl", |v| Volume::from_liters(v * 1e-12)),
+    ("nl", |v| Volume::from_liters(v * 1e-9)),
+    ("\u{00B5}l", |v| Volume::from_liters(v * 1e-6)),
+    ("ml", Volume::from_milliliters),
+    ("l", Volume::from_liters),
+    ("m\u{00B3}", Volume::from_cubic_meters),
+    ("km\u{00B3}", |v| Volume::from_liters(v * 1e12)),
+    ("mL", Volume::from_milliliters),
+    ("L", Volume::from_liters),
+    ("cc", Volume::from_cubic_centimeters),
+    ("cm\u{00B3}", Volume::from_cubic_centimeters),
+    ("cm3", Volume::from_cubic_centimeters),
+    ("m3", Volume::from_cubic_meters),
+    ("tsp", Volume::from_teaspoons),
+    ("tbsp", Volume::from_tablespoons),
+    ("in\u{00B3}", Volume::from_cubic_inches),
+    ("cu in", Volume::from_cubic_inches),
+    ("fl oz", Volume::from_fluid_ounces),
+    ("fl oz (UK)", Volume::from_fluid_ounces_uk),
+    ("pt", Volume::from_pints),
+    ("pt (UK)", Volume::from_pints_uk),
+    ("qt", Volume::from_quarts),
+    ("gal", Volume::from_gallons),
+    ("gal (UK)", Volume::from_gallons_uk),
+    ("ft\u{00B3}", Volume::from_cubic_feet),
+    ("cu ft", Volume::from_cubic_feet),
+    ("yd\u{00B3}", Volume::from_cubic_yards),
+    ("cu yd", Volume::from_cubic_yards),
+    ("liter", Volume::from_liters),
+    ("liters", Volume::from_liters),
+    ("litre", Volume::from_liters),
+    ("litres", Volume::from_liters),
+    ("milliliter", Volume::from_milliliters),
+    ("milliliters", Volume::from_milliliters),
+    ("millilitre", Volume::from_milliliters),
+    ("millilitres", Volume::from_milliliters),
+    ("cubic centimeter", Volume::from_cubic_centimeters),
+    ("cubic centimeters", Volume::from_cubic_centimeters),
+    ("cubic centimetre", Volume::from_cubic_centimeters),
+    ("cubic centimetres", Volume::from_cubic_centimeters),
+    ("cubic meter", Volume::from_cubic_meters),
+    ("cubic meters", Volume::from_cubic_meters),
+    ("cubic metre", Volume::from_cubic_meters),
+    ("cubic metres", Volume::from_cubic_meters),
+    ("drop", Volume::from_drops),
+    ("drops", Volume::from_drops),
+    ("dram", Volume::from_drams),
+    ("drams", Volume::from_drams),
+    ("teaspoon", Volume::from_teaspoons),
+    ("teaspoons", Volume::from_teaspoons),
+    ("tablespoon", Volume::from_tablespoons),
+    ("tablespoons", Volume::from_tablespoons),
+    ("cubic inch", Volume::from_cubic_inches),
+    ("cubic inches", Volume::from_cubic_inches),
+    ("fluid ounce", Volume::from_fluid_ounces),
+    ("fluid ounces", Volume::from_fluid_ounces),
+    ("fluid ounce uk", Volume::from_fluid_ounces_uk),
+    ("fluid ounces uk", Volume::from_fluid_ounces_uk),
+    ("cup", Volume::from_cups),
+    ("cups", Volume::from_cups),
+    ("pint", Volume::from_pints),
+    ("pints", Volume::from_pints),
+    ("pint uk", Volume::from_pints_uk),
+    ("pints uk", Volume::from_pints_uk),
+    ("quart", Volume::from_quarts),
+    ("quarts", Volume::from_quarts),
+    ("gallon", Volume::from_gallons),
+    ("gallons", Volume::from_gallons),
+    ("gallon uk", Volume::from_gallons_uk),
+    ("gallons uk", Volume::from_gallons_uk),
+    ("cubic foot", Volume::from_cubic_feet),
+    ("cubic feet", Volume::from_cubic_feet),
+    ("cubic yard", Volume::from_cubic_yards),
+    ("cubic yards", Volume::from_cubic_yards),
+];
+
+impl UnitNames for Volume {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for Volume {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use volume::*;
@@ -593,4 +710,18 @@ mod test {
         assert_eq!(a >= b, false);
     }
 
+    #[test]
+    fn from_str() {
+        let a: Volume = "2 gal".parse().unwrap();
+        let b: Volume = "330 ml".parse().unwrap();
+        assert_almost_eq(a.as_gallons(), 2.0);
+        assert_almost_eq(b.as_liters(), 0.33);
+    }
+
+    #[test]
+    fn units() {
+        let a = Volume::from_unit(1.0, VolumeUnit::Gallon);
+        assert_almost_eq(a.as_pints(), 8.0);
+        assert_almost_eq(a.as_unit(VolumeUnit::Liter), 3.785411);
+    }
 }
@@ -0,0 +1,375 @@
+//! Types and constants for handling volumetric flow rate (volume per unit
+//! time).
+
+use super::measurement::*;
+use super::parse::{parse_measurement, ParseMeasurementError, UnitConstructor, UnitNames};
+use super::speed::{SECONDS_HOURS_FACTOR, SECONDS_MINUTES_FACTOR};
+use super::*;
+
+/// Number of L/s in a m³/s
+pub const LITERS_PER_SECOND_CUBIC_METERS_PER_SECOND_FACTOR: f64 =
+    1.0 / volume::LITER_CUBIC_METER_FACTOR;
+/// Number of L/s in a (US) gal/min
+pub const LITERS_PER_SECOND_GALLONS_PER_MINUTE_FACTOR: f64 =
+    1.0 / volume::LITER_GALLONS_FACTOR / SECONDS_MINUTES_FACTOR;
+/// Number of L/s in a ft³/min (cfm)
+pub const LITERS_PER_SECOND_CUBIC_FEET_PER_MINUTE_FACTOR: f64 =
+    1.0 / volume::LITER_CUBIC_FEET_FACTOR / SECONDS_MINUTES_FACTOR;
+
+/// The `VolumetricFlowRate` struct can be used to deal with volumetric flow
+/// rates in a common way, such as the output of a pump or the airflow of a
+/// fan. Common metric and imperial units are supported.
+///
+/// Like `Volume`, a VolumetricFlowRate is stored in litres (per second), so
+/// that Volume = VolumetricFlowRate × Duration.
+///
+/// # Example
+///
+/// ```
+/// use measurements::VolumetricFlowRate;
+/// use std::time::Duration;
+///
+/// let pump = VolumetricFlowRate::from_liters_per_minute(30.0);
+/// let pumped = pump * Duration::from_secs(120);
+/// println!("The pump moved {} in two minutes.", pumped);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct VolumetricFlowRate {
+    liters_per_second: f64,
+}
+
+impl VolumetricFlowRate {
+    /// Create a new VolumetricFlowRate from a floating point value in cubic
+    /// meters per second
+    pub fn from_cubic_meters_per_second(cubic_meters_per_second: f64) -> Self {
+        VolumetricFlowRate::from_liters_per_second(
+            cubic_meters_per_second * LITERS_PER_SECOND_CUBIC_METERS_PER_SECOND_FACTOR,
+        )
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in cubic
+    /// metres per second
+    pub fn from_cubic_metres_per_second(cubic_metres_per_second: f64) -> Self {
+        VolumetricFlowRate::from_cubic_meters_per_second(cubic_metres_per_second)
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in cubic
+    /// meters per hour
+    pub fn from_cubic_meters_per_hour(cubic_meters_per_hour: f64) -> Self {
+        VolumetricFlowRate::from_cubic_meters_per_second(
+            cubic_meters_per_hour / SECONDS_HOURS_FACTOR,
+        )
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in cubic
+    /// metres per hour
+    pub fn from_cubic_metres_per_hour(cubic_metres_per_hour: f64) -> Self {
+        VolumetricFlowRate::from_cubic_meters_per_hour(cubic_metres_per_hour)
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in liters
+    /// per second
+    pub fn from_liters_per_second(liters_per_second: f64) -> Self {
+        VolumetricFlowRate { liters_per_second }
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in litres
+    /// per second
+    pub fn from_litres_per_second(litres_per_second: f64) -> Self {
+        VolumetricFlowRate::from_liters_per_second(litres_per_second)
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in liters
+    /// per minute
+    pub fn from_liters_per_minute(liters_per_minute: f64) -> Self {
+        VolumetricFlowRate::from_liters_per_second(liters_per_minute / SECONDS_MINUTES_FACTOR)
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in litres
+    /// per minute
+    pub fn from_litres_per_minute(litres_per_minute: f64) -> Self {
+        VolumetricFlowRate::from_liters_per_minute(litres_per_minute)
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in (US)
+    /// gallons per minute
+    pub fn from_gallons_per_minute(gallons_per_minute: f64) -> Self {
+        VolumetricFlowRate::from_liters_per_second(
+            gallons_per_minute * LITERS_PER_SECOND_GALLONS_PER_MINUTE_FACTOR,
+        )
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in cubic
+    /// feet per minute (cfm)
+    pub fn from_cubic_feet_per_minute(cubic_feet_per_minute: f64) -> Self {
+        VolumetricFlowRate::from_liters_per_second(
+            cubic_feet_per_minute * LITERS_PER_SECOND_CUBIC_FEET_PER_MINUTE_FACTOR,
+        )
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in cubic
+    /// meters per second
+    pub fn as_cubic_meters_per_second(&self) -> f64 {
+        self.liters_per_second / LITERS_PER_SECOND_CUBIC_METERS_PER_SECOND_FACTOR
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in cubic
+    /// metres per second
+    pub fn as_cubic_metres_per_second(&self) -> f64 {
+        self.as_cubic_meters_per_second()
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in cubic
+    /// meters per hour
+    pub fn as_cubic_meters_per_hour(&self) -> f64 {
+        self.as_cubic_meters_per_second() * SECONDS_HOURS_FACTOR
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in cubic
+    /// metres per hour
+    pub fn as_cubic_metres_per_hour(&self) -> f64 {
+        self.as_cubic_meters_per_hour()
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in liters
+    /// per second
+    pub fn as_liters_per_second(&self) -> f64 {
+        self.liters_per_second
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in litres
+    /// per second
+    pub fn as_litres_per_second(&self) -> f64 {
+        self.as_liters_per_second()
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in liters
+    /// per minute
+    pub fn as_liters_per_minute(&self) -> f64 {
+        self.liters_per_second * SECONDS_MINUTES_FACTOR
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in litres
+    /// per minute
+    pub fn as_litres_per_minute(&self) -> f64 {
+        self.as_liters_per_minute()
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in (US)
+    /// gallons per minute
+    pub fn as_gallons_per_minute(&self) -> f64 {
+        self.liters_per_second / LITERS_PER_SECOND_GALLONS_PER_MINUTE_FACTOR
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in cubic
+    /// feet per minute (cfm)
+    pub fn as_cubic_feet_per_minute(&self) -> f64 {
+        self.liters_per_second / LITERS_PER_SECOND_CUBIC_FEET_PER_MINUTE_FACTOR
+    }
+}
+
+impl Measurement for VolumetricFlowRate {
+    fn as_base_units(&self) -> f64 {
+        self.liters_per_second
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_liters_per_second(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "L/s"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = [
+            ("\u{00B5}L/s", 1e-6),
+            ("mL/s", 1e-3),
+            ("L/s", 1e0),
+            ("m\u{00B3}/s", 1e3),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+}
+
+implement_measurement! { VolumetricFlowRate }
+
+implement_units! {
+    VolumetricFlowRate, VolumetricFlowRateUnit {
+        CubicMeterPerSecond => ("m\u{00B3}/s", "cubic meter per second", "cubic meters per second", LITERS_PER_SECOND_CUBIC_METERS_PER_SECOND_FACTOR) in Si,
+        CubicMeterPerHour => ("m\u{00B3}/h", "cubic meter per hour", "cubic meters per hour", LITERS_PER_SECOND_CUBIC_METERS_PER_SECOND_FACTOR / SECONDS_HOURS_FACTOR),
+        LiterPerSecond => ("L/s", "liter per second", "liters per second", 1.0) in Si,
+        LiterPerMinute => ("L/min", "liter per minute", "liters per minute", 1.0 / SECONDS_MINUTES_FACTOR),
+        GallonPerMinute => ("gal/min", "gallon per minute", "gallons per minute", LITERS_PER_SECOND_GALLONS_PER_MINUTE_FACTOR) in UsCustomary,
+        CubicFootPerMinute => ("cfm", "cubic foot per minute", "cubic feet per minute", LITERS_PER_SECOND_CUBIC_FEET_PER_MINUTE_FACTOR) in UsCustomary | Imperial,
+    }
+}
+
+/// The unit names understood when parsing a `VolumetricFlowRate`.
+const UNIT_NAMES: &[UnitConstructor<'static, VolumetricFlowRate>] = &[
+    ("\u{00B5}L/s", |v| {
+        VolumetricFlowRate::from_liters_per_second(v * 1e-6)
+    }),
+    ("mL/s", |v| {
+        VolumetricFlowRate::from_liters_per_second(v * 1e-3)
+    }),
+    ("L/s", VolumetricFlowRate::from_liters_per_second),
+    (
+        "m\u{00B3}/s",
+        VolumetricFlowRate::from_cubic_meters_per_second,
+    ),
+    ("m3/s", VolumetricFlowRate::from_cubic_meters_per_second),
+    (
+        "m\u{00B3}/h",
+        VolumetricFlowRate::from_cubic_meters_per_hour,
+    ),
+    ("m3/h", VolumetricFlowRate::from_cubic_meters_per_hour),
+    ("L/min", VolumetricFlowRate::from_liters_per_minute),
+    ("lpm", VolumetricFlowRate::from_liters_per_minute),
+    ("gal/min", VolumetricFlowRate::from_gallons_per_minute),
+    ("gpm", VolumetricFlowRate::from_gallons_per_minute),
+    ("cfm", VolumetricFlowRate::from_cubic_feet_per_minute),
+    (
+        "ft\u{00B3}/min",
+        VolumetricFlowRate::from_cubic_feet_per_minute,
+    ),
+    (
+        "cubic meters per second",
+        VolumetricFlowRate::from_cubic_meters_per_second,
+    ),
+    (
+        "cubic metres per second",
+        VolumetricFlowRate::from_cubic_meters_per_second,
+    ),
+    (
+        "cubic meters per hour",
+        VolumetricFlowRate::from_cubic_meters_per_hour,
+    ),
+    (
+        "cubic metres per hour",
+        VolumetricFlowRate::from_cubic_meters_per_hour,
+    ),
+    (
+        "liters per second",
+        VolumetricFlowRate::from_liters_per_second,
+    ),
+    (
+        "litres per second",
+        VolumetricFlowRate::from_liters_per_second,
+    ),
+    (
+        "liters per minute",
+        VolumetricFlowRate::from_liters_per_minute,
+    ),
+    (
+        "litres per minute",
+        VolumetricFlowRate::from_liters_per_minute,
+    ),
+    (
+        "gallons per minute",
+        VolumetricFlowRate::from_gallons_per_minute,
+    ),
+    (
+        "cubic feet per minute",
+        VolumetricFlowRate::from_cubic_feet_per_minute,
+    ),
+];
+
+impl UnitNames for VolumetricFlowRate {
+    fn unit_names() -> &'static [UnitConstructor<'static, Self>] {
+        UNIT_NAMES
+    }
+}
+
+impl ::std::str::FromStr for VolumetricFlowRate {
+    type Err = ParseMeasurementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_measurement(s, UNIT_NAMES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use area::Area;
+    use density::Density;
+    use mass_flow_rate::MassFlowRate;
+    use speed::Speed;
+    use test_utils::assert_almost_eq;
+    use time::Duration;
+    use volume::Volume;
+    use volumetric_flow_rate::*;
+
+    #[test]
+    fn metric() {
+        let f = VolumetricFlowRate::from_cubic_meters_per_second(1.0);
+        assert_almost_eq(f.as_liters_per_second(), 1000.0);
+        assert_almost_eq(f.as_liters_per_minute(), 60000.0);
+        assert_almost_eq(f.as_cubic_meters_per_hour(), 3600.0);
+    }
+
+    #[test]
+    fn imperial() {
+        let f = VolumetricFlowRate::from_gallons_per_minute(1.0);
+        assert_almost_eq(f.as_liters_per_minute(), 3.785411784);
+        let f = VolumetricFlowRate::from_cubic_feet_per_minute(1.0);
+        assert_almost_eq(f.as_liters_per_second(), 0.4719474432);
+    }
+
+    #[test]
+    fn volume_and_duration() {
+        let f = VolumetricFlowRate::from_liters_per_minute(30.0);
+        let v: Volume = f * Duration::new(120, 0);
+        assert_almost_eq(v.as_liters(), 60.0);
+        let f: VolumetricFlowRate = Volume::from_liters(10.0) / Duration::new(5, 0);
+        assert_almost_eq(f.as_liters_per_second(), 2.0);
+        let t: Duration = Volume::from_liters(10.0) / f;
+        assert_eq!(t, Duration::new(5, 0));
+    }
+
+    #[test]
+    fn area_and_speed() {
+        let pipe = Area::from_square_meters(0.01);
+        let f: VolumetricFlowRate = pipe * Speed::from_meters_per_second(2.0);
+        assert_almost_eq(f.as_liters_per_second(), 20.0);
+        let f: VolumetricFlowRate = Speed::from_meters_per_second(2.0) * pipe;
+        assert_almost_eq(f.as_cubic_meters_per_second(), 0.02);
+        let s: Speed = f / pipe;
+        assert_almost_eq(s.as_meters_per_second(), 2.0);
+        let a: Area = f / Speed::from_meters_per_second(4.0);
+        assert_almost_eq(a.as_square_meters(), 0.005);
+    }
+
+    #[test]
+    fn density() {
+        let f = VolumetricFlowRate::from_liters_per_second(2.0);
+        let m: MassFlowRate = f * Density::from_kilograms_per_cubic_meter(800.0);
+        assert_almost_eq(m.as_kilograms_per_second(), 1.6);
+        let f: VolumetricFlowRate = m / Density::from_kilograms_per_liter(0.4);
+        assert_almost_eq(f.as_liters_per_second(), 4.0);
+    }
+
+    #[test]
+    fn display() {
+        let f = VolumetricFlowRate::from_liters_per_second(2.5);
+        assert_eq!(format!("{}", f), "2.5\u{00A0}L/s");
+        let f = VolumetricFlowRate::from_cubic_meters_per_second(1.5);
+        assert_eq!(format!("{}", f), "1.5\u{00A0}m\u{00B3}/s");
+    }
+
+    #[test]
+    fn from_str() {
+        let a: VolumetricFlowRate = "30 L/min".parse().unwrap();
+        let b: VolumetricFlowRate = "100 cfm".parse().unwrap();
+        assert_almost_eq(a.as_liters_per_second(), 0.5);
+        assert_almost_eq(b.as_cubic_feet_per_minute(), 100.0);
+    }
+
+    #[test]
+    fn units() {
+        let a = VolumetricFlowRate::from_unit(60.0, VolumetricFlowRateUnit::LiterPerMinute);
+        assert_almost_eq(a.as_liters_per_second(), 1.0);
+        assert_almost_eq(a.as_unit(VolumetricFlowRateUnit::CubicMeterPerHour), 3.6);
+    }
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_measurements"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(args: &[&str], input: &str) -> String {
+    let output = run(args, input);
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn convert() {
+    assert_eq!(stdout(&["convert", "5", "ft", "m"], ""), "5 ft = 1.524 m\n");
+    assert_eq!(
+        stdout(&["convert", "250", "lbf ft", "Nm"], ""),
+        "250 lbf ft = 338.9539742 Nm\n"
+    );
+    assert_eq!(
+        stdout(&["convert", "30 psi", "bar"], ""),
+        "30 psi = 2.068428 bar\n"
+    );
+}
+
+#[test]
+fn convert_errors() {
+    let output = run(&["convert", "5", "ft", "kg"], "");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("cannot convert Length into a unit of Mass"));
+    assert!(!run(&["convert", "5"], "").status.success());
+}
+
+#[test]
+fn units() {
+    let listing = stdout(&["units", "angular", "velocity"], "");
+    assert!(listing.contains("rpm"));
+    assert!(stdout(&["units"], "").contains("Pressure"));
+    assert!(!run(&["units", "flavour"], "").status.success());
+}
+
+#[test]
+fn repl() {
+    let output = run(
+        &[],
+        "3 kg * 9.81 m/s^2\n\n100 rpm -> rad/s\n2 m + 3 kg\nquit\n1 + 1\n",
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout, "29.43\u{00A0}N\n10.47197551 rad/s\n");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("cannot apply '+' to Length and Mass"));
+}
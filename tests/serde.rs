@@ -0,0 +1,65 @@
+#![cfg(feature = "serde")]
+
+extern crate measurements;
+extern crate serde_json;
+extern crate serde_test;
+
+use measurements::test_utils::assert_almost_eq;
+use measurements::{Length, Pressure, Temperature};
+use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+
+#[test]
+fn compact() {
+    let l = Length::from_meters(1.5);
+    assert_tokens(&l.compact(), &[Token::F64(1.5)]);
+}
+
+#[test]
+fn readable() {
+    let l = Length::from_meters(1.5);
+    assert_tokens(&l.readable(), &[Token::Str("1.5 m")]);
+    assert_de_tokens(&l.readable(), &[Token::F64(1.5)]);
+}
+
+#[test]
+fn json_string() {
+    let l: Length = serde_json::from_str("\"5 ft\"").unwrap();
+    assert_almost_eq(l.as_feet(), 5.0);
+    let l: Length = serde_json::from_str("\"3'6\\\"\"").unwrap();
+    assert_almost_eq(l.as_feet(), 3.5);
+    assert_eq!(serde_json::to_string(&Length::from_meters(2.0)).unwrap(), "\"2 m\"");
+}
+
+#[test]
+fn non_finite() {
+    let l = Length::from_meters(f64::INFINITY);
+    assert_tokens(&l.readable(), &[Token::Str("inf m")]);
+    let l = Length::from_meters(f64::NEG_INFINITY);
+    assert_tokens(&l.readable(), &[Token::Str("-inf m")]);
+    let json = serde_json::to_string(&Length::from_meters(f64::NAN)).unwrap();
+    assert_eq!(json, "\"NaN m\"");
+    let l: Length = serde_json::from_str(&json).unwrap();
+    assert!(l.as_meters().is_nan());
+}
+
+#[test]
+fn json_number() {
+    let p: Pressure = serde_json::from_str("101325").unwrap();
+    assert_almost_eq(p.as_atmospheres(), 1.0);
+}
+
+#[test]
+fn json_map() {
+    let t: Temperature = serde_json::from_str(r#"{"value": 20, "unit": "°C"}"#).unwrap();
+    assert_almost_eq(t.as_celsius(), 20.0);
+    let p: Pressure = serde_json::from_str(r#"{"unit": "psi", "value": 14.5}"#).unwrap();
+    assert_almost_eq(p.as_psi(), 14.5);
+}
+
+#[test]
+fn json_errors() {
+    assert!(serde_json::from_str::<Length>("\"5 cubits\"").is_err());
+    assert!(serde_json::from_str::<Length>(r#"{"value": 5}"#).is_err());
+    assert!(serde_json::from_str::<Length>(r#"{"value": 5, "unit": "parsec"}"#).is_err());
+    assert!(serde_json::from_str::<Length>("true").is_err());
+}